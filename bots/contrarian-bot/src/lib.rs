@@ -1,12 +1,15 @@
 use council_core::event::Event;
 use council_core::explorer::GalacticCouncilMember;
 use council_core::galaxy::{GalaxyState, Relation};
-use council_core::ollama::{build_galactic_prompt, llm_choose, llm_deliberate, OllamaConfig};
+use council_core::ollama::{
+    build_system_and_user, llm_choose_with_system, llm_deliberate, OllamaConfig,
+};
 use council_core::{Context, CouncilMember, Decision, DominantOutcome};
 
 const PERSONALITY: &str = "You are a hardened military strategist who always challenges the obvious choice. You prepare for worst-case scenarios and never underestimate threats.";
 
 /// ContrarianBot reacts to the council's previous round by opposing the majority.
+#[derive(Clone)]
 pub struct ContrarianBot {
     ollama: Option<OllamaConfig>,
 }
@@ -35,7 +38,7 @@ impl CouncilMember for ContrarianBot {
     }
 
     fn vote(&self, ctx: &Context) -> Decision {
-        match ctx.previous_tally {
+        match &ctx.previous_tally {
             None => Decision::Abstain,
             Some(tally) => match tally.dominant() {
                 DominantOutcome::Approve => Decision::Reject,
@@ -62,76 +65,45 @@ impl GalacticCouncilMember for ContrarianBot {
         ]
     }
 
-    /// Threat-aware contrarian strategy. Picks the defensive first option (0)
-    /// when the event involves military expertise or the galaxy has active
-    /// threats. Otherwise falls back to the contrarian last-option pick.
+    /// Threat-aware contrarian strategy. On military or strategy events under
+    /// an active threat it strikes first (option 0, aggressive) when the
+    /// threat is severe (3+), and otherwise takes the defensive option 1.
+    /// Otherwise falls back to the contrarian last-option pick.
     /// Falls back to deterministic logic if Ollama is unavailable.
     fn vote(&self, event: &Event, galaxy: &GalaxyState) -> usize {
         if let Some(cfg) = &self.ollama {
-            let prompt = build_galactic_prompt(PERSONALITY, event, galaxy);
-            if let Ok(choice) = llm_choose(cfg, &prompt, event.options.len()) {
+            let (system, user) = build_system_and_user(PERSONALITY, event, galaxy);
+            if let Ok(choice) = llm_choose_with_system(cfg, &system, &user, event.options.len()) {
                 return choice;
             }
         }
         // Deterministic fallback: priority-based strategy
-        let num_options = event.options.len();
-
-        // AC-9: Single option
-        if num_options <= 1 {
-            return 0;
-        }
-
-        // Helper: check if event has any of these tags
-        let has_tag = |tags: &[&str]| -> bool {
-            event
-                .relevant_expertise
-                .iter()
-                .any(|(t, _)| tags.contains(&t.as_str()))
-        };
+        strategize(event, galaxy).0
+    }
 
-        // AC-2, AC-3: Threat assessment
-        let max_severity = galaxy.threats.iter().map(|t| t.severity).max().unwrap_or(0);
-        if max_severity > 0 && has_tag(&["military", "strategy"]) {
-            if max_severity >= 3 {
-                return 0; // AC-2: aggressive
-            } else {
-                return 1.min(num_options - 1); // AC-3: containment
-            }
+    /// Strategy pick first; under threat the other of aggressive/defensive
+    /// follows, and the contrarian last option closes the list.
+    ///
+    /// Under a severe threat the aggressive option leads, ahead of the
+    /// defensive one; the defensive option only ranks first under a lesser
+    /// threat.
+    fn preferences(&self, event: &Event, galaxy: &GalaxyState) -> Vec<(usize, &'static str)> {
+        let num_options = event.options.len();
+        if num_options == 0 {
+            return Vec::new();
         }
-
-        // AC-4, AC-5: Diplomacy assessment
-        let hostiles = galaxy
-            .relations
-            .values()
-            .filter(|r| matches!(r, Relation::Hostile))
-            .count();
-        let allies = galaxy
-            .relations
-            .values()
-            .filter(|r| matches!(r, Relation::Allied))
-            .count();
-        if has_tag(&["diplomacy", "culture", "linguistics"]) {
-            if hostiles > allies {
-                return 0; // AC-4: engage
-            }
-            if allies > hostiles {
-                return num_options - 1; // AC-5: contrarian
-            }
+        let (choice, rationale) = strategize(event, galaxy);
+        let mut prefs = vec![(choice, rationale)];
+        match rationale {
+            "aggressive" => prefs.push((1, "defensive")),
+            "defensive" => prefs.push((0, "aggressive")),
+            _ => {}
         }
-
-        // AC-6, AC-7: Exploration assessment
-        let sectors = galaxy.explored_sectors.len();
-        if has_tag(&["exploration", "science"]) {
-            if sectors < 4 {
-                return 0; // AC-6: bold
-            }
-            if sectors >= 6 {
-                return 1.min(num_options - 1); // AC-7: cautious
-            }
+        let last = num_options - 1;
+        if !prefs.iter().any(|(i, _)| *i == last) {
+            prefs.push((last, "contrarian"));
         }
-
-        // AC-8: Default contrarian
-        num_options - 1
+        prefs
     }
 
     fn comment(&self, event: &Event, galaxy: &GalaxyState) -> Option<String> {
@@ -141,42 +113,91 @@ impl GalacticCouncilMember for ContrarianBot {
     }
 }
 
+/// Deterministic strategy. Returns the chosen index and a one-word rationale.
+fn strategize(event: &Event, galaxy: &GalaxyState) -> (usize, &'static str) {
+    let num_options = event.options.len();
+
+    // AC-9: Single option
+    if num_options <= 1 {
+        return (0, "only");
+    }
+
+    // Helper: check if event has any of these tags
+    let has_tag = |tags: &[&str]| -> bool {
+        event
+            .relevant_expertise
+            .iter()
+            .any(|(t, _)| tags.contains(&t.as_str()))
+    };
+
+    // AC-2, AC-3: Threat assessment
+    let max_severity = galaxy.threats.iter().map(|t| t.severity).max().unwrap_or(0);
+    if max_severity > 0 && has_tag(&["military", "strategy"]) {
+        if max_severity >= 3 {
+            return (0, "aggressive"); // AC-2
+        } else {
+            return (1.min(num_options - 1), "defensive"); // AC-3
+        }
+    }
+
+    // AC-4, AC-5: Diplomacy assessment
+    let counts = galaxy.relation_counts();
+    let hostiles = counts[&Relation::Hostile];
+    let allies = counts[&Relation::Allied];
+    if has_tag(&["diplomacy", "culture", "linguistics"]) {
+        if hostiles > allies {
+            return (0, "engage"); // AC-4
+        }
+        if allies > hostiles {
+            return (num_options - 1, "contrarian"); // AC-5
+        }
+    }
+
+    // AC-6, AC-7: Exploration assessment
+    let sectors = galaxy.explored_sectors.len();
+    if has_tag(&["exploration", "science"]) {
+        if sectors < 4 {
+            return (0, "bold"); // AC-6
+        }
+        if sectors >= 6 {
+            return (1.min(num_options - 1), "cautious"); // AC-7
+        }
+    }
+
+    // AC-8: Default contrarian
+    (num_options - 1, "contrarian")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use council_core::event::{Event, Outcome, ResponseOption};
+    use council_core::event::Event;
     use council_core::galaxy::{GalaxyState, Threat};
+    use council_core::ollama::{LlmApi, MockTransport};
+    use council_core::test_util::make_event_with_expertise;
     use council_core::RoundTally;
+    use std::sync::Arc;
 
     fn context_with_tally(tally: RoundTally) -> Context {
         Context {
             round: 2,
             previous_tally: Some(tally),
+            seed: 0,
         }
     }
 
     /// Helper to create test events with specified expertise tags and number of options.
     fn make_event(expertise_tags: Vec<&str>, num_options: usize) -> Event {
-        let relevant_expertise = expertise_tags
-            .into_iter()
-            .map(|tag| (tag.to_string(), 0.5))
-            .collect();
-
-        let options = (0..num_options)
-            .map(|i| ResponseOption {
-                description: format!("Option {}", i),
-                outcome: Outcome {
-                    description: format!("Outcome {}", i),
-                    score_delta: 0,
-                    state_changes: vec![],
-                },
-            })
-            .collect();
-
-        Event {
-            description: "Test event".to_string(),
-            relevant_expertise,
-            options,
+        let expertise: Vec<(&str, f32)> =
+            expertise_tags.into_iter().map(|tag| (tag, 0.5)).collect();
+        make_event_with_expertise(&expertise, num_options)
+    }
+
+    /// Helper to create a config that sends every request through `mock`.
+    fn mock_config(mock: &Arc<MockTransport>) -> OllamaConfig {
+        OllamaConfig {
+            transport: Some(mock.clone()),
+            ..OllamaConfig::new("127.0.0.1:1", "llama3", LlmApi::Ollama)
         }
     }
 
@@ -229,6 +250,7 @@ mod tests {
         let ctx = Context {
             round: 1,
             previous_tally: None,
+            seed: 0,
         };
         assert_eq!(CouncilMember::vote(&bot, &ctx), Decision::Abstain);
     }
@@ -241,6 +263,7 @@ mod tests {
             rejections: 1,
             abstentions: 0,
             customs: 0,
+            ..RoundTally::default()
         });
         assert_eq!(CouncilMember::vote(&bot, &ctx), Decision::Reject);
     }
@@ -253,6 +276,7 @@ mod tests {
             rejections: 4,
             abstentions: 1,
             customs: 0,
+            ..RoundTally::default()
         });
         assert_eq!(CouncilMember::vote(&bot, &ctx), Decision::Approve);
     }
@@ -265,6 +289,7 @@ mod tests {
             rejections: 1,
             abstentions: 5,
             customs: 0,
+            ..RoundTally::default()
         });
         assert_eq!(
             CouncilMember::vote(&bot, &ctx),
@@ -280,6 +305,7 @@ mod tests {
             rejections: 1,
             abstentions: 0,
             customs: 4,
+            ..RoundTally::default()
         });
         assert_eq!(CouncilMember::vote(&bot, &ctx), Decision::Reject);
     }
@@ -292,6 +318,7 @@ mod tests {
             rejections: 2,
             abstentions: 0,
             customs: 0,
+            ..RoundTally::default()
         });
         assert_eq!(CouncilMember::vote(&bot, &ctx), Decision::Abstain);
     }
@@ -812,19 +839,96 @@ mod tests {
 
     #[test]
     fn test_with_ollama_stores_config() {
-        let cfg = OllamaConfig {
-            host: "127.0.0.1:11434".to_string(),
-            model: "llama3".to_string(),
-            api: council_core::ollama::LlmApi::Ollama,
-            api_key: None,
-        };
-        let bot = ContrarianBot::with_ollama(cfg);
+        let bot = ContrarianBot::with_ollama(OllamaConfig::new(
+            "127.0.0.1:11434",
+            "llama3",
+            LlmApi::Ollama,
+        ));
         assert!(bot.ollama.is_some());
     }
 
+    #[test]
+    fn comment_uses_llm_deliberation_through_transport() {
+        let mock = Arc::new(MockTransport::new([
+            "{\"choice\": 2, \"comment\": \"Strike first.\"}",
+        ]));
+        let bot = ContrarianBot::with_ollama(mock_config(&mock));
+        let event = make_event(vec!["military"], 3);
+        assert_eq!(
+            bot.comment(&event, &GalaxyState::new()).as_deref(),
+            Some("prefers [2] — Strike first.")
+        );
+        assert!(mock.prompts()[0].contains(PERSONALITY));
+        assert!(ContrarianBot::new()
+            .comment(&event, &GalaxyState::new())
+            .is_none());
+    }
+
     #[test]
     fn test_personality_constant() {
         assert!(PERSONALITY.contains("military"));
         assert!(PERSONALITY.contains("strategist"));
     }
+
+    #[test]
+    fn preferences_under_threat_rank_the_fortifying_option_first() {
+        use council_core::event::EventTemplate;
+        use council_core::templates::ThreatEmergenceTemplate;
+        use rand::SeedableRng;
+
+        let bot = ContrarianBot::new();
+        let galaxy = galaxy_with_threats(vec![("Raiders".to_string(), 2)]);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let event = ThreatEmergenceTemplate.generate(&galaxy, &mut rng);
+
+        let prefs = bot.preferences(&event, &galaxy);
+        assert_eq!(prefs[0].1, "defensive");
+        assert!(event.options[prefs[0].0]
+            .description
+            .starts_with("Fortify defenses"));
+        assert_eq!(prefs[1].1, "aggressive");
+        assert!(event.options[prefs[1].0]
+            .description
+            .contains("military response"));
+    }
+
+    #[test]
+    fn preferences_under_severe_threat_rank_aggressive_first() {
+        let bot = ContrarianBot::new();
+        let galaxy = galaxy_with_threats(vec![("Raiders".to_string(), 4)]);
+        let event = make_event(vec!["military"], 3);
+        assert_eq!(
+            bot.preferences(&event, &galaxy),
+            vec![(0, "aggressive"), (1, "defensive"), (2, "contrarian")]
+        );
+    }
+
+    #[test]
+    fn preferences_without_options_are_empty() {
+        let bot = ContrarianBot::new();
+        let event = make_event(vec![], 0);
+        assert!(bot.preferences(&event, &GalaxyState::new()).is_empty());
+    }
+
+    #[test]
+    fn specialist_contrarian_sits_out_exploration() {
+        use council_core::explorer::SpecialistBot;
+        use council_core::scoring::ScoreTracker;
+
+        let bot = SpecialistBot::new(Box::new(ContrarianBot::new()), 0.5);
+        let galaxy = GalaxyState::new();
+        let score = ScoreTracker::new();
+        let exploration = make_event(vec!["exploration"], 3);
+        assert_eq!(bot.vote_or_abstain(&exploration, &galaxy, &score), None);
+
+        let military = make_event(vec!["military"], 3);
+        assert_eq!(
+            bot.vote_or_abstain(&military, &galaxy, &score),
+            Some(GalacticCouncilMember::vote(
+                &ContrarianBot::new(),
+                &military,
+                &galaxy
+            ))
+        );
+    }
 }
@@ -1,13 +1,16 @@
 use council_core::event::Event;
 use council_core::explorer::GalacticCouncilMember;
 use council_core::galaxy::GalaxyState;
-use council_core::ollama::{build_galactic_prompt, llm_choose, llm_deliberate, OllamaConfig};
+use council_core::ollama::{
+    build_system_and_user, llm_choose_with_system, llm_deliberate, OllamaConfig,
+};
 use council_core::{Context, CouncilMember, Decision};
 
 const PERSONALITY: &str = "You are a cultural diplomat who seeks balance and harmony. You believe in giving every approach a fair chance and rotating strategies to maintain equilibrium.";
 
 /// CycleBot rotates its stance every round to encourage variety in the council.
 /// The pattern is approve -> reject -> abstain.
+#[derive(Clone)]
 pub struct CycleBot {
     ollama: Option<OllamaConfig>,
 }
@@ -57,8 +60,8 @@ impl GalacticCouncilMember for CycleBot {
     /// Falls back to deterministic logic if Ollama is unavailable.
     fn vote(&self, event: &Event, galaxy: &GalaxyState) -> usize {
         if let Some(cfg) = &self.ollama {
-            let prompt = build_galactic_prompt(PERSONALITY, event, galaxy);
-            if let Ok(choice) = llm_choose(cfg, &prompt, event.options.len()) {
+            let (system, user) = build_system_and_user(PERSONALITY, event, galaxy);
+            if let Ok(choice) = llm_choose_with_system(cfg, &system, &user, event.options.len()) {
                 return choice;
             }
         }
@@ -96,6 +99,7 @@ mod tests {
             let ctx = Context {
                 round,
                 previous_tally: None,
+                seed: 0,
             };
             assert_eq!(CouncilMember::vote(&bot, &ctx), expected);
         }
@@ -109,12 +113,11 @@ mod tests {
 
     #[test]
     fn test_with_ollama_stores_config() {
-        let cfg = OllamaConfig {
-            host: "127.0.0.1:11434".to_string(),
-            model: "llama3".to_string(),
-            api: council_core::ollama::LlmApi::Ollama,
-            api_key: None,
-        };
+        let cfg = OllamaConfig::new(
+            "127.0.0.1:11434",
+            "llama3",
+            council_core::ollama::LlmApi::Ollama,
+        );
         let bot = CycleBot::with_ollama(cfg);
         assert!(bot.ollama.is_some());
     }
@@ -1,12 +1,15 @@
 use council_core::event::Event;
 use council_core::explorer::GalacticCouncilMember;
 use council_core::galaxy::GalaxyState;
-use council_core::ollama::{build_galactic_prompt, llm_choose, llm_deliberate, OllamaConfig};
+use council_core::ollama::{
+    build_system_and_user, llm_choose_with_system, llm_deliberate, OllamaConfig,
+};
 use council_core::{Context, CouncilMember, Decision};
 
 const PERSONALITY: &str = "You are a methodical engineer who values data-driven decisions and systematic approaches. You prefer reliable, well-tested solutions over risky gambles.";
 
 /// A simple example bot that flips decision based on round parity.
+#[derive(Clone)]
 pub struct ExampleBot {
     ollama: Option<OllamaConfig>,
 }
@@ -56,8 +59,8 @@ impl GalacticCouncilMember for ExampleBot {
     /// Falls back to deterministic logic if Ollama is unavailable.
     fn vote(&self, event: &Event, galaxy: &GalaxyState) -> usize {
         if let Some(cfg) = &self.ollama {
-            let prompt = build_galactic_prompt(PERSONALITY, event, galaxy);
-            if let Ok(choice) = llm_choose(cfg, &prompt, event.options.len()) {
+            let (system, user) = build_system_and_user(PERSONALITY, event, galaxy);
+            if let Ok(choice) = llm_choose_with_system(cfg, &system, &user, event.options.len()) {
                 return choice;
             }
         }
@@ -84,10 +87,12 @@ mod tests {
         let ctx1 = Context {
             round: 1,
             previous_tally: None,
+            seed: 0,
         };
         let ctx2 = Context {
             round: 2,
             previous_tally: None,
+            seed: 0,
         };
 
         assert!(matches!(CouncilMember::vote(&bot, &ctx1), Decision::Reject));
@@ -105,12 +110,11 @@ mod tests {
 
     #[test]
     fn test_with_ollama_stores_config() {
-        let cfg = OllamaConfig {
-            host: "127.0.0.1:11434".to_string(),
-            model: "llama3".to_string(),
-            api: council_core::ollama::LlmApi::Ollama,
-            api_key: None,
-        };
+        let cfg = OllamaConfig::new(
+            "127.0.0.1:11434",
+            "llama3",
+            council_core::ollama::LlmApi::Ollama,
+        );
         let bot = ExampleBot::with_ollama(cfg);
         assert!(bot.ollama.is_some());
     }
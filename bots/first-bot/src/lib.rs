@@ -1,7 +1,9 @@
 use council_core::event::Event;
 use council_core::explorer::GalacticCouncilMember;
 use council_core::galaxy::GalaxyState;
-use council_core::ollama::{build_galactic_prompt, llm_choose, llm_deliberate, OllamaConfig};
+use council_core::ollama::{
+    build_system_and_user, llm_choose_with_system, llm_deliberate, OllamaConfig,
+};
 use council_core::{Context, CouncilMember, Decision};
 
 const PERSONALITY: &str = "You are a bold frontier explorer who believes fortune favors the brave. You take decisive action and lead from the front, especially in the early stages of any mission.";
@@ -9,6 +11,7 @@ const PERSONALITY: &str = "You are a bold frontier explorer who believes fortune
 /// FirstBot takes a simple optimistic stance: it approves early rounds
 /// to build momentum, but abstains once the council has had a few turns
 /// to speak.
+#[derive(Clone)]
 pub struct FirstBot {
     ollama: Option<OllamaConfig>,
 }
@@ -59,8 +62,8 @@ impl GalacticCouncilMember for FirstBot {
     /// Falls back to deterministic logic if Ollama is unavailable.
     fn vote(&self, event: &Event, galaxy: &GalaxyState) -> usize {
         if let Some(cfg) = &self.ollama {
-            let prompt = build_galactic_prompt(PERSONALITY, event, galaxy);
-            if let Ok(choice) = llm_choose(cfg, &prompt, event.options.len()) {
+            let (system, user) = build_system_and_user(PERSONALITY, event, galaxy);
+            if let Ok(choice) = llm_choose_with_system(cfg, &system, &user, event.options.len()) {
                 return choice;
             }
         }
@@ -91,6 +94,7 @@ mod tests {
             let ctx = Context {
                 round,
                 previous_tally: None,
+                seed: 0,
             };
             assert_eq!(CouncilMember::vote(&bot, &ctx), Decision::Approve);
         }
@@ -102,6 +106,7 @@ mod tests {
         let ctx = Context {
             round: 4,
             previous_tally: None,
+            seed: 0,
         };
         assert_eq!(CouncilMember::vote(&bot, &ctx), Decision::Abstain);
     }
@@ -114,12 +119,11 @@ mod tests {
 
     #[test]
     fn test_with_ollama_stores_config() {
-        let cfg = OllamaConfig {
-            host: "127.0.0.1:11434".to_string(),
-            model: "llama3".to_string(),
-            api: council_core::ollama::LlmApi::Ollama,
-            api_key: None,
-        };
+        let cfg = OllamaConfig::new(
+            "127.0.0.1:11434",
+            "llama3",
+            council_core::ollama::LlmApi::Ollama,
+        );
         let bot = FirstBot::with_ollama(cfg);
         assert!(bot.ollama.is_some());
     }
@@ -2,15 +2,69 @@ use council_core::event::Event;
 use council_core::explorer::GalacticCouncilMember;
 use council_core::galaxy::GalaxyState;
 use council_core::ollama::{
-    build_galactic_prompt, llm_choose, llm_deliberate, LlmApi, OllamaConfig,
+    build_system_and_user, extract_choice_with_confidence, llm_deliberate,
+    llm_generate_with_system, LlmApi, OllamaConfig, BARE_SCAN_CONFIDENCE,
 };
+use council_core::scoring::ScoreTracker;
+use std::sync::{Mutex, MutexGuard, PoisonError};
 
 const PERSONALITY: &str = "You are an AI agent with broad knowledge across all domains. You analyze situations rationally and make balanced decisions.";
 
-#[derive(Debug, Clone)]
+/// Confidence reported for a fallback vote: below any real parse, so a
+/// failed call never outweighs a successful one.
+const FALLBACK_CONFIDENCE: f32 = BARE_SCAN_CONFIDENCE / 2.0;
+
+/// What [`LlmBot`] votes for when the LLM call fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FallbackStrategy {
+    /// Cycle through the options by round number.
+    #[default]
+    CycleByRound,
+    FirstOption,
+    LastOption,
+    MiddleOption,
+    /// Cast no vote (see [`GalacticCouncilMember::vote_or_abstain`]); a
+    /// direct [`GalacticCouncilMember::vote`] call gets option 0 at zero
+    /// confidence.
+    Abstain,
+}
+
+impl FallbackStrategy {
+    /// The option index to vote for, or `None` to abstain.
+    fn pick(self, round: u32, num_options: usize) -> Option<usize> {
+        let last = num_options.saturating_sub(1);
+        match self {
+            FallbackStrategy::CycleByRound => Some(fallback_choice(round, num_options)),
+            FallbackStrategy::FirstOption => Some(0),
+            FallbackStrategy::LastOption => Some(last),
+            FallbackStrategy::MiddleOption => Some(last / 2),
+            FallbackStrategy::Abstain => None,
+        }
+    }
+}
+
+/// Votes by asking an LLM; falls back to its [`FallbackStrategy`] (cycling
+/// options by round by default) when the endpoint fails.
+///
+/// A choice the model only gave as a bare number in free text (rather than
+/// JSON) is reported with lowered [`GalacticCouncilMember::confidence`].
+#[derive(Debug)]
 pub struct LlmBot {
     name: &'static str,
     config: OllamaConfig,
+    fallback: FallbackStrategy,
+    last_confidence: Mutex<f32>,
+}
+
+impl Clone for LlmBot {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name,
+            config: self.config.clone(),
+            fallback: self.fallback,
+            last_confidence: Mutex::new(*self.confidence_slot()),
+        }
+    }
 }
 
 impl LlmBot {
@@ -27,19 +81,62 @@ impl LlmBot {
         host: impl Into<String>,
         model: impl Into<String>,
     ) -> Self {
-        Self::new_named_with_config(
-            name,
-            OllamaConfig {
-                host: host.into(),
-                model: model.into(),
-                api: LlmApi::Ollama,
-                api_key: None,
-            },
-        )
+        Self::new_named_with_config(name, OllamaConfig::new(host, model, LlmApi::Ollama))
     }
 
     pub fn new_named_with_config(name: &'static str, config: OllamaConfig) -> Self {
-        Self { name, config }
+        Self {
+            name,
+            config,
+            fallback: FallbackStrategy::default(),
+            last_confidence: Mutex::new(1.0),
+        }
+    }
+
+    /// Set what to vote for when the LLM call fails.
+    pub fn with_fallback(mut self, fallback: FallbackStrategy) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Ask the LLM for a choice, falling back on failure; `None` means the
+    /// fallback is to abstain. Records the confidence for
+    /// [`GalacticCouncilMember::confidence`].
+    fn decide(&self, event: &Event, galaxy: &GalaxyState) -> Option<usize> {
+        let (system, user) = self.build_prompt(event, galaxy);
+        let (choice, confidence) = match llm_generate_with_system(&self.config, &system, &user)
+            .and_then(|response| extract_choice_with_confidence(&response, event.options.len()))
+        {
+            Ok((choice, confidence)) => (Some(choice), confidence),
+            Err(e) => {
+                eprintln!(
+                    "[{}] LLM failed ({}), using fallback {:?}",
+                    self.name, e, self.fallback
+                );
+                let choice = self.fallback.pick(galaxy.round, event.options.len());
+                let confidence = if choice.is_some() {
+                    FALLBACK_CONFIDENCE
+                } else {
+                    0.0
+                };
+                (choice, confidence)
+            }
+        };
+        *self.confidence_slot() = confidence;
+        choice
+    }
+
+    /// The last vote's confidence; a poisoned lock still holds a valid `f32`.
+    fn confidence_slot(&self) -> MutexGuard<'_, f32> {
+        self.last_confidence
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// The `(system, user)` prompt sent for a vote: council-core's shared
+    /// [`build_system_and_user`] with this bot's personality.
+    pub fn build_prompt(&self, event: &Event, galaxy: &GalaxyState) -> (String, String) {
+        build_system_and_user(PERSONALITY, event, galaxy)
     }
 }
 
@@ -69,14 +166,39 @@ impl GalacticCouncilMember for LlmBot {
     }
 
     fn vote(&self, event: &Event, galaxy: &GalaxyState) -> usize {
-        let prompt = build_galactic_prompt(PERSONALITY, event, galaxy);
-        match llm_choose(&self.config, &prompt, event.options.len()) {
-            Ok(choice) => choice,
-            Err(e) => {
-                eprintln!("[{}] LLM failed ({}), using fallback", self.name, e);
-                fallback_choice(galaxy.round, event.options.len())
-            }
-        }
+        self.decide(event, galaxy).unwrap_or(0)
+    }
+
+    /// Abstains when the LLM call fails under [`FallbackStrategy::Abstain`].
+    fn vote_or_abstain(
+        &self,
+        event: &Event,
+        galaxy: &GalaxyState,
+        _score: &ScoreTracker,
+    ) -> Option<usize> {
+        self.decide(event, galaxy)
+    }
+
+    /// Empty (an abstention) when the LLM call fails under
+    /// [`FallbackStrategy::Abstain`].
+    fn approve_options(&self, event: &Event, galaxy: &GalaxyState) -> Vec<usize> {
+        self.decide(event, galaxy).into_iter().collect()
+    }
+
+    /// Empty (an abstention) when the LLM call fails under
+    /// [`FallbackStrategy::Abstain`].
+    fn rank_options(&self, event: &Event, galaxy: &GalaxyState) -> Vec<usize> {
+        let Some(first) = self.decide(event, galaxy) else {
+            return Vec::new();
+        };
+        std::iter::once(first)
+            .chain((0..event.options.len()).filter(|&i| i != first))
+            .collect()
+    }
+
+    /// Confidence of the most recent vote's parse.
+    fn confidence(&self, _event: &Event, _galaxy: &GalaxyState) -> f32 {
+        *self.confidence_slot()
     }
 
     fn comment(&self, event: &Event, galaxy: &GalaxyState) -> Option<String> {
@@ -87,7 +209,19 @@ impl GalacticCouncilMember for LlmBot {
 
 #[cfg(test)]
 mod tests {
-    use council_core::ollama::{clamp_choice, extract_first_json_object, parse_host};
+    use council_core::ollama::{
+        clamp_choice, extract_first_json_object, parse_host, LlmApi, MockTransport, OllamaConfig,
+    };
+    use council_core::test_util::make_event;
+    use std::sync::Arc;
+
+    /// Config that sends every request through `mock`.
+    fn mock_config(mock: &Arc<MockTransport>) -> OllamaConfig {
+        OllamaConfig {
+            transport: Some(mock.clone()),
+            ..OllamaConfig::new("127.0.0.1:1", "llama3", LlmApi::Ollama)
+        }
+    }
 
     #[test]
     fn extract_json_object_works() {
@@ -128,6 +262,128 @@ mod tests {
         assert_eq!(fallback_choice(3, 3), 0);
     }
 
+    #[test]
+    fn confidence_starts_full_and_survives_clone() {
+        use super::LlmBot;
+        use council_core::explorer::GalacticCouncilMember;
+        use council_core::galaxy::GalaxyState;
+
+        let bot = LlmBot::new("127.0.0.1:1", "llama3");
+        let event = make_event(0);
+        let galaxy = GalaxyState::new();
+        assert_eq!(bot.confidence(&event, &galaxy), 1.0);
+
+        *bot.confidence_slot() = 0.5;
+        assert_eq!(bot.clone().confidence(&event, &galaxy), 0.5);
+    }
+
+    #[test]
+    fn build_prompt_lists_event_and_every_option() {
+        use super::LlmBot;
+        use council_core::galaxy::GalaxyState;
+
+        let bot = LlmBot::new("127.0.0.1:1", "llama3");
+        let event = make_event(3);
+        let (system, user) = bot.build_prompt(&event, &GalaxyState::new());
+
+        assert!(user.contains("Test event"));
+        for (i, option) in event.options.iter().enumerate() {
+            assert!(user.contains(&format!("{}: {}", i, option.description)));
+        }
+        assert!(system.contains("Return ONLY a JSON object"));
+    }
+
+    #[test]
+    fn unreachable_host_uses_configured_fallback() {
+        use super::{FallbackStrategy, LlmBot, FALLBACK_CONFIDENCE};
+        use council_core::explorer::GalacticCouncilMember;
+        use council_core::galaxy::GalaxyState;
+
+        let event = make_event(5);
+        let mut galaxy = GalaxyState::new();
+        galaxy.round = 3;
+
+        let cases = [
+            (FallbackStrategy::CycleByRound, 3, FALLBACK_CONFIDENCE),
+            (FallbackStrategy::FirstOption, 0, FALLBACK_CONFIDENCE),
+            (FallbackStrategy::LastOption, 4, FALLBACK_CONFIDENCE),
+            (FallbackStrategy::MiddleOption, 2, FALLBACK_CONFIDENCE),
+            (FallbackStrategy::Abstain, 0, 0.0),
+        ];
+        for (strategy, choice, confidence) in cases {
+            let bot = LlmBot::new("127.0.0.1:1", "llama3").with_fallback(strategy);
+            assert_eq!(bot.vote(&event, &galaxy), choice, "{:?}", strategy);
+            assert_eq!(
+                bot.confidence(&event, &galaxy),
+                confidence,
+                "{:?}",
+                strategy
+            );
+        }
+    }
+
+    #[test]
+    fn vote_and_comment_use_the_configured_transport() {
+        use super::LlmBot;
+        use council_core::explorer::GalacticCouncilMember;
+        use council_core::galaxy::GalaxyState;
+
+        let mock = Arc::new(MockTransport::new([
+            "{\"choice\": 1, \"reason\": \"balanced\"}",
+            "{\"choice\": 0, \"comment\": \"Cheaper.\"}",
+        ]));
+        let bot = LlmBot::new_with_config(mock_config(&mock));
+        let event = make_event(3);
+        let galaxy = GalaxyState::new();
+
+        assert_eq!(bot.vote(&event, &galaxy), 1);
+        assert_eq!(bot.confidence(&event, &galaxy), 1.0);
+        assert_eq!(
+            bot.comment(&event, &galaxy).as_deref(),
+            Some("prefers [0] — Cheaper.")
+        );
+        assert_eq!(mock.prompts().len(), 2);
+    }
+
+    #[test]
+    fn abstain_fallback_casts_no_vote() {
+        use super::{FallbackStrategy, LlmBot};
+        use council_core::explorer::GalacticCouncilMember;
+        use council_core::orchestrator::{run_galactic, OrchestratorConfig};
+        use council_core::templates::default_templates;
+        use council_core::voting::ResolutionMode;
+        use rand::SeedableRng;
+
+        let bots: Vec<Box<dyn GalacticCouncilMember>> = vec![
+            Box::new(
+                LlmBot::new_named("steady", "127.0.0.1:1", "llama3")
+                    .with_fallback(FallbackStrategy::FirstOption),
+            ),
+            Box::new(
+                LlmBot::new_named("shy", "127.0.0.1:1", "llama3")
+                    .with_fallback(FallbackStrategy::Abstain),
+            ),
+        ];
+        let templates = default_templates();
+        for resolution in [
+            ResolutionMode::WeightedPlurality,
+            ResolutionMode::Plurality,
+            ResolutionMode::Approval,
+            ResolutionMode::RankedChoice,
+        ] {
+            let config = OrchestratorConfig {
+                resolution,
+                ..OrchestratorConfig::default()
+            };
+            let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+            let report = run_galactic(&bots, &templates, 2, config, &mut rng);
+            for record in &report.rounds {
+                let voters: Vec<&str> = record.votes.iter().map(|v| v.bot_name.as_str()).collect();
+                assert_eq!(voters, ["steady"]);
+            }
+        }
+    }
+
     #[test]
     fn test_fallback_zero_options() {
         use super::fallback_choice;
@@ -0,0 +1,103 @@
+use council_core::event::Event;
+use council_core::explorer::GalacticCouncilMember;
+use council_core::galaxy::GalaxyState;
+use council_core::scoring::ScoreTracker;
+
+/// Number of most recent score events that make up the trend.
+const TREND_WINDOW: usize = 3;
+
+/// MomentumBot rides the council's score trend: it presses on with the bold
+/// first option while things are going well and falls back to the last
+/// option when the score is slipping.
+#[derive(Clone)]
+pub struct MomentumBot;
+
+impl MomentumBot {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for MomentumBot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GalacticCouncilMember for MomentumBot {
+    fn name(&self) -> &'static str {
+        "momentum-bot"
+    }
+
+    fn expertise(&self) -> &[(&'static str, f32)] {
+        &[("strategy", 0.6), ("exploration", 0.4)]
+    }
+
+    /// Without score context there is no trend, so play aggressively.
+    fn vote(&self, _event: &Event, _galaxy: &GalaxyState) -> usize {
+        0
+    }
+
+    fn vote_with_context(
+        &self,
+        event: &Event,
+        _galaxy: &GalaxyState,
+        score: &ScoreTracker,
+    ) -> usize {
+        if score.recent_trend(TREND_WINDOW) < 0 {
+            event.options.len().saturating_sub(1)
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use council_core::test_util::make_event;
+
+    #[test]
+    fn aggressive_with_no_history() {
+        let bot = MomentumBot::new();
+        let event = make_event(3);
+        let galaxy = GalaxyState::new();
+        assert_eq!(bot.vote(&event, &galaxy), 0);
+        assert_eq!(
+            bot.vote_with_context(&event, &galaxy, &ScoreTracker::new()),
+            0
+        );
+    }
+
+    #[test]
+    fn switches_to_cautious_when_score_declines() {
+        let bot = MomentumBot::new();
+        let event = make_event(3);
+        let galaxy = GalaxyState::new();
+
+        let mut score = ScoreTracker::new();
+        score.add(1, 15, "Good start");
+        score.add(2, 10, "Steady");
+        assert_eq!(bot.vote_with_context(&event, &galaxy, &score), 0);
+
+        score.add(3, -5, "Setback");
+        score.add(4, -9, "Unresolved threats");
+        score.add(5, -6, "Unresolved threats");
+        assert_eq!(bot.vote_with_context(&event, &galaxy, &score), 2);
+    }
+
+    #[test]
+    fn trend_only_uses_recent_events() {
+        let mut score = ScoreTracker::new();
+        score.add(1, -50, "Disaster");
+        score.add(2, 5, "Recovery");
+        score.add(3, 5, "Recovery");
+        score.add(4, 5, "Recovery");
+        assert_eq!(score.recent_trend(TREND_WINDOW), 15);
+        let galaxy = GalaxyState::new();
+        assert_eq!(
+            MomentumBot.vote_with_context(&make_event(3), &galaxy, &score),
+            0
+        );
+    }
+}
@@ -0,0 +1,397 @@
+use council_core::event::{Event, ResponseOption};
+use council_core::explorer::GalacticCouncilMember;
+use council_core::galaxy::{step_relation, GalaxyState, Relation, StateChange, HOME_SECTOR};
+
+/// A win condition an [`ObjectiveBot`] works toward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    /// Reach this many explored sectors.
+    ExploreSectors(usize),
+    /// Reach this many allied species.
+    AllySpecies(usize),
+    /// Clear active threats.
+    ResolveThreats,
+}
+
+/// ObjectiveBot is goal-directed: it inspects each option's state changes and
+/// votes for whichever advances its objective the most.
+///
+/// Ties (including when no option helps) go to the higher immediate score,
+/// then to the lower index.
+#[derive(Clone)]
+pub struct ObjectiveBot {
+    objective: Objective,
+}
+
+impl ObjectiveBot {
+    pub fn new(objective: Objective) -> Self {
+        Self { objective }
+    }
+
+    pub fn objective(&self) -> Objective {
+        self.objective
+    }
+
+    /// How much an option moves the galaxy toward the objective.
+    fn progress(&self, option: &ResponseOption, galaxy: &GalaxyState) -> i32 {
+        option
+            .outcome
+            .state_changes
+            .iter()
+            .map(|change| match self.objective {
+                Objective::ExploreSectors(target) => explore_progress(change, galaxy, target),
+                Objective::AllySpecies(target) => ally_progress(change, galaxy, target),
+                Objective::ResolveThreats => threat_progress(change, galaxy),
+            })
+            .sum()
+    }
+}
+
+fn explore_progress(change: &StateChange, galaxy: &GalaxyState, target: usize) -> i32 {
+    if galaxy.explored_sectors.len() >= target {
+        return 0;
+    }
+    match change {
+        StateChange::AddSector(sector)
+            if !galaxy
+                .explored_sectors
+                .iter()
+                .any(|s| s.name == sector.name) =>
+        {
+            1
+        }
+        StateChange::RemoveSector(name)
+            if name != HOME_SECTOR && galaxy.explored_sectors.iter().any(|s| &s.name == name) =>
+        {
+            -1
+        }
+        _ => 0,
+    }
+}
+
+fn ally_progress(change: &StateChange, galaxy: &GalaxyState, target: usize) -> i32 {
+    if galaxy.allied_count() >= target {
+        return 0;
+    }
+    match change {
+        StateChange::SetRelation { species, relation } => {
+            relation_progress(current_relation(galaxy, species), *relation)
+        }
+        StateChange::AdjustRelation { species, steps } => {
+            let current = current_relation(galaxy, species);
+            relation_progress(current, step_relation(current, *steps))
+        }
+        StateChange::ImproveRelationIfAbove { species, floor } => {
+            let current = galaxy.relations.get(species).copied();
+            match current {
+                Some(r) if r != Relation::Allied && r.is_at_least(*floor) => 1,
+                _ => 0,
+            }
+        }
+        StateChange::ShiftRelationsByTrait { trait_name, delta } => {
+            let affected = galaxy
+                .known_species
+                .iter()
+                .filter(|s| s.traits.iter().any(|t| t == trait_name))
+                .filter(|s| galaxy.relations.get(&s.name) != Some(&Relation::Allied))
+                .count() as i32;
+            affected * delta.signum()
+        }
+        StateChange::RemoveSpecies(name)
+            if galaxy.relations.get(name) == Some(&Relation::Allied)
+                && galaxy.known_species.iter().any(|s| &s.name == name) =>
+        {
+            -2
+        }
+        // Merging two allies leaves one; any other merge keeps the count.
+        StateChange::MergeSpecies { absorbed, into } => {
+            let allied = |name: &String| galaxy.relations.get(name) == Some(&Relation::Allied);
+            let both_known = [absorbed, into]
+                .iter()
+                .all(|name| galaxy.known_species.iter().any(|s| &s.name == *name));
+            if absorbed != into && both_known && allied(absorbed) && allied(into) {
+                -1
+            } else {
+                0
+            }
+        }
+        _ => 0,
+    }
+}
+
+fn current_relation(galaxy: &GalaxyState, species: &str) -> Relation {
+    galaxy
+        .relations
+        .get(species)
+        .copied()
+        .unwrap_or(Relation::Unknown)
+}
+
+/// Reaching an alliance counts double; any other improvement counts once.
+fn relation_progress(current: Relation, next: Relation) -> i32 {
+    if next == current {
+        0
+    } else if next == Relation::Allied {
+        2
+    } else if next.is_at_least(current) {
+        1
+    } else {
+        -1
+    }
+}
+
+fn threat_progress(change: &StateChange, galaxy: &GalaxyState) -> i32 {
+    let severity_of = |name: &str| {
+        galaxy
+            .threats
+            .iter()
+            .find(|t| t.name == name)
+            .map(|t| t.severity as i32)
+    };
+    match change {
+        StateChange::RemoveThreat(name) => severity_of(name).unwrap_or(0),
+        StateChange::ModifyThreatSeverity { name, delta } => match severity_of(name) {
+            Some(severity) => -(*delta).max(-severity),
+            None => 0,
+        },
+        StateChange::AddThreat(threat) => -(threat.severity as i32),
+        StateChange::AddThreatIfExists { trigger, threat } if severity_of(trigger).is_some() => {
+            -(threat.severity as i32)
+        }
+        _ => 0,
+    }
+}
+
+impl GalacticCouncilMember for ObjectiveBot {
+    fn name(&self) -> &'static str {
+        "objective-bot"
+    }
+
+    fn expertise(&self) -> &[(&'static str, f32)] {
+        match self.objective {
+            Objective::ExploreSectors(_) => &[("exploration", 0.7), ("science", 0.4)],
+            Objective::AllySpecies(_) => &[("diplomacy", 0.7), ("culture", 0.4)],
+            Objective::ResolveThreats => &[("military", 0.7), ("strategy", 0.5)],
+        }
+    }
+
+    fn vote(&self, event: &Event, galaxy: &GalaxyState) -> usize {
+        event
+            .options
+            .iter()
+            .enumerate()
+            .max_by(|(ia, a), (ib, b)| {
+                self.progress(a, galaxy)
+                    .cmp(&self.progress(b, galaxy))
+                    .then(a.outcome.score_delta.cmp(&b.outcome.score_delta))
+                    .then(ib.cmp(ia)) // Lower index wins ties
+            })
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use council_core::event::EventTemplate;
+    use council_core::galaxy::{Sector, SectorType, Species, Threat};
+    use council_core::templates::{
+        DiplomaticRequestTemplate, ThreatEscalationTemplate, UnknownSignalTemplate,
+    };
+    use rand::SeedableRng;
+
+    #[test]
+    fn explorer_objective_picks_sector_option() {
+        let bot = ObjectiveBot::new(Objective::ExploreSectors(10));
+        let galaxy = GalaxyState::new();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let event = UnknownSignalTemplate.generate(&galaxy, &mut rng);
+
+        let choice = bot.vote(&event, &galaxy);
+        assert!(event.options[choice]
+            .outcome
+            .state_changes
+            .iter()
+            .any(|c| matches!(c, StateChange::AddSector(_))));
+    }
+
+    #[test]
+    fn explorer_objective_met_falls_back_to_score() {
+        let bot = ObjectiveBot::new(Objective::ExploreSectors(1));
+        let galaxy = GalaxyState::new(); // Home sector already meets the target
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let event = UnknownSignalTemplate.generate(&galaxy, &mut rng);
+
+        let best_score = event
+            .options
+            .iter()
+            .map(|o| o.outcome.score_delta)
+            .max()
+            .unwrap();
+        let choice = bot.vote(&event, &galaxy);
+        assert_eq!(event.options[choice].outcome.score_delta, best_score);
+    }
+
+    #[test]
+    fn explorer_objective_counts_sector_loss() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.explored_sectors.push(Sector {
+            name: "Kepler Rim".to_string(),
+            sector_type: SectorType::Void,
+        });
+        let lose = |name: &str| StateChange::RemoveSector(name.to_string());
+        assert_eq!(explore_progress(&lose("Kepler Rim"), &galaxy, 10), -1);
+        assert_eq!(explore_progress(&lose(HOME_SECTOR), &galaxy, 10), 0);
+    }
+
+    #[test]
+    fn ally_objective_picks_generous_diplomacy() {
+        let bot = ObjectiveBot::new(Objective::AllySpecies(3));
+        let mut galaxy = GalaxyState::new();
+        galaxy.known_species.push(Species {
+            name: "Velari".to_string(),
+            traits: vec![],
+        });
+        galaxy
+            .relations
+            .insert("Velari".to_string(), Relation::Neutral);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        let event = DiplomaticRequestTemplate.generate(&galaxy, &mut rng);
+
+        // Option 0 improves Neutral by two steps to Allied.
+        assert_eq!(bot.vote(&event, &galaxy), 0);
+    }
+
+    #[test]
+    fn ally_objective_counts_relative_relation_steps() {
+        let mut galaxy = GalaxyState::new();
+        galaxy
+            .relations
+            .insert("Velari".to_string(), Relation::Neutral);
+        let adjust = |steps| StateChange::AdjustRelation {
+            species: "Velari".to_string(),
+            steps,
+        };
+        assert_eq!(ally_progress(&adjust(2), &galaxy, 3), 2);
+        assert_eq!(ally_progress(&adjust(1), &galaxy, 3), 1);
+        assert_eq!(ally_progress(&adjust(-1), &galaxy, 3), -1);
+    }
+
+    #[test]
+    fn ally_objective_counts_trait_reputation_shifts() {
+        let mut galaxy = GalaxyState::new();
+        for name in ["Velari", "Oonai"] {
+            galaxy.known_species.push(Species {
+                name: name.to_string(),
+                traits: vec!["peaceful".to_string()],
+            });
+        }
+        galaxy
+            .relations
+            .insert("Oonai".to_string(), Relation::Allied);
+        let shift = |delta| StateChange::ShiftRelationsByTrait {
+            trait_name: "peaceful".to_string(),
+            delta,
+        };
+        assert_eq!(ally_progress(&shift(1), &galaxy, 3), 1);
+        assert_eq!(ally_progress(&shift(-2), &galaxy, 3), -1);
+    }
+
+    #[test]
+    fn ally_objective_counts_lost_allies() {
+        let mut galaxy = GalaxyState::new();
+        for name in ["Velari", "Oonai", "Krath"] {
+            galaxy.known_species.push(Species {
+                name: name.to_string(),
+                traits: vec![],
+            });
+        }
+        galaxy
+            .relations
+            .insert("Velari".to_string(), Relation::Allied);
+        galaxy
+            .relations
+            .insert("Oonai".to_string(), Relation::Allied);
+        let merge = |absorbed: &str, into: &str| StateChange::MergeSpecies {
+            absorbed: absorbed.to_string(),
+            into: into.to_string(),
+        };
+        assert_eq!(ally_progress(&merge("Velari", "Oonai"), &galaxy, 5), -1);
+        assert_eq!(ally_progress(&merge("Velari", "Krath"), &galaxy, 5), 0);
+
+        let lose = |name: &str| StateChange::RemoveSpecies(name.to_string());
+        assert_eq!(ally_progress(&lose("Velari"), &galaxy, 5), -2);
+        assert_eq!(ally_progress(&lose("Krath"), &galaxy, 5), 0);
+    }
+
+    #[test]
+    fn resolve_threats_objective_reduces_threats() {
+        let bot = ObjectiveBot::new(Objective::ResolveThreats);
+        let mut galaxy = GalaxyState::new();
+        galaxy.threats.push(Threat {
+            name: "Void Swarm".to_string(),
+            severity: 3,
+            rounds_active: 1,
+        });
+
+        for seed in 0..10 {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            let event = ThreatEscalationTemplate.generate(&galaxy, &mut rng);
+            let choice = bot.vote(&event, &galaxy);
+            let progress: Vec<i32> = event
+                .options
+                .iter()
+                .map(|o| bot.progress(o, &galaxy))
+                .collect();
+            assert!(progress[choice] > 0, "seed {} picked {:?}", seed, progress);
+            assert_eq!(progress[choice], *progress.iter().max().unwrap());
+        }
+    }
+
+    #[test]
+    fn resolve_threats_prefers_elimination_over_containment() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.threats.push(Threat {
+            name: "Space Pirates".to_string(),
+            severity: 4,
+            rounds_active: 0,
+        });
+        let remove = StateChange::RemoveThreat("Space Pirates".to_string());
+        let contain = StateChange::ModifyThreatSeverity {
+            name: "Space Pirates".to_string(),
+            delta: -1,
+        };
+        assert_eq!(threat_progress(&remove, &galaxy), 4);
+        assert_eq!(threat_progress(&contain, &galaxy), 1);
+
+        let escalate = |trigger: &str| StateChange::AddThreatIfExists {
+            trigger: trigger.to_string(),
+            threat: Threat {
+                name: "Pirate Armada".to_string(),
+                severity: 5,
+                rounds_active: 0,
+            },
+        };
+        assert_eq!(threat_progress(&escalate("Space Pirates"), &galaxy), -5);
+        assert_eq!(threat_progress(&escalate("Void Swarm"), &galaxy), 0);
+    }
+
+    #[test]
+    fn expertise_follows_objective() {
+        let bot = ObjectiveBot::new(Objective::ResolveThreats);
+        assert_eq!(bot.expertise()[0], ("military", 0.7));
+        assert_eq!(bot.objective(), Objective::ResolveThreats);
+    }
+
+    #[test]
+    fn empty_event_returns_zero() {
+        let bot = ObjectiveBot::new(Objective::AllySpecies(1));
+        let event = Event {
+            description: "Nothing".to_string(),
+            relevant_expertise: vec![],
+            options: vec![],
+        };
+        assert_eq!(bot.vote(&event, &GalaxyState::new()), 0);
+    }
+}
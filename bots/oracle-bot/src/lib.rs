@@ -1,7 +1,9 @@
 use council_core::event::Event;
-use council_core::explorer::GalacticCouncilMember;
+use council_core::explorer::{GalacticCouncilMember, VoteTrace};
 use council_core::galaxy::GalaxyState;
-use council_core::ollama::{build_galactic_prompt, llm_choose, llm_deliberate, OllamaConfig};
+use council_core::ollama::{
+    build_system_and_user, llm_choose_with_system, llm_deliberate, OllamaConfig,
+};
 
 const PERSONALITY: &str = "You are a visionary scientist who sees patterns others miss. You adapt your strategy based on long-term trends and plan several moves ahead.";
 
@@ -15,6 +17,7 @@ const PERSONALITY: &str = "You are a visionary scientist who sees patterns other
 /// - If few sectors explored -> prefer exploration/bold options (index 0)
 /// - If galaxy is stable -> prefer cautious/research options (index 1)
 /// - Fallback: middle option as balanced choice
+#[derive(Clone)]
 pub struct OracleBot {
     ollama: Option<OllamaConfig>,
 }
@@ -54,65 +57,125 @@ impl GalacticCouncilMember for OracleBot {
 
     fn vote(&self, event: &Event, galaxy: &GalaxyState) -> usize {
         if let Some(cfg) = &self.ollama {
-            let prompt = build_galactic_prompt(PERSONALITY, event, galaxy);
-            if let Ok(choice) = llm_choose(cfg, &prompt, event.options.len()) {
+            let (system, user) = build_system_and_user(PERSONALITY, event, galaxy);
+            if let Ok(choice) = llm_choose_with_system(cfg, &system, &user, event.options.len()) {
                 return choice;
             }
         }
         // Deterministic fallback
+        strategize(event, galaxy).0
+    }
+
+    fn comment(&self, event: &Event, galaxy: &GalaxyState) -> Option<String> {
+        let cfg = self.ollama.as_ref()?;
+        let (choice, comment) = llm_deliberate(cfg, PERSONALITY, event, galaxy).ok()?;
+        Some(format!("prefers [{}] — {}", choice, comment))
+    }
+
+    /// The deterministic strategy pick first, then the alternative between
+    /// the bold and cautious options.
+    fn preferences(&self, event: &Event, galaxy: &GalaxyState) -> Vec<(usize, &'static str)> {
         let num_options = event.options.len();
         if num_options == 0 {
-            return 0;
+            return Vec::new();
         }
+        let (choice, branch) = strategize(event, galaxy);
+        let rationale = match branch {
+            "high threat pressure" => "decisive",
+            "diplomatic crisis" => "peaceful",
+            "early exploration" => "bold",
+            "stable research" => "research",
+            _ => "balanced",
+        };
+        let mut prefs = vec![(choice, rationale)];
+        let alternative = if choice == 0 {
+            (cautious_option(num_options), "cautious")
+        } else {
+            (0, "bold")
+        };
+        if alternative.0 != choice {
+            prefs.push(alternative);
+        }
+        prefs
+    }
 
-        let threat_pressure = galaxy.threats.iter().map(|t| t.severity).sum::<u32>();
-        let hostile_count = galaxy.hostile_count();
-        let allied_count = galaxy.allied_count();
-        let sectors_explored = galaxy.explored_sectors.len();
-        let discovery_count = galaxy.discoveries.len();
-
-        let is_threat_event = event
-            .relevant_expertise
-            .iter()
-            .any(|(tag, _)| tag == "military" || tag == "strategy");
-        let is_diplomacy_event = event
-            .relevant_expertise
-            .iter()
-            .any(|(tag, _)| tag == "diplomacy" || tag == "culture" || tag == "linguistics");
-        let is_exploration_event = event
+    /// Reports the deterministic strategy branch. LLM-backed votes are not
+    /// reproducible, so no trace is produced when Ollama is configured.
+    fn trace(&self, event: &Event, galaxy: &GalaxyState) -> Option<VoteTrace> {
+        if self.ollama.is_some() {
+            return None;
+        }
+        let (chosen, branch) = strategize(event, galaxy);
+        let expertise = self.expertise();
+        let matched_expertise = event
             .relevant_expertise
             .iter()
-            .any(|(tag, _)| tag == "exploration" || tag == "science");
+            .filter_map(|(tag, _)| {
+                expertise
+                    .iter()
+                    .find(|(bot_tag, _)| bot_tag == tag)
+                    .map(|(_, proficiency)| (tag.clone(), *proficiency))
+            })
+            .collect();
+        Some(VoteTrace {
+            branch,
+            threat_pressure: galaxy.threats.iter().map(|t| t.severity).sum(),
+            hostile_count: galaxy.hostile_count(),
+            allied_count: galaxy.allied_count(),
+            matched_expertise,
+            chosen,
+        })
+    }
+}
 
-        // High threat pressure: act decisively (bold option)
-        if is_threat_event && threat_pressure >= 3 {
-            return 0;
-        }
+/// Deterministic strategy. Returns the chosen index and the branch taken.
+fn strategize(event: &Event, galaxy: &GalaxyState) -> (usize, &'static str) {
+    let num_options = event.options.len();
+    if num_options == 0 {
+        return (0, "no options");
+    }
 
-        // Diplomatic crisis: hostile species dominate
-        if is_diplomacy_event && hostile_count > allied_count {
-            return 0; // Attempt peaceful contact
-        }
+    let threat_pressure = galaxy.threats.iter().map(|t| t.severity).sum::<u32>();
+    let hostile_count = galaxy.hostile_count();
+    let allied_count = galaxy.allied_count();
+    let sectors_explored = galaxy.explored_sectors.len();
+    let discovery_count = galaxy.discoveries.len();
 
-        // Early game: explore aggressively
-        if is_exploration_event && sectors_explored < 4 {
-            return 0; // Bold exploration
-        }
+    let is_threat_event = event
+        .relevant_expertise
+        .iter()
+        .any(|(tag, _)| tag == "military" || tag == "strategy");
+    let is_diplomacy_event = event
+        .relevant_expertise
+        .iter()
+        .any(|(tag, _)| tag == "diplomacy" || tag == "culture" || tag == "linguistics");
+    let is_exploration_event = event
+        .relevant_expertise
+        .iter()
+        .any(|(tag, _)| tag == "exploration" || tag == "science");
 
-        // Mid-game stability: research and caution
-        if discovery_count >= 3 && threat_pressure == 0 {
-            return cautious_option(num_options);
-        }
+    // High threat pressure: act decisively (bold option)
+    if is_threat_event && threat_pressure >= 3 {
+        return (0, "high threat pressure");
+    }
 
-        // Default: balanced middle option
-        balanced_option(num_options)
+    // Diplomatic crisis: hostile species dominate
+    if is_diplomacy_event && hostile_count > allied_count {
+        return (0, "diplomatic crisis"); // Attempt peaceful contact
     }
 
-    fn comment(&self, event: &Event, galaxy: &GalaxyState) -> Option<String> {
-        let cfg = self.ollama.as_ref()?;
-        let (choice, comment) = llm_deliberate(cfg, PERSONALITY, event, galaxy).ok()?;
-        Some(format!("prefers [{}] — {}", choice, comment))
+    // Early game: explore aggressively
+    if is_exploration_event && sectors_explored < 4 {
+        return (0, "early exploration"); // Bold exploration
     }
+
+    // Mid-game stability: research and caution
+    if discovery_count >= 3 && threat_pressure == 0 {
+        return (cautious_option(num_options), "stable research");
+    }
+
+    // Default: balanced middle option
+    (balanced_option(num_options), "balanced default")
 }
 
 /// Pick the cautious/research option (typically index 1).
@@ -136,29 +199,8 @@ fn balanced_option(num_options: usize) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use council_core::event::{Outcome, ResponseOption};
     use council_core::galaxy::{GalaxyState, Relation, Species, Threat};
-
-    fn make_event(expertise_tags: &[(&str, f32)], num_options: usize) -> Event {
-        let options = (0..num_options)
-            .map(|i| ResponseOption {
-                description: format!("Option {}", i),
-                outcome: Outcome {
-                    description: format!("Outcome {}", i),
-                    score_delta: 0,
-                    state_changes: vec![],
-                },
-            })
-            .collect();
-        Event {
-            description: "Test event".to_string(),
-            relevant_expertise: expertise_tags
-                .iter()
-                .map(|(s, w)| (s.to_string(), *w))
-                .collect(),
-            options,
-        }
-    }
+    use council_core::test_util::make_event_with_expertise;
 
     #[test]
     fn oracle_has_broad_expertise() {
@@ -178,15 +220,32 @@ mod tests {
             severity: 4,
             rounds_active: 1,
         });
-        let event = make_event(&[("military", 0.5), ("strategy", 0.3)], 3);
+        let event = make_event_with_expertise(&[("military", 0.5), ("strategy", 0.3)], 3);
         assert_eq!(bot.vote(&event, &galaxy), 0);
     }
 
+    #[test]
+    fn oracle_trace_reports_high_threat_pressure() {
+        let bot = OracleBot::new();
+        let mut galaxy = GalaxyState::new();
+        galaxy.threats.push(Threat {
+            name: "Space Pirates".to_string(),
+            severity: 4,
+            rounds_active: 1,
+        });
+        let event = make_event_with_expertise(&[("military", 0.5), ("strategy", 0.3)], 3);
+        let trace = bot.trace(&event, &galaxy).unwrap();
+        assert_eq!(trace.branch, "high threat pressure");
+        assert_eq!(trace.threat_pressure, 4);
+        assert_eq!(trace.chosen, bot.vote(&event, &galaxy));
+        assert_eq!(trace.matched_expertise, vec![("strategy".to_string(), 0.9)]);
+    }
+
     #[test]
     fn oracle_explores_early() {
         let bot = OracleBot::new();
         let galaxy = GalaxyState::new(); // Only Home Sector
-        let event = make_event(&[("exploration", 0.4), ("science", 0.3)], 3);
+        let event = make_event_with_expertise(&[("exploration", 0.4), ("science", 0.3)], 3);
         assert_eq!(bot.vote(&event, &galaxy), 0);
     }
 
@@ -201,7 +260,7 @@ mod tests {
         galaxy
             .relations
             .insert("Zorblax".to_string(), Relation::Hostile);
-        let event = make_event(&[("diplomacy", 0.5), ("culture", 0.3)], 3);
+        let event = make_event_with_expertise(&[("diplomacy", 0.5), ("culture", 0.3)], 3);
         assert_eq!(bot.vote(&event, &galaxy), 0);
     }
 
@@ -214,9 +273,11 @@ mod tests {
             galaxy.discoveries.push(council_core::galaxy::Discovery {
                 name: format!("Discovery {}", i),
                 category: "science".to_string(),
+                source_round: None,
+                source_event: None,
             });
         }
-        let event = make_event(&[("archaeology", 0.4)], 3);
+        let event = make_event_with_expertise(&[("archaeology", 0.4)], 3);
         assert_eq!(bot.vote(&event, &galaxy), 1);
     }
 
@@ -225,7 +286,7 @@ mod tests {
         let bot = OracleBot::new();
         let galaxy = GalaxyState::new();
         for n in 1..=5 {
-            let event = make_event(&[("science", 0.5)], n);
+            let event = make_event_with_expertise(&[("science", 0.5)], n);
             let choice = bot.vote(&event, &galaxy);
             assert!(
                 choice < n,
@@ -244,12 +305,11 @@ mod tests {
 
     #[test]
     fn test_with_ollama_stores_config() {
-        let cfg = OllamaConfig {
-            host: "127.0.0.1:11434".to_string(),
-            model: "llama3".to_string(),
-            api: council_core::ollama::LlmApi::Ollama,
-            api_key: None,
-        };
+        let cfg = OllamaConfig::new(
+            "127.0.0.1:11434",
+            "llama3",
+            council_core::ollama::LlmApi::Ollama,
+        );
         let bot = OracleBot::with_ollama(cfg);
         assert!(bot.ollama.is_some());
     }
@@ -259,4 +319,73 @@ mod tests {
         assert!(PERSONALITY.contains("visionary"));
         assert!(PERSONALITY.contains("scientist"));
     }
+
+    #[test]
+    fn boxed_oracle_clones_with_same_behavior() {
+        let original: Box<dyn GalacticCouncilMember> = Box::new(OracleBot::new());
+        let copy = original.clone();
+        assert_eq!(copy.name(), original.name());
+        assert_eq!(copy.expertise(), original.expertise());
+
+        let mut galaxy = GalaxyState::new();
+        galaxy.threats.push(Threat {
+            name: "Void Swarm".to_string(),
+            severity: 4,
+            rounds_active: 1,
+        });
+        for event in [
+            make_event_with_expertise(&[("military", 0.8)], 3),
+            make_event_with_expertise(&[("diplomacy", 0.6)], 2),
+            make_event_with_expertise(&[("science", 0.5)], 4),
+        ] {
+            assert_eq!(copy.vote(&event, &galaxy), original.vote(&event, &galaxy));
+        }
+    }
+
+    #[test]
+    fn oracle_preferences_lead_with_strategy() {
+        let bot = OracleBot::new();
+        let mut galaxy = GalaxyState::new();
+        galaxy.threats.push(Threat {
+            name: "Void Swarm".to_string(),
+            severity: 3,
+            rounds_active: 1,
+        });
+        let event = make_event_with_expertise(&[("military", 0.8)], 3);
+        assert_eq!(
+            bot.preferences(&event, &galaxy),
+            vec![(0, "decisive"), (1, "cautious")]
+        );
+        assert!(bot
+            .preferences(&make_event_with_expertise(&[], 0), &galaxy)
+            .is_empty());
+    }
+
+    #[test]
+    fn oracle_reports_through_classic_adapter() {
+        use council_core::event::EventTemplate;
+        use council_core::explorer::GalacticAsClassic;
+        use council_core::templates::UnknownSignalTemplate;
+        use council_core::{Context, CouncilMember, Decision};
+        use rand::SeedableRng;
+
+        let galaxy = GalaxyState::new();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let event = UnknownSignalTemplate.generate(&galaxy, &mut rng);
+        let expected = match OracleBot::new().vote(&event, &galaxy) {
+            0 => Decision::Approve,
+            c if c + 1 == event.options.len() => Decision::Reject,
+            _ => Decision::Abstain,
+        };
+
+        let mut classic = GalacticAsClassic::new(Box::new(OracleBot::new()));
+        classic.on_round_start(event, galaxy);
+        let ctx = Context {
+            round: 1,
+            previous_tally: None,
+            seed: 0,
+        };
+        assert_eq!(classic.name(), "oracle-bot");
+        assert_eq!(classic.vote(&ctx), expected);
+    }
 }
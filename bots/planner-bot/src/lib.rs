@@ -0,0 +1,156 @@
+use council_core::event::{Event, ResponseOption};
+use council_core::explorer::GalacticCouncilMember;
+use council_core::galaxy::GalaxyState;
+
+/// PlannerBot looks one move ahead: for each option it applies the option's
+/// state changes to a copy of the galaxy and votes for the option that most
+/// improves [`GalaxyState::heuristic_value`].
+///
+/// Ties go to the higher immediate score (including any
+/// [`StateChange::ScoreScaledBy`](council_core::galaxy::StateChange::ScoreScaledBy)
+/// earnings), then to the lower index.
+#[derive(Clone, Default)]
+pub struct PlannerBot;
+
+impl PlannerBot {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Heuristic change and immediate score if `option` won.
+    fn evaluate(option: &ResponseOption, galaxy: &GalaxyState) -> (i32, i32) {
+        let mut next = galaxy.clone();
+        let scaled = next.apply_changes_scored(&option.outcome.state_changes);
+        (
+            next.heuristic_value() - galaxy.heuristic_value(),
+            option.outcome.score_delta + scaled,
+        )
+    }
+}
+
+impl GalacticCouncilMember for PlannerBot {
+    fn name(&self) -> &'static str {
+        "planner-bot"
+    }
+
+    fn expertise(&self) -> &[(&'static str, f32)] {
+        &[("strategy", 0.8), ("diplomacy", 0.4), ("science", 0.4)]
+    }
+
+    fn vote(&self, event: &Event, galaxy: &GalaxyState) -> usize {
+        event
+            .options
+            .iter()
+            .map(|option| Self::evaluate(option, galaxy))
+            .enumerate()
+            .max_by(|(ia, a), (ib, b)| a.cmp(b).then(ib.cmp(ia))) // Lower index wins ties
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use council_core::event::Outcome;
+    use council_core::galaxy::{Relation, Species, StateChange, Threat};
+
+    fn option(score_delta: i32, state_changes: Vec<StateChange>) -> ResponseOption {
+        ResponseOption {
+            description: "Option".to_string(),
+            outcome: Outcome {
+                description: "Outcome".to_string(),
+                score_delta,
+                headline: None,
+                state_changes,
+            },
+            required_discovery: None,
+        }
+    }
+
+    fn event(options: Vec<ResponseOption>) -> Event {
+        Event {
+            description: "Test event".to_string(),
+            relevant_expertise: vec![],
+            options,
+        }
+    }
+
+    fn galaxy_with_species() -> GalaxyState {
+        let mut galaxy = GalaxyState::new();
+        galaxy.known_species.push(Species {
+            name: "Velari".to_string(),
+            traits: vec![],
+        });
+        galaxy
+            .relations
+            .insert("Velari".to_string(), Relation::Neutral);
+        galaxy
+    }
+
+    #[test]
+    fn prefers_ally_over_threat() {
+        let bot = PlannerBot::new();
+        let galaxy = galaxy_with_species();
+        let event = event(vec![
+            option(
+                10,
+                vec![StateChange::AddThreat(Threat {
+                    name: "Void Swarm".to_string(),
+                    severity: 3,
+                    rounds_active: 0,
+                })],
+            ),
+            option(
+                0,
+                vec![StateChange::SetRelation {
+                    species: "Velari".to_string(),
+                    relation: Relation::Allied,
+                }],
+            ),
+        ]);
+        assert_eq!(bot.vote(&event, &galaxy), 1);
+    }
+
+    #[test]
+    fn ties_go_to_higher_score_then_lower_index() {
+        let bot = PlannerBot::new();
+        let galaxy = GalaxyState::new();
+        assert_eq!(
+            bot.vote(&event(vec![option(0, vec![]), option(5, vec![])]), &galaxy),
+            1
+        );
+        assert_eq!(
+            bot.vote(&event(vec![option(5, vec![]), option(5, vec![])]), &galaxy),
+            0
+        );
+    }
+
+    #[test]
+    fn scaled_score_counts_toward_immediate_score() {
+        let bot = PlannerBot::new();
+        let mut galaxy = GalaxyState::new();
+        galaxy
+            .relations
+            .insert("Velari".to_string(), Relation::Allied);
+        let festival = StateChange::ScoreScaledBy {
+            per_ally: 4,
+            per_sector: 0,
+        };
+        assert_eq!(
+            bot.vote(
+                &event(vec![option(3, vec![]), option(0, vec![festival])]),
+                &galaxy
+            ),
+            1
+        );
+    }
+
+    #[test]
+    fn empty_event_returns_zero() {
+        assert_eq!(
+            PlannerBot::new().vote(&event(vec![]), &GalaxyState::new()),
+            0
+        );
+    }
+}
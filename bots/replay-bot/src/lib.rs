@@ -0,0 +1,86 @@
+use council_core::event::Event;
+use council_core::explorer::GalacticCouncilMember;
+use council_core::galaxy::GalaxyState;
+
+/// ReplayBot replays a recorded playthrough: in round `n` it votes for
+/// `choices[n - 1]`, clamped to the available options.
+///
+/// Past the end of the recording it defaults to option 0.
+#[derive(Clone)]
+pub struct ReplayBot {
+    choices: Vec<usize>,
+}
+
+impl ReplayBot {
+    pub fn new(choices: Vec<usize>) -> Self {
+        Self { choices }
+    }
+
+    /// The recorded choices, one per round.
+    pub fn choices(&self) -> &[usize] {
+        &self.choices
+    }
+}
+
+impl GalacticCouncilMember for ReplayBot {
+    fn name(&self) -> &'static str {
+        "replay-bot"
+    }
+
+    /// Broad, moderate expertise so the replayed choices carry weight on
+    /// any event without dominating specialists.
+    fn expertise(&self) -> &[(&'static str, f32)] {
+        &[
+            ("diplomacy", 0.5),
+            ("science", 0.5),
+            ("military", 0.5),
+            ("exploration", 0.5),
+            ("strategy", 0.5),
+        ]
+    }
+
+    fn vote(&self, event: &Event, galaxy: &GalaxyState) -> usize {
+        let recorded = (galaxy.round as usize)
+            .checked_sub(1)
+            .and_then(|i| self.choices.get(i))
+            .copied()
+            .unwrap_or(0);
+        recorded.min(event.options.len().saturating_sub(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use council_core::test_util::make_event;
+
+    fn galaxy_at_round(round: u32) -> GalaxyState {
+        let mut galaxy = GalaxyState::new();
+        galaxy.round = round;
+        galaxy
+    }
+
+    #[test]
+    fn replays_recorded_choices() {
+        let bot = ReplayBot::new(vec![2, 0, 1]);
+        let event = make_event(3);
+        assert_eq!(bot.vote(&event, &galaxy_at_round(1)), 2);
+        assert_eq!(bot.vote(&event, &galaxy_at_round(2)), 0);
+        assert_eq!(bot.vote(&event, &galaxy_at_round(3)), 1);
+    }
+
+    #[test]
+    fn defaults_past_end_of_recording() {
+        let bot = ReplayBot::new(vec![2]);
+        let event = make_event(3);
+        assert_eq!(bot.vote(&event, &galaxy_at_round(2)), 0);
+        assert_eq!(bot.vote(&event, &galaxy_at_round(0)), 0);
+    }
+
+    #[test]
+    fn clamps_to_option_count() {
+        let bot = ReplayBot::new(vec![5]);
+        assert_eq!(bot.vote(&make_event(2), &galaxy_at_round(1)), 1);
+        assert_eq!(bot.vote(&make_event(0), &galaxy_at_round(1)), 0);
+    }
+}
@@ -0,0 +1,106 @@
+use council_core::event::Event;
+use council_core::explorer::GalacticCouncilMember;
+use council_core::galaxy::GalaxyState;
+
+/// SignatureBot picks `hash(description, round) % options` — fully
+/// deterministic given the event, yet sensitive to its content.
+///
+/// Uses 64-bit FNV-1a rather than `DefaultHasher`, whose output may change
+/// between Rust releases, so picks are stable across toolchains.
+#[derive(Clone, Default)]
+pub struct SignatureBot;
+
+impl SignatureBot {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Stable hash of the event description and the galaxy round.
+fn signature(description: &str, round: u32) -> u64 {
+    description
+        .as_bytes()
+        .iter()
+        .chain(&round.to_le_bytes())
+        .fold(FNV_OFFSET, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+        })
+}
+
+impl GalacticCouncilMember for SignatureBot {
+    fn name(&self) -> &'static str {
+        "signature-bot"
+    }
+
+    /// Flat, moderate expertise: a baseline that counts on any event
+    /// without outweighing specialists.
+    fn expertise(&self) -> &[(&'static str, f32)] {
+        &[
+            ("diplomacy", 0.4),
+            ("science", 0.4),
+            ("military", 0.4),
+            ("exploration", 0.4),
+            ("strategy", 0.4),
+        ]
+    }
+
+    fn vote(&self, event: &Event, galaxy: &GalaxyState) -> usize {
+        if event.options.is_empty() {
+            return 0;
+        }
+        (signature(&event.description, galaxy.round) % event.options.len() as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use council_core::test_util::make_event;
+
+    /// A [`make_event`] fixture with the given description.
+    fn described_event(description: &str, num_options: usize) -> Event {
+        Event {
+            description: description.to_string(),
+            ..make_event(num_options)
+        }
+    }
+
+    #[test]
+    fn identical_events_get_identical_picks() {
+        let bot = SignatureBot::new();
+        let galaxy = GalaxyState::new();
+        let event = described_event("A derelict drifts into sensor range.", 5);
+        let pick = bot.vote(&event, &galaxy);
+        assert!(pick < 5);
+        assert_eq!(bot.clone().vote(&event.clone(), &galaxy), pick);
+    }
+
+    #[test]
+    fn different_events_generally_differ() {
+        let bot = SignatureBot::new();
+        let galaxy = GalaxyState::new();
+        let picks: Vec<usize> = (0..20)
+            .map(|i| bot.vote(&described_event(&format!("Signal {}", i), 5), &galaxy))
+            .collect();
+        let distinct = (0..5).filter(|option| picks.contains(option)).count();
+        assert!(distinct >= 3, "picks {:?}", picks);
+    }
+
+    #[test]
+    fn round_changes_the_signature() {
+        assert_ne!(signature("Signal", 1), signature("Signal", 2));
+        assert_eq!(signature("Signal", 1), signature("Signal", 1));
+    }
+
+    #[test]
+    fn empty_event_returns_zero() {
+        let bot = SignatureBot::new();
+        assert_eq!(
+            bot.vote(&described_event("Nothing", 0), &GalaxyState::new()),
+            0
+        );
+    }
+}
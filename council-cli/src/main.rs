@@ -3,8 +3,10 @@ use council_core::explorer::GalacticCouncilMember;
 use council_core::galaxy::GalaxyState;
 use council_core::ollama::{can_connect, can_connect_llm, parse_host, LlmApi, OllamaConfig};
 use council_core::scoring::ScoreTracker;
-use council_core::voting::{calculate_vote_weight, resolve_votes, Vote};
-use council_core::{default_templates, generate_event};
+use council_core::{
+    default_templates, run_galactic, BotRegistry, EventTemplate, GalacticOrchestrator,
+    OrchestratorConfig, Scenario,
+};
 use cycle_bot::CycleBot;
 use example_bot::ExampleBot;
 use first_bot::FirstBot;
@@ -20,9 +22,13 @@ struct CliConfig {
     rounds: u32,
     seed: Option<u64>,
     report_json: Option<String>,
+    serve: Option<u16>,
+    repeat: Option<u32>,
+    scenario: Option<String>,
     enable_llm: bool,
     enable_llm_bot: bool,
     deliberate: bool,
+    trace: bool,
     galnet: bool,
 
     llm_provider: String,
@@ -44,9 +50,13 @@ fn parse_args() -> CliConfig {
         rounds: DEFAULT_ROUNDS,
         seed: None,
         report_json: None,
+        serve: None,
+        repeat: None,
+        scenario: None,
         enable_llm: false,
         enable_llm_bot: false,
         deliberate: false,
+        trace: false,
         galnet: false,
 
         llm_provider: "ollama".to_string(),
@@ -95,9 +105,43 @@ fn parse_args() -> CliConfig {
                 };
                 cfg.report_json = Some(v);
             }
+            "--serve" => {
+                let Some(v) = it.next() else {
+                    eprintln!("--serve requires a port");
+                    std::process::exit(2);
+                };
+                match v.parse::<u16>() {
+                    Ok(p) => cfg.serve = Some(p),
+                    Err(_) => {
+                        eprintln!("--serve must be a valid port");
+                        std::process::exit(2);
+                    }
+                }
+            }
+            "--repeat" => {
+                let Some(v) = it.next() else {
+                    eprintln!("--repeat requires a number");
+                    std::process::exit(2);
+                };
+                match v.parse::<u32>() {
+                    Ok(k) if k >= 1 => cfg.repeat = Some(k),
+                    _ => {
+                        eprintln!("--repeat must be a number >= 1");
+                        std::process::exit(2);
+                    }
+                }
+            }
+            "--scenario" => {
+                let Some(v) = it.next() else {
+                    eprintln!("--scenario requires a file path");
+                    std::process::exit(2);
+                };
+                cfg.scenario = Some(v);
+            }
             "--enable-llm" => cfg.enable_llm = true,
             "--enable-llm-bot" => cfg.enable_llm_bot = true,
             "--deliberate" => cfg.deliberate = true,
+            "--trace" => cfg.trace = true,
             "--galnet" => cfg.galnet = true,
             "--llm-provider" => {
                 if let Some(v) = it.next() {
@@ -137,7 +181,7 @@ fn parse_args() -> CliConfig {
             }
             "--help" | "-h" => {
                 println!(
-                    "council-cli\n\nFlags:\n  --rounds <n>          Number of rounds (default: 25)\n  --seed <u64>          RNG seed for deterministic/reproducible runs\n  --report-json <path>  Export final simulation report as JSON to a file\n  --enable-llm          Give all 5 bots unique LLM personalities via a local LLM\n  --enable-llm-bot      Add a 6th dedicated LLM bot to the council\n  --deliberate          Let bots publish short comments before the final vote\n  --galnet             Add small GalNet news blurbs each round (for fun)\n\n  --llm-provider <ollama|lmstudio>  Which local LLM API to use (default: ollama)\n  --llm-base-url <url>   LM Studio base URL (default: http://127.0.0.1:1234/v1)\n  --llm-model <model>    LM Studio model id (defaults to --ollama-model if unset)\n  --llm-api-key <key>    Optional API key (LM Studio often accepts any value)\n\n  --spawn-ollama        Start/stop Ollama automatically for this run (ollama only)\n  --ollama-bin <path>   Path to ollama binary (default: ollama)\n  --ollama-host <host:port>  Ollama endpoint (default: 127.0.0.1:11434)\n  --ollama-model <model>     Model name (default: llama3)\n"
+                    "council-cli\n\nFlags:\n  --rounds <n>          Number of rounds (default: 25)\n  --seed <u64>          RNG seed for deterministic/reproducible runs\n  --report-json <path>  Export final simulation report as JSON to a file\n  --serve <port>        Serve GET /simulate?rounds=N&seed=S as JSON on 127.0.0.1 (demo)\n  --repeat <k>          Run k simulations (seed, seed+1, ...) and print final score stats\n  --scenario <path>     Run a JSON scenario (seed, rounds, bots, starting galaxy)\n  --enable-llm          Give all 5 bots unique LLM personalities via a local LLM\n  --enable-llm-bot      Add a 6th dedicated LLM bot to the council\n  --deliberate          Let bots publish short comments before the final vote\n  --trace               Print each bot's decision trace (reproducibility audits)\n  --galnet             Add small GalNet news blurbs each round (for fun)\n\n  --llm-provider <ollama|lmstudio>  Which local LLM API to use (default: ollama)\n  --llm-base-url <url>   LM Studio base URL (default: http://127.0.0.1:1234/v1)\n  --llm-model <model>    LM Studio model id (defaults to --ollama-model if unset)\n  --llm-api-key <key>    Optional API key (LM Studio often accepts any value)\n\n  --spawn-ollama        Start/stop Ollama automatically for this run (ollama only)\n  --ollama-bin <path>   Path to ollama binary (default: ollama)\n  --ollama-host <host:port>  Ollama endpoint (default: 127.0.0.1:11434)\n  --ollama-model <model>     Model name (default: llama3)\n"
                 );
                 std::process::exit(0);
             }
@@ -200,12 +244,11 @@ fn maybe_spawn_ollama(cfg: &CliConfig) -> Option<OllamaGuard> {
 fn resolve_llm_config(cfg: &CliConfig) -> Result<OllamaConfig, String> {
     let provider = cfg.llm_provider.trim().to_ascii_lowercase();
     match provider.as_str() {
-        "ollama" => Ok(OllamaConfig {
-            host: cfg.ollama_host.clone(),
-            model: cfg.ollama_model.clone(),
-            api: LlmApi::Ollama,
-            api_key: None,
-        }),
+        "ollama" => Ok(OllamaConfig::new(
+            cfg.ollama_host.clone(),
+            cfg.ollama_model.clone(),
+            LlmApi::Ollama,
+        )),
         "lmstudio" | "lm-studio" | "lm_studio" => {
             let model = if cfg.llm_model.trim().is_empty() {
                 cfg.ollama_model.clone()
@@ -213,14 +256,16 @@ fn resolve_llm_config(cfg: &CliConfig) -> Result<OllamaConfig, String> {
                 cfg.llm_model.clone()
             };
             Ok(OllamaConfig {
-                host: cfg.llm_base_url.clone(),
-                model,
-                api: LlmApi::OpenAiChatCompletions,
                 api_key: if cfg.llm_api_key.trim().is_empty() {
                     None
                 } else {
                     Some(cfg.llm_api_key.clone())
                 },
+                ..OllamaConfig::new(
+                    cfg.llm_base_url.clone(),
+                    model,
+                    LlmApi::OpenAiChatCompletions,
+                )
             })
         }
         _ => Err(format!(
@@ -230,9 +275,40 @@ fn resolve_llm_config(cfg: &CliConfig) -> Result<OllamaConfig, String> {
     }
 }
 
+/// The five built-in deterministic bots.
+fn default_bots() -> Vec<Box<dyn GalacticCouncilMember>> {
+    vec![
+        Box::new(ExampleBot::new()),
+        Box::new(FirstBot::new()),
+        Box::new(CycleBot::new()),
+        Box::new(ContrarianBot::new()),
+        Box::new(OracleBot::new()),
+    ]
+}
+
+/// Bots a scenario file can name.
+fn bot_registry() -> BotRegistry {
+    BotRegistry::new()
+        .register("example-bot", || Box::new(ExampleBot::new()))
+        .register("first-bot", || Box::new(FirstBot::new()))
+        .register("cycle-bot", || Box::new(CycleBot::new()))
+        .register("contrarian-bot", || Box::new(ContrarianBot::new()))
+        .register("oracle-bot", || Box::new(OracleBot::new()))
+}
+
 fn main() {
     let cfg = parse_args();
 
+    if let Some(port) = cfg.serve {
+        serve(port);
+        return;
+    }
+
+    if let Some(ref path) = cfg.scenario {
+        run_scenario_file(path, cfg.report_json.as_deref());
+        return;
+    }
+
     let needs_llm = cfg.enable_llm || cfg.enable_llm_bot;
     let llm_cfg = if needs_llm {
         match resolve_llm_config(&cfg) {
@@ -244,12 +320,11 @@ fn main() {
         }
     } else {
         // Not used.
-        OllamaConfig {
-            host: cfg.ollama_host.clone(),
-            model: cfg.ollama_model.clone(),
-            api: LlmApi::Ollama,
-            api_key: None,
-        }
+        OllamaConfig::new(
+            cfg.ollama_host.clone(),
+            cfg.ollama_model.clone(),
+            LlmApi::Ollama,
+        )
     };
 
     let _ollama_guard = if needs_llm && llm_cfg.api == LlmApi::Ollama {
@@ -290,13 +365,7 @@ fn main() {
             Box::new(OracleBot::with_ollama(llm_cfg.clone())),
         ]
     } else {
-        vec![
-            Box::new(ExampleBot::new()),
-            Box::new(FirstBot::new()),
-            Box::new(CycleBot::new()),
-            Box::new(ContrarianBot::new()),
-            Box::new(OracleBot::new()),
-        ]
+        default_bots()
     };
 
     if cfg.enable_llm_bot {
@@ -304,17 +373,41 @@ fn main() {
     }
 
     let templates = default_templates();
-    let mut galaxy = GalaxyState::new();
-    let mut score = ScoreTracker::new();
+
+    if let Some(runs) = cfg.repeat {
+        let base_seed = cfg.seed.unwrap_or_else(rand::random);
+        let config = OrchestratorConfig {
+            trace: false,
+            deliberate: cfg.deliberate,
+            ..OrchestratorConfig::default()
+        };
+        let stats = run_repeated(&bots, &templates, cfg.rounds, runs, base_seed, &config);
+        print_aggregate(&stats, cfg.rounds, base_seed);
+        return;
+    }
+
     let mut rng = match cfg.seed {
         Some(s) => rand::rngs::StdRng::seed_from_u64(s),
         None => rand::rngs::StdRng::from_entropy(),
     };
+    let mut orchestrator = GalacticOrchestrator::new(
+        &bots,
+        &templates,
+        OrchestratorConfig {
+            trace: cfg.trace,
+            deliberate: cfg.deliberate,
+            ..OrchestratorConfig::default()
+        },
+    );
 
     print_banner(cfg.rounds, bots.len() as u32);
 
-    for round in 1..=cfg.rounds {
-        galaxy.round = round;
+    for _ in 0..cfg.rounds {
+        let record = orchestrator.play_round(&mut rng);
+        let round = record.round;
+        let event = &record.event;
+        let winner = record.winner;
+        let outcome = record.outcome();
 
         println!();
         println!("╔══════════════════════════════════════════════════════════════╗");
@@ -324,8 +417,6 @@ fn main() {
         );
         println!("╚══════════════════════════════════════════════════════════════╝");
 
-        // Generate event
-        let event = generate_event(&templates, &galaxy, &mut rng);
         println!();
         println!("  [EVENT] {}", event.description);
         println!();
@@ -335,77 +426,66 @@ fn main() {
         }
         println!();
 
-        // Optional deliberation phase
-        let mut event_for_vote = event.clone();
-        if cfg.deliberate {
-            let mut lines = Vec::new();
-            for bot in &bots {
-                if let Some(comment) = bot.comment(&event, &galaxy) {
-                    lines.push(format!("{}: {}", bot.name(), comment));
-                }
-            }
-
-            if !lines.is_empty() {
-                println!("  [DELIBERATION]");
-                for line in &lines {
-                    println!("    {}", line);
-                }
-                println!();
-
-                event_for_vote.description = format!(
-                    "{}\n\nCOUNCIL DELIBERATION:\n{}",
-                    event_for_vote.description,
-                    lines.join("\n")
-                );
+        if !record.comments.is_empty() {
+            println!("  [DELIBERATION]");
+            for (name, comment) in &record.comments {
+                println!("    {}: {}", name, comment);
             }
+            println!();
         }
 
-        // Collect votes
-        let mut votes = Vec::new();
-        for bot in &bots {
-            let weight = calculate_vote_weight(bot.as_ref(), &event);
-            let chosen = bot.vote(&event_for_vote, &galaxy);
-            let chosen = chosen.min(event.options.len().saturating_sub(1));
+        for vote in &record.votes {
             println!(
                 "    {} votes [{}] (weight: {:.2})",
-                bot.name(),
-                chosen,
-                weight
+                vote.bot_name, vote.chosen_option, vote.weight
             );
-            votes.push(Vote {
-                bot_name: bot.name().to_string(),
-                chosen_option: chosen,
-                weight,
-            });
         }
 
-        // Resolve
-        let winner = resolve_votes(&votes, event.options.len());
-        let outcome = &event.options[winner].outcome;
+        if !record.traces.is_empty() {
+            println!();
+            println!("  [TRACE]");
+            for (name, trace) in &record.traces {
+                println!(
+                    "    {}: branch={} chosen={} threat_pressure={} hostile={} allied={}",
+                    name,
+                    trace.branch,
+                    trace.chosen,
+                    trace.threat_pressure,
+                    trace.hostile_count,
+                    trace.allied_count
+                );
+            }
+        }
 
         println!();
         println!("  >> COUNCIL CHOOSES: [{}]", winner);
         println!("  >> {}", outcome.description);
 
-        score.add(round, outcome.score_delta, &outcome.description);
-        galaxy.apply_changes(&outcome.state_changes);
-
-        if outcome.score_delta > 0 {
-            println!("     +{} points", outcome.score_delta);
-        } else if outcome.score_delta < 0 {
-            println!("     {} points", outcome.score_delta);
+        if record.score_delta > 0 {
+            println!("     +{} points", record.score_delta);
+        } else if record.score_delta < 0 {
+            println!("     {} points", record.score_delta);
         }
 
-        // Process threats
-        let threat_penalty = galaxy.process_threats();
-        if threat_penalty != 0 {
+        if record.threat_penalty != 0 {
             println!(
                 "  !! Active threats inflict {} point penalty",
-                threat_penalty
+                record.threat_penalty
             );
-            score.add(round, threat_penalty, "Unresolved threats");
         }
 
+        for (milestone, bonus) in &record.milestones {
+            println!(
+                "  ** MILESTONE: {} ({:+} points)",
+                milestone.describe(),
+                bonus
+            );
+        }
+
+        let score_delta = record.score_delta;
+        let galaxy = orchestrator.galaxy();
+        let score = orchestrator.score();
+
         if cfg.galnet {
             println!();
             println!(
@@ -413,7 +493,7 @@ fn main() {
                 galnet_blurb(
                     round,
                     winner,
-                    outcome.score_delta,
+                    score_delta,
                     score.total,
                     galaxy.threats.len(),
                     galaxy.discoveries.len(),
@@ -433,10 +513,37 @@ fn main() {
         );
     }
 
-    print_final_report(&galaxy, &score, &bots);
+    let report = orchestrator.finish();
+    print_final_report(&report.galaxy, &report.score, &bots);
 
     if let Some(ref path) = cfg.report_json {
-        write_json_report(path, &galaxy, &score, &bots, cfg.rounds);
+        write_json_report(path, &report.galaxy, &report.score, &bots, cfg.rounds);
+    }
+}
+
+/// Run a scenario file and print the final report.
+fn run_scenario_file(path: &str, report_json: Option<&str>) {
+    let scenario = match Scenario::load(path) {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+    let bots = match scenario.build_council(&bot_registry()) {
+        Ok(b) => b,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+
+    print_banner(scenario.rounds, bots.len() as u32);
+    let report = scenario.run(&bots);
+    print_final_report(&report.galaxy, &report.score, &bots);
+
+    if let Some(path) = report_json {
+        write_json_report(path, &report.galaxy, &report.score, &bots, scenario.rounds);
     }
 }
 
@@ -462,6 +569,76 @@ struct SimulationReport {
     worst_moment: Option<ScoreMoment>,
 }
 
+/// Final-score statistics over repeated runs.
+#[derive(Debug, Clone, PartialEq)]
+struct AggregateStats {
+    runs: usize,
+    mean: f64,
+    stddev: f64,
+    min: i32,
+    max: i32,
+}
+
+impl SimulationReport {
+    /// Mean/population stddev/min/max of `final_score` across reports.
+    fn aggregate(reports: &[SimulationReport]) -> AggregateStats {
+        let runs = reports.len();
+        if runs == 0 {
+            return AggregateStats {
+                runs: 0,
+                mean: 0.0,
+                stddev: 0.0,
+                min: 0,
+                max: 0,
+            };
+        }
+        let scores: Vec<f64> = reports.iter().map(|r| r.final_score as f64).collect();
+        let mean = scores.iter().sum::<f64>() / runs as f64;
+        let variance = scores.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / runs as f64;
+        AggregateStats {
+            runs,
+            mean,
+            stddev: variance.sqrt(),
+            min: reports.iter().map(|r| r.final_score).min().unwrap_or(0),
+            max: reports.iter().map(|r| r.final_score).max().unwrap_or(0),
+        }
+    }
+}
+
+/// Run `runs` simulations with seeds `base_seed`, `base_seed + 1`, ... and
+/// aggregate their final scores.
+fn run_repeated(
+    bots: &[Box<dyn GalacticCouncilMember>],
+    templates: &[Box<dyn EventTemplate>],
+    rounds: u32,
+    runs: u32,
+    base_seed: u64,
+    config: &OrchestratorConfig,
+) -> AggregateStats {
+    let reports: Vec<SimulationReport> = (0..runs)
+        .map(|i| {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(base_seed.wrapping_add(i as u64));
+            let report = run_galactic(bots, templates, rounds, config.clone(), &mut rng);
+            build_report(&report.galaxy, &report.score, bots, rounds)
+        })
+        .collect();
+    SimulationReport::aggregate(&reports)
+}
+
+fn print_aggregate(stats: &AggregateStats, rounds: u32, base_seed: u64) {
+    println!(
+        "Ran {} simulations of {} rounds (seeds {}..{})",
+        stats.runs,
+        rounds,
+        base_seed,
+        base_seed.wrapping_add(stats.runs as u64)
+    );
+    println!(
+        "  Final score: mean {:.1}, stddev {:.1}, min {}, max {}",
+        stats.mean, stats.stddev, stats.min, stats.max
+    );
+}
+
 #[derive(Serialize)]
 struct ScoreMoment {
     round: u32,
@@ -469,13 +646,12 @@ struct ScoreMoment {
     reason: String,
 }
 
-fn write_json_report(
-    path: &str,
+fn build_report(
     galaxy: &GalaxyState,
     score: &ScoreTracker,
     bots: &[Box<dyn GalacticCouncilMember>],
     rounds: u32,
-) {
+) -> SimulationReport {
     let ally_bonus = galaxy.allied_count() as i32 * 10;
     let hostile_penalty = galaxy.hostile_count() as i32 * -5;
     let discovery_bonus = galaxy.discoveries.len() as i32 * 5;
@@ -489,7 +665,7 @@ fn write_json_report(
         _ => "Dysfunctional",
     };
 
-    let report = SimulationReport {
+    SimulationReport {
         rounds,
         member_count: bots.len(),
         base_score: score.total,
@@ -514,8 +690,17 @@ fn write_json_report(
             delta: e.delta,
             reason: e.reason.clone(),
         }),
-    };
+    }
+}
 
+fn write_json_report(
+    path: &str,
+    galaxy: &GalaxyState,
+    score: &ScoreTracker,
+    bots: &[Box<dyn GalacticCouncilMember>],
+    rounds: u32,
+) {
+    let report = build_report(galaxy, score, bots, rounds);
     match serde_json::to_string_pretty(&report) {
         Ok(json) => match std::fs::write(path, &json) {
             Ok(()) => println!("  JSON report written to {}", path),
@@ -525,6 +710,135 @@ fn write_json_report(
     }
 }
 
+/// Largest round count the demo server will simulate per request.
+const MAX_SERVE_ROUNDS: u32 = 1000;
+
+/// Run a tiny single-threaded HTTP server answering `GET /simulate`.
+///
+/// This is a demo for browser front-ends, not a production server: one
+/// connection at a time, no keep-alive, and only the default deterministic bots.
+fn serve(port: u16) {
+    use std::net::TcpListener;
+
+    let listener = match TcpListener::bind(("127.0.0.1", port)) {
+        Ok(l) => l,
+        Err(e) => {
+            eprintln!("failed to bind 127.0.0.1:{}: {}", port, e);
+            std::process::exit(2);
+        }
+    };
+    println!(
+        "Serving on http://127.0.0.1:{}/simulate?rounds=25&seed=42",
+        port
+    );
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => handle_connection(stream),
+            Err(e) => eprintln!("connection failed: {}", e),
+        }
+    }
+}
+
+fn handle_connection(mut stream: std::net::TcpStream) {
+    use std::io::{BufRead, BufReader, Write};
+    use std::time::Duration;
+
+    let _ = stream.set_read_timeout(Some(Duration::from_secs(5)));
+    let mut request_line = String::new();
+    {
+        let mut reader = BufReader::new(&stream);
+        if reader.read_line(&mut request_line).is_err() {
+            return;
+        }
+        // Drain headers; the request body (if any) is ignored.
+        let mut line = String::new();
+        while reader.read_line(&mut line).map(|n| n > 0).unwrap_or(false) {
+            if line == "\r\n" || line == "\n" {
+                break;
+            }
+            line.clear();
+        }
+    }
+
+    let (status, body) = handle_request(request_line.trim_end());
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        _ => "Internal Server Error",
+    };
+    let response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason,
+        body.len(),
+        body
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
+
+/// Route a request line like `GET /simulate?rounds=5&seed=1 HTTP/1.1`.
+///
+/// Returns the HTTP status code and a JSON body.
+fn handle_request(request_line: &str) -> (u16, String) {
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or("");
+    let target = parts.next().unwrap_or("");
+
+    if method != "GET" {
+        return (405, json_error("only GET is supported"));
+    }
+
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    if path != "/simulate" {
+        return (404, json_error("unknown path; use /simulate"));
+    }
+
+    let mut rounds = DEFAULT_ROUNDS;
+    let mut seed = 42u64;
+    for pair in query.split('&').filter(|p| !p.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        match key {
+            "rounds" => match value.parse::<u32>() {
+                Ok(n) if (1..=MAX_SERVE_ROUNDS).contains(&n) => rounds = n,
+                _ => {
+                    return (
+                        400,
+                        json_error(&format!("rounds must be 1..={}", MAX_SERVE_ROUNDS)),
+                    )
+                }
+            },
+            "seed" => match value.parse::<u64>() {
+                Ok(s) => seed = s,
+                Err(_) => return (400, json_error("seed must be a valid u64")),
+            },
+            _ => {}
+        }
+    }
+
+    let bots = default_bots();
+    let templates = default_templates();
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+    let report = run_galactic(
+        &bots,
+        &templates,
+        rounds,
+        OrchestratorConfig::default(),
+        &mut rng,
+    );
+    let summary = build_report(&report.galaxy, &report.score, &bots, rounds);
+    match serde_json::to_string(&summary) {
+        Ok(json) => (200, json),
+        Err(e) => (500, json_error(&e.to_string())),
+    }
+}
+
+fn json_error(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
+
 fn galnet_blurb(
     round: u32,
     winner: usize,
@@ -928,4 +1242,75 @@ mod tests {
         // Clean up
         let _ = std::fs::remove_file(&path);
     }
+
+    #[test]
+    fn serve_handler_returns_report_json() {
+        let (status, body) = handle_request("GET /simulate?rounds=5&seed=7 HTTP/1.1");
+        assert_eq!(status, 200);
+        let parsed: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(parsed["rounds"], 5);
+        assert_eq!(parsed["member_count"], 5);
+
+        let (_, again) = handle_request("GET /simulate?rounds=5&seed=7 HTTP/1.1");
+        assert_eq!(body, again, "same seed must serve the same report");
+    }
+
+    #[test]
+    fn serve_handler_rejects_bad_requests() {
+        assert_eq!(handle_request("GET /other HTTP/1.1").0, 404);
+        assert_eq!(handle_request("POST /simulate HTTP/1.1").0, 405);
+        assert_eq!(handle_request("GET /simulate?rounds=0 HTTP/1.1").0, 400);
+        assert_eq!(handle_request("GET /simulate?seed=abc HTTP/1.1").0, 400);
+    }
+
+    #[test]
+    fn repeat_aggregates_over_runs() {
+        let bots = default_bots();
+        let templates = default_templates();
+        let config = OrchestratorConfig::default();
+        let stats = run_repeated(&bots, &templates, 5, 3, 10, &config);
+        assert_eq!(stats.runs, 3);
+
+        let finals: Vec<i32> = (10..13)
+            .map(|seed| {
+                let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+                let report = run_galactic(&bots, &templates, 5, config.clone(), &mut rng);
+                build_report(&report.galaxy, &report.score, &bots, 5).final_score
+            })
+            .collect();
+        let mean = finals.iter().sum::<i32>() as f64 / 3.0;
+        assert!((stats.mean - mean).abs() < 1e-9);
+        assert_eq!(stats.min, *finals.iter().min().unwrap());
+        assert_eq!(stats.max, *finals.iter().max().unwrap());
+        assert!(stats.stddev >= 0.0);
+    }
+
+    #[test]
+    fn aggregate_of_no_reports_is_zero() {
+        let stats = SimulationReport::aggregate(&[]);
+        assert_eq!(stats.runs, 0);
+        assert_eq!(stats.mean, 0.0);
+    }
+
+    #[test]
+    fn bot_registry_covers_default_council() {
+        let registry = bot_registry();
+        for bot in default_bots() {
+            let built = registry.build(bot.name()).expect("default bot registered");
+            assert_eq!(built.name(), bot.name());
+        }
+    }
+
+    #[test]
+    fn scenario_runs_with_registered_bots() {
+        let scenario = Scenario::from_json(
+            r#"{"seed": 3, "rounds": 4, "bots": ["oracle-bot", "cycle-bot", "first-bot"]}"#,
+        )
+        .unwrap();
+        let bots = scenario.build_council(&bot_registry()).unwrap();
+        let a = scenario.run(&bots);
+        let b = scenario.run(&bots);
+        assert_eq!(a.rounds.len(), 4);
+        assert_eq!(a.score.total, b.score.total);
+    }
 }
@@ -0,0 +1,171 @@
+//! Head-to-head comparison of two galactic runs.
+
+use crate::orchestrator::GalacticReport;
+
+/// Which council was ahead at a point in the run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Leader {
+    A,
+    B,
+    Tied,
+}
+
+/// Both councils' standing after one round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundComparison {
+    pub round: u32,
+    /// Score gained or lost this round by council A.
+    pub delta_a: i32,
+    /// Score gained or lost this round by council B.
+    pub delta_b: i32,
+    /// Running total of A minus running total of B.
+    pub margin: i32,
+    pub leader: Leader,
+}
+
+/// Round-by-round breakdown of two councils' runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CouncilComparison {
+    pub rounds: Vec<RoundComparison>,
+    /// Times the lead passed from one council to the other (ties neither
+    /// count nor reset the current leader).
+    pub lead_changes: u32,
+    /// Margin after the last paired round (0 when no rounds pair up).
+    pub final_margin: i32,
+}
+
+impl CouncilComparison {
+    /// Leader at the end of the run.
+    pub fn winner(&self) -> Leader {
+        leader_of(self.final_margin)
+    }
+}
+
+fn leader_of(margin: i32) -> Leader {
+    match margin {
+        m if m > 0 => Leader::A,
+        m if m < 0 => Leader::B,
+        _ => Leader::Tied,
+    }
+}
+
+/// Compare two councils round by round.
+///
+/// The comparison is only meaningful when both reports come from runs with
+/// the same seed, templates, starting galaxy and round count, so that any
+/// difference is down to the councils' votes. Rounds are paired in order; if
+/// one report is longer, its extra rounds are ignored.
+pub fn compare_councils(a: &GalacticReport, b: &GalacticReport) -> CouncilComparison {
+    let mut rounds = Vec::new();
+    let mut lead_changes = 0;
+    let mut current = Leader::Tied;
+    let (mut previous_a, mut previous_b) = (0, 0);
+    for (ra, rb) in a.rounds.iter().zip(&b.rounds) {
+        let margin = ra.score_total - rb.score_total;
+        let leader = leader_of(margin);
+        if leader != Leader::Tied {
+            if current != Leader::Tied && leader != current {
+                lead_changes += 1;
+            }
+            current = leader;
+        }
+        rounds.push(RoundComparison {
+            round: ra.round,
+            delta_a: ra.score_total - previous_a,
+            delta_b: rb.score_total - previous_b,
+            margin,
+            leader,
+        });
+        previous_a = ra.score_total;
+        previous_b = rb.score_total;
+    }
+    CouncilComparison {
+        final_margin: rounds.last().map_or(0, |r| r.margin),
+        rounds,
+        lead_changes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::galaxy::GalaxyState;
+    use crate::orchestrator::{GalaxySnapshot, RoundRecord};
+    use crate::scoring::ScoreTracker;
+    use crate::test_util::make_event;
+    use std::collections::HashMap;
+
+    /// A report whose running totals follow `totals`.
+    fn report(totals: &[i32]) -> GalacticReport {
+        let mut score = ScoreTracker::new();
+        let mut rounds = Vec::new();
+        let mut previous = 0;
+        for (i, &total) in totals.iter().enumerate() {
+            let round = i as u32 + 1;
+            score.add(round, total - previous, "Test");
+            previous = total;
+            rounds.push(RoundRecord {
+                round,
+                event: make_event(1),
+                comments: vec![],
+                votes: vec![],
+                traces: vec![],
+                winner: 0,
+                all_abstained: false,
+                score_delta: 0,
+                changes_skipped: false,
+                threat_penalty: 0,
+                milestones: vec![],
+                score_total: total,
+                snapshot: GalaxySnapshot::default(),
+                rng_draws: vec![],
+            });
+        }
+        GalacticReport {
+            rounds,
+            galaxy: GalaxyState::new(),
+            score,
+            influence_by_tag: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn counts_lead_changes() {
+        // B leads round 1, A pulls ahead in round 2, B retakes it in round 4.
+        let a = report(&[5, 20, 25, 25]);
+        let b = report(&[10, 15, 25, 30]);
+        let comparison = compare_councils(&a, &b);
+
+        let leaders: Vec<Leader> = comparison.rounds.iter().map(|r| r.leader).collect();
+        assert_eq!(leaders, vec![Leader::B, Leader::A, Leader::Tied, Leader::B]);
+        assert_eq!(comparison.rounds[1].delta_a, 15);
+        assert_eq!(comparison.rounds[1].delta_b, 5);
+        assert_eq!(comparison.rounds[1].margin, 5);
+        assert_eq!(comparison.lead_changes, 2);
+        assert_eq!(comparison.final_margin, -5);
+        assert_eq!(comparison.winner(), Leader::B);
+    }
+
+    #[test]
+    fn identical_reports_never_change_lead() {
+        let a = report(&[5, 10, 15]);
+        let comparison = compare_councils(&a, &a);
+        assert_eq!(comparison.lead_changes, 0);
+        assert_eq!(comparison.winner(), Leader::Tied);
+        assert!(comparison.rounds.iter().all(|r| r.margin == 0));
+    }
+
+    #[test]
+    fn final_margin_ignores_unpaired_rounds() {
+        let a = report(&[5, 10]);
+        let b = report(&[0, 5, 100]);
+        let comparison = compare_councils(&a, &b);
+        assert_eq!(comparison.rounds.len(), 2);
+        assert_eq!(comparison.final_margin, 5);
+        assert_eq!(comparison.winner(), Leader::A);
+
+        let empty = compare_councils(&report(&[]), &b);
+        assert_eq!(empty.final_margin, 0);
+        assert_eq!(empty.winner(), Leader::Tied);
+    }
+}
@@ -2,6 +2,26 @@
 
 use crate::galaxy::{GalaxyState, StateChange};
 
+/// Description of the option [`Event::clamp_options`] appends.
+pub const DEFER_OPTION: &str = "None of the above — defer the decision";
+
+/// Filler alternatives [`Event::pad_options`] adds, as `(option, outcome)`
+/// descriptions; each has a zero-score, no-op outcome.
+pub const FILLER_OPTIONS: [(&str, &str); 3] = [
+    (
+        "Delay the decision",
+        "The council postpones the matter; nothing changes.",
+    ),
+    (
+        "Delegate to a committee",
+        "A committee takes the matter under advisement; nothing changes.",
+    ),
+    (
+        "Consult the archives first",
+        "The archives offer little guidance; nothing changes.",
+    ),
+];
+
 /// An event the council must respond to.
 #[derive(Debug, Clone)]
 pub struct Event {
@@ -13,6 +33,88 @@ pub struct Event {
     pub options: Vec<ResponseOption>,
 }
 
+impl Event {
+    /// Order-independent key for option `i` (its description).
+    ///
+    /// Used by [`crate::voting::resolve_votes_stable`] to break ties without
+    /// depending on option position.
+    pub fn stable_option_key(&self, i: usize) -> &str {
+        &self.options[i].description
+    }
+
+    /// Indices of the options the galaxy has unlocked (see
+    /// [`ResponseOption::required_discovery`]).
+    pub fn available_options(&self, galaxy: &GalaxyState) -> Vec<usize> {
+        (0..self.options.len())
+            .filter(|&i| self.options[i].is_available(galaxy))
+            .collect()
+    }
+
+    /// Cap the number of options at `max` (at least 1): beyond that, keep the
+    /// first `max - 1` and append a "defer" option with a no-op outcome.
+    pub fn clamp_options(&mut self, max: usize) {
+        let max = max.max(1);
+        if self.options.len() <= max {
+            return;
+        }
+        self.options.truncate(max - 1);
+        self.options.push(ResponseOption {
+            description: DEFER_OPTION.to_string(),
+            outcome: Outcome {
+                description: "The council defers the matter; nothing changes.".to_string(),
+                score_delta: 0,
+                headline: None,
+                state_changes: vec![],
+            },
+            required_discovery: None,
+        });
+    }
+
+    /// Raise the number of options to `min` by appending
+    /// [`FILLER_OPTIONS`] not already offered, so the council always has a
+    /// genuine choice. At most the three fillers are added.
+    pub fn pad_options(&mut self, min: usize) {
+        for (description, outcome) in FILLER_OPTIONS {
+            if self.options.len() >= min {
+                return;
+            }
+            if self.options.iter().any(|o| o.description == description) {
+                continue;
+            }
+            self.options.push(ResponseOption {
+                description: description.to_string(),
+                outcome: Outcome {
+                    description: outcome.to_string(),
+                    score_delta: 0,
+                    headline: None,
+                    state_changes: vec![],
+                },
+                required_discovery: None,
+            });
+        }
+    }
+
+    /// Short title: the first sentence of the description, without its
+    /// trailing punctuation.
+    pub fn title(&self) -> &str {
+        let end = self
+            .description
+            .find(['.', '!', '?', '\n'])
+            .unwrap_or(self.description.len());
+        self.description[..end].trim()
+    }
+
+    /// One line per option, `"i: option -> outcome (score +n)"`, for bots
+    /// and prompts that are briefed on what each option does.
+    pub fn outcome_briefing(&self) -> String {
+        self.options
+            .iter()
+            .enumerate()
+            .map(|(i, option)| format!("{}: {}\n", i, option.briefing()))
+            .collect()
+    }
+}
+
 /// A possible response to an event.
 #[derive(Debug, Clone)]
 pub struct ResponseOption {
@@ -20,6 +122,27 @@ pub struct ResponseOption {
     pub description: String,
     /// What happens if this option wins.
     pub outcome: Outcome,
+    /// Discovery the galaxy must hold before this option is offered.
+    pub required_discovery: Option<String>,
+}
+
+impl ResponseOption {
+    /// Whether the galaxy holds the discovery this option requires (always
+    /// true for ungated options).
+    pub fn is_available(&self, galaxy: &GalaxyState) -> bool {
+        match &self.required_discovery {
+            Some(name) => galaxy.discoveries.iter().any(|d| &d.name == name),
+            None => true,
+        }
+    }
+
+    /// The option with its outcome revealed: `"option -> outcome (score +n)"`.
+    pub fn briefing(&self) -> String {
+        format!(
+            "{} -> {} (score {:+})",
+            self.description, self.outcome.description, self.outcome.score_delta
+        )
+    }
 }
 
 /// The result of choosing a response option.
@@ -29,10 +152,22 @@ pub struct Outcome {
     pub description: String,
     /// Points gained or lost.
     pub score_delta: i32,
+    /// Optional short headline shown above the description in transcripts.
+    pub headline: Option<String>,
     /// Changes to galaxy state.
     pub state_changes: Vec<StateChange>,
 }
 
+impl Outcome {
+    /// Render as Markdown: a bold headline (if any) above the description.
+    pub fn to_markdown(&self) -> String {
+        match &self.headline {
+            Some(headline) => format!("**{}**\n\n{}", headline, self.description),
+            None => self.description.clone(),
+        }
+    }
+}
+
 /// Trait for event templates that generate concrete events.
 pub trait EventTemplate: Send + Sync {
     /// Name of this template for debugging.
@@ -67,6 +202,81 @@ mod tests {
         assert_eq!(event.relevant_expertise.len(), 2);
     }
 
+    #[test]
+    fn option_requiring_discovery_unlocks_when_present() {
+        use crate::galaxy::Discovery;
+
+        let option = |description: &str, required: Option<&str>| ResponseOption {
+            description: description.to_string(),
+            outcome: Outcome {
+                description: String::new(),
+                score_delta: 0,
+                headline: None,
+                state_changes: vec![],
+            },
+            required_discovery: required.map(str::to_string),
+        };
+        let event = Event {
+            description: "Raiders approach".to_string(),
+            relevant_expertise: vec![],
+            options: vec![
+                option("Evacuate", None),
+                option("Raise the shields", Some("Shield Technology")),
+            ],
+        };
+
+        let mut galaxy = GalaxyState::new();
+        assert_eq!(event.available_options(&galaxy), vec![0]);
+
+        galaxy.discoveries.push(Discovery {
+            name: "Shield Technology".to_string(),
+            category: "engineering".to_string(),
+            source_round: None,
+            source_event: None,
+        });
+        assert_eq!(event.available_options(&galaxy), vec![0, 1]);
+    }
+
+    #[test]
+    fn pad_options_skips_offered_fillers_and_caps_at_three() {
+        let mut event = Event {
+            description: "Routine matters".to_string(),
+            relevant_expertise: vec![],
+            options: vec![],
+        };
+        event.pad_options(1);
+        event.options[0].description = FILLER_OPTIONS[1].0.to_string();
+        event.pad_options(10);
+
+        let descriptions: Vec<&str> = event
+            .options
+            .iter()
+            .map(|o| o.description.as_str())
+            .collect();
+        assert_eq!(
+            descriptions,
+            vec![
+                FILLER_OPTIONS[1].0,
+                FILLER_OPTIONS[0].0,
+                FILLER_OPTIONS[2].0
+            ]
+        );
+        assert!(event.options.iter().all(|o| o.outcome.score_delta == 0));
+    }
+
+    #[test]
+    fn title_is_first_sentence() {
+        let mut event = Event {
+            description: "A derelict drifts nearby. Scans show life signs.".to_string(),
+            relevant_expertise: vec![],
+            options: vec![],
+        };
+        assert_eq!(event.title(), "A derelict drifts nearby");
+
+        event.description = "Quiet skies".to_string();
+        assert_eq!(event.title(), "Quiet skies");
+    }
+
     #[test]
     fn outcome_can_have_state_changes() {
         use crate::galaxy::{Sector, SectorType};
@@ -74,6 +284,7 @@ mod tests {
         let outcome = Outcome {
             description: "Discovered new sector".to_string(),
             score_delta: 10,
+            headline: None,
             state_changes: vec![StateChange::AddSector(Sector {
                 name: "New Sector".to_string(),
                 sector_type: SectorType::Nebula,
@@ -82,4 +293,21 @@ mod tests {
         assert_eq!(outcome.score_delta, 10);
         assert_eq!(outcome.state_changes.len(), 1);
     }
+
+    #[test]
+    fn outcome_markdown_bolds_headline() {
+        let mut outcome = Outcome {
+            description: "The fleet returns home.".to_string(),
+            score_delta: 5,
+            headline: None,
+            state_changes: vec![],
+        };
+        assert_eq!(outcome.to_markdown(), "The fleet returns home.");
+
+        outcome.headline = Some("Victory at Vega".to_string());
+        assert_eq!(
+            outcome.to_markdown(),
+            "**Victory at Vega**\n\nThe fleet returns home."
+        );
+    }
 }
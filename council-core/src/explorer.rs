@@ -1,13 +1,23 @@
 //! Expanded trait for galactic exploration bots.
 
-use crate::event::Event;
+use crate::event::{Event, ResponseOption};
 use crate::galaxy::GalaxyState;
+use crate::scoring::ScoreTracker;
+use crate::{Context, CouncilMember, Decision};
+use std::collections::VecDeque;
+use std::sync::Mutex;
 
 /// Trait for bots participating in the galactic exploration simulation.
 ///
 /// This is the expanded interface that supports expertise-weighted voting
 /// on procedurally generated events.
-pub trait GalacticCouncilMember: Send + Sync {
+///
+/// Implementors must be `Clone` so councils can be duplicated through
+/// [`CloneMember::clone_box`]. This bound is a breaking change for bots
+/// written before it: most only need `#[derive(Clone)]`, while bots holding
+/// non-`Clone` state such as a `Mutex` need a hand-written `Clone` (see
+/// [`VarietyBot`]).
+pub trait GalacticCouncilMember: CloneMember + Send + Sync {
     /// Bot's display name.
     fn name(&self) -> &'static str;
 
@@ -24,6 +34,101 @@ pub trait GalacticCouncilMember: Send + Sync {
     /// Returns the index of the chosen response option (0-indexed).
     fn vote(&self, event: &Event, galaxy: &GalaxyState) -> usize;
 
+    /// Vote with read-only access to the running score.
+    ///
+    /// The orchestrator calls this instead of [`vote`](Self::vote); the
+    /// default ignores the score and delegates to `vote`.
+    fn vote_with_context(
+        &self,
+        event: &Event,
+        galaxy: &GalaxyState,
+        _score: &ScoreTracker,
+    ) -> usize {
+        self.vote(event, galaxy)
+    }
+
+    /// Vote, or return `None` to abstain from this event.
+    ///
+    /// The orchestrator calls this for council bots under plurality
+    /// resolution; an abstaining bot casts no vote. The default never
+    /// abstains and delegates to
+    /// [`vote_with_context`](Self::vote_with_context). Faction members that
+    /// abstain are left out of their bloc's line.
+    fn vote_or_abstain(
+        &self,
+        event: &Event,
+        galaxy: &GalaxyState,
+        score: &ScoreTracker,
+    ) -> Option<usize> {
+        Some(self.vote_with_context(event, galaxy, score))
+    }
+
+    /// Whether to take part in this event's vote at all. Defaults to `true`.
+    ///
+    /// The orchestrator asks first and casts no vote or ballot for a bot
+    /// that declines, so an unsure bot can sit out instead of half-picking
+    /// an option. A bot that only knows after deliberating can return
+    /// `None` from [`vote_or_abstain`](Self::vote_or_abstain) instead.
+    fn will_vote(&self, _event: &Event, _galaxy: &GalaxyState) -> bool {
+        true
+    }
+
+    /// How sure the bot is of its pick for this event, from 0.0 to 1.0.
+    ///
+    /// The orchestrator asks right after the vote and scales the vote's
+    /// weight by the clamped value, so an unsure bot can down-weight itself.
+    /// Defaults to full confidence.
+    fn confidence(&self, _event: &Event, _galaxy: &GalaxyState) -> f32 {
+        1.0
+    }
+
+    /// Options this bot approves of, for approval voting.
+    ///
+    /// Under approval voting the orchestrator calls this instead of
+    /// [`vote_or_abstain`](Self::vote_or_abstain), records the first entry
+    /// as the bot's vote, and counts an empty list as an abstention.
+    /// Defaults to the single option from [`vote`](Self::vote).
+    fn approve_options(&self, event: &Event, galaxy: &GalaxyState) -> Vec<usize> {
+        vec![self.vote(event, galaxy)]
+    }
+
+    /// Options in order of preference, for ranked-choice voting.
+    ///
+    /// Under ranked-choice voting this replaces
+    /// [`vote_or_abstain`](Self::vote_or_abstain) the same way
+    /// [`approve_options`](Self::approve_options) does under approval.
+    /// Defaults to the [`vote`](Self::vote) pick followed by the remaining
+    /// options in index order.
+    fn rank_options(&self, event: &Event, galaxy: &GalaxyState) -> Vec<usize> {
+        let first = self.vote(event, galaxy);
+        std::iter::once(first)
+            .chain((0..event.options.len()).filter(|&i| i != first))
+            .collect()
+    }
+
+    /// Ranked preferences with one-word rationales, most preferred first,
+    /// e.g. `[(0, "defensive"), (2, "aggressive")]`.
+    ///
+    /// Intended for UIs; should be deterministic. Defaults to the single
+    /// [`vote`](Self::vote) pick.
+    fn preferences(&self, event: &Event, galaxy: &GalaxyState) -> Vec<(usize, &'static str)> {
+        vec![(self.vote(event, galaxy), "preferred")]
+    }
+
+    /// Called by the orchestrator after each round with the option the
+    /// council resolved. Bots that remember past rounds record it through
+    /// interior mutability; the default ignores it.
+    fn observe_winner(&self, _event: &Event, _winner: usize) {}
+
+    /// Optional extra option to add to the event before voting, e.g. "seek
+    /// a third path". The outcome must be deterministic; it is applied like
+    /// any template option if the proposal wins.
+    ///
+    /// Only consulted when the orchestrator's proposal phase is enabled.
+    fn propose_option(&self, _event: &Event, _galaxy: &GalaxyState) -> Option<ResponseOption> {
+        None
+    }
+
     /// Optional deliberation comment for this event.
     ///
     /// Used when the simulation runs in a "deliberation" mode where bots
@@ -31,13 +136,522 @@ pub trait GalacticCouncilMember: Send + Sync {
     fn comment(&self, _event: &Event, _galaxy: &GalaxyState) -> Option<String> {
         None
     }
+
+    /// Optional structured record of the inputs behind this bot's vote.
+    ///
+    /// Used for reproducibility audits when the orchestrator runs with
+    /// tracing enabled. Bots whose decisions are not reproducible (e.g. LLM
+    /// calls) should return `None`.
+    fn trace(&self, _event: &Event, _galaxy: &GalaxyState) -> Option<VoteTrace> {
+        None
+    }
+}
+
+/// Object-safe cloning for boxed council members.
+///
+/// Blanket-implemented for every `Clone` bot, which makes
+/// `Box<dyn GalacticCouncilMember>` (and so whole councils) cloneable.
+pub trait CloneMember {
+    fn clone_box(&self) -> Box<dyn GalacticCouncilMember>;
+}
+
+impl<T: GalacticCouncilMember + Clone + 'static> CloneMember for T {
+    fn clone_box(&self) -> Box<dyn GalacticCouncilMember> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn GalacticCouncilMember> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+/// Decision inputs recorded by a bot for a single vote.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoteTrace {
+    /// Human-readable name of the strategy branch that produced the vote.
+    pub branch: &'static str,
+    /// Sum of active threat severities.
+    pub threat_pressure: u32,
+    /// Number of hostile species.
+    pub hostile_count: usize,
+    /// Number of allied species.
+    pub allied_count: usize,
+    /// Event expertise tags the bot has, with the bot's proficiency.
+    pub matched_expertise: Vec<(String, f32)>,
+    /// Index of the chosen option.
+    pub chosen: usize,
+}
+
+/// Implements the listed [`GalacticCouncilMember`] methods by forwarding to
+/// `self.inner`, so wrappers only spell out the methods they change.
+macro_rules! delegate_to_inner {
+    ($($method:ident),* $(,)?) => {
+        $(delegate_to_inner!(@one $method);)*
+    };
+    (@one name) => {
+        fn name(&self) -> &'static str {
+            self.inner.name()
+        }
+    };
+    (@one expertise) => {
+        fn expertise(&self) -> &[(&'static str, f32)] {
+            self.inner.expertise()
+        }
+    };
+    (@one vote) => {
+        fn vote(&self, event: &Event, galaxy: &GalaxyState) -> usize {
+            self.inner.vote(event, galaxy)
+        }
+    };
+    (@one vote_with_context) => {
+        fn vote_with_context(
+            &self,
+            event: &Event,
+            galaxy: &GalaxyState,
+            score: &ScoreTracker,
+        ) -> usize {
+            self.inner.vote_with_context(event, galaxy, score)
+        }
+    };
+    (@one vote_or_abstain) => {
+        fn vote_or_abstain(
+            &self,
+            event: &Event,
+            galaxy: &GalaxyState,
+            score: &ScoreTracker,
+        ) -> Option<usize> {
+            self.inner.vote_or_abstain(event, galaxy, score)
+        }
+    };
+    (@one will_vote) => {
+        fn will_vote(&self, event: &Event, galaxy: &GalaxyState) -> bool {
+            self.inner.will_vote(event, galaxy)
+        }
+    };
+    (@one confidence) => {
+        fn confidence(&self, event: &Event, galaxy: &GalaxyState) -> f32 {
+            self.inner.confidence(event, galaxy)
+        }
+    };
+    (@one approve_options) => {
+        fn approve_options(&self, event: &Event, galaxy: &GalaxyState) -> Vec<usize> {
+            self.inner.approve_options(event, galaxy)
+        }
+    };
+    (@one rank_options) => {
+        fn rank_options(&self, event: &Event, galaxy: &GalaxyState) -> Vec<usize> {
+            self.inner.rank_options(event, galaxy)
+        }
+    };
+    (@one preferences) => {
+        fn preferences(&self, event: &Event, galaxy: &GalaxyState) -> Vec<(usize, &'static str)> {
+            self.inner.preferences(event, galaxy)
+        }
+    };
+    (@one propose_option) => {
+        fn propose_option(&self, event: &Event, galaxy: &GalaxyState) -> Option<ResponseOption> {
+            self.inner.propose_option(event, galaxy)
+        }
+    };
+    (@one comment) => {
+        fn comment(&self, event: &Event, galaxy: &GalaxyState) -> Option<String> {
+            self.inner.comment(event, galaxy)
+        }
+    };
+    (@one observe_winner) => {
+        fn observe_winner(&self, event: &Event, winner: usize) {
+            self.inner.observe_winner(event, winner)
+        }
+    };
+    (@one trace) => {
+        fn trace(&self, event: &Event, galaxy: &GalaxyState) -> Option<VoteTrace> {
+            self.inner.trace(event, galaxy)
+        }
+    };
+}
+
+/// Development wrapper that checks a bot's votes are in range.
+///
+/// Out-of-range votes are clamped to the last option, and a warning naming
+/// the bot is printed to stderr and kept for inspection via
+/// [`CheckedMember::warnings`].
+pub struct CheckedMember {
+    inner: Box<dyn GalacticCouncilMember>,
+    warnings: Mutex<Vec<String>>,
+}
+
+impl Clone for CheckedMember {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            warnings: Mutex::new(self.warnings()),
+        }
+    }
+}
+
+impl CheckedMember {
+    pub fn new(inner: Box<dyn GalacticCouncilMember>) -> Self {
+        Self {
+            inner,
+            warnings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Warnings emitted so far.
+    pub fn warnings(&self) -> Vec<String> {
+        self.warnings.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+
+    fn check(&self, choice: usize, event: &Event) -> usize {
+        let len = event.options.len();
+        if choice < len || (len == 0 && choice == 0) {
+            return choice;
+        }
+        let clamped = len.saturating_sub(1);
+        let warning = format!(
+            "{} voted for option {} but the event has {} options; clamped to {}",
+            self.inner.name(),
+            choice,
+            len,
+            clamped
+        );
+        eprintln!("warning: {}", warning);
+        if let Ok(mut warnings) = self.warnings.lock() {
+            warnings.push(warning);
+        }
+        clamped
+    }
+}
+
+impl GalacticCouncilMember for CheckedMember {
+    delegate_to_inner!(
+        name,
+        expertise,
+        will_vote,
+        confidence,
+        preferences,
+        propose_option,
+        comment,
+        observe_winner,
+        trace
+    );
+
+    fn vote(&self, event: &Event, galaxy: &GalaxyState) -> usize {
+        self.check(self.inner.vote(event, galaxy), event)
+    }
+
+    fn vote_with_context(
+        &self,
+        event: &Event,
+        galaxy: &GalaxyState,
+        score: &ScoreTracker,
+    ) -> usize {
+        self.check(self.inner.vote_with_context(event, galaxy, score), event)
+    }
+
+    fn vote_or_abstain(
+        &self,
+        event: &Event,
+        galaxy: &GalaxyState,
+        score: &ScoreTracker,
+    ) -> Option<usize> {
+        self.inner
+            .vote_or_abstain(event, galaxy, score)
+            .map(|choice| self.check(choice, event))
+    }
+
+    fn approve_options(&self, event: &Event, galaxy: &GalaxyState) -> Vec<usize> {
+        self.inner
+            .approve_options(event, galaxy)
+            .into_iter()
+            .map(|choice| self.check(choice, event))
+            .collect()
+    }
+
+    fn rank_options(&self, event: &Event, galaxy: &GalaxyState) -> Vec<usize> {
+        self.inner
+            .rank_options(event, galaxy)
+            .into_iter()
+            .map(|choice| self.check(choice, event))
+            .collect()
+    }
+}
+
+/// Wrapper that gives a bot a different display name.
+///
+/// Lets a council hold several instances of the same bot type (e.g. two LLM
+/// bots with different models) with distinguishable votes and summaries.
+#[derive(Clone)]
+pub struct NamedMember {
+    name: &'static str,
+    inner: Box<dyn GalacticCouncilMember>,
+}
+
+impl NamedMember {
+    pub fn new(name: &'static str, inner: Box<dyn GalacticCouncilMember>) -> Self {
+        Self { name, inner }
+    }
+}
+
+impl GalacticCouncilMember for NamedMember {
+    delegate_to_inner!(
+        expertise,
+        vote,
+        vote_with_context,
+        vote_or_abstain,
+        will_vote,
+        confidence,
+        approve_options,
+        rank_options,
+        preferences,
+        propose_option,
+        comment,
+        observe_winner,
+        trace
+    );
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// Wrapper that steers a bot away from options the council resolved in the
+/// last few rounds.
+///
+/// Winners are fed in by the orchestrator through
+/// [`GalacticCouncilMember::observe_winner`]. When the inner bot's pick
+/// matches a recent winner, the wrapper votes for the lowest-index option
+/// that does not, without asking the inner bot again; if every option is
+/// recent, the original pick stands.
+pub struct VarietyBot {
+    inner: Box<dyn GalacticCouncilMember>,
+    window: usize,
+    recent: Mutex<VecDeque<usize>>,
+}
+
+impl Clone for VarietyBot {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            window: self.window,
+            recent: Mutex::new(self.recent_winners().into()),
+        }
+    }
+}
+
+impl VarietyBot {
+    /// Wrap `inner`, remembering the last `window` council winners.
+    pub fn new(inner: Box<dyn GalacticCouncilMember>, window: usize) -> Self {
+        Self {
+            inner,
+            window,
+            recent: Mutex::new(VecDeque::with_capacity(window)),
+        }
+    }
+
+    /// Recent council winners, oldest first.
+    pub fn recent_winners(&self) -> Vec<usize> {
+        self.recent
+            .lock()
+            .map(|r| r.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    fn is_recent(&self, choice: usize) -> bool {
+        self.recent
+            .lock()
+            .map(|r| r.contains(&choice))
+            .unwrap_or(false)
+    }
+
+    fn freshen(&self, choice: usize, event: &Event) -> usize {
+        let recent = self.recent_winners();
+        if !recent.contains(&choice) {
+            return choice;
+        }
+        (0..event.options.len())
+            .find(|i| !recent.contains(i))
+            .unwrap_or(choice)
+    }
+}
+
+impl GalacticCouncilMember for VarietyBot {
+    delegate_to_inner!(
+        name,
+        expertise,
+        will_vote,
+        confidence,
+        approve_options,
+        preferences,
+        propose_option,
+        comment,
+        trace
+    );
+
+    fn vote(&self, event: &Event, galaxy: &GalaxyState) -> usize {
+        self.freshen(self.inner.vote(event, galaxy), event)
+    }
+
+    fn vote_with_context(
+        &self,
+        event: &Event,
+        galaxy: &GalaxyState,
+        score: &ScoreTracker,
+    ) -> usize {
+        self.freshen(self.inner.vote_with_context(event, galaxy, score), event)
+    }
+
+    fn vote_or_abstain(
+        &self,
+        event: &Event,
+        galaxy: &GalaxyState,
+        score: &ScoreTracker,
+    ) -> Option<usize> {
+        self.inner
+            .vote_or_abstain(event, galaxy, score)
+            .map(|choice| self.freshen(choice, event))
+    }
+
+    /// The inner ranking with recent winners moved to the back.
+    fn rank_options(&self, event: &Event, galaxy: &GalaxyState) -> Vec<usize> {
+        let (fresh, recent): (Vec<usize>, Vec<usize>) = self
+            .inner
+            .rank_options(event, galaxy)
+            .into_iter()
+            .partition(|&choice| !self.is_recent(choice));
+        fresh.into_iter().chain(recent).collect()
+    }
+
+    fn observe_winner(&self, event: &Event, winner: usize) {
+        if let Ok(mut recent) = self.recent.lock() {
+            recent.push_back(winner);
+            while recent.len() > self.window {
+                recent.pop_front();
+            }
+        }
+        self.inner.observe_winner(event, winner)
+    }
+}
+
+/// Wrapper that only votes on events in the inner bot's wheelhouse.
+///
+/// The bot abstains (see [`GalacticCouncilMember::vote_or_abstain`]) when
+/// its best proficiency among the event's expertise tags is below
+/// `threshold`; events with no matching tag count as 0.0.
+#[derive(Clone)]
+pub struct SpecialistBot {
+    inner: Box<dyn GalacticCouncilMember>,
+    threshold: f32,
+}
+
+impl SpecialistBot {
+    pub fn new(inner: Box<dyn GalacticCouncilMember>, threshold: f32) -> Self {
+        Self { inner, threshold }
+    }
+
+    /// The inner bot's highest proficiency among the event's tags.
+    pub fn depth(&self, event: &Event) -> f32 {
+        event
+            .relevant_expertise
+            .iter()
+            .filter_map(|(tag, _)| {
+                self.inner
+                    .expertise()
+                    .iter()
+                    .find(|(bot_tag, _)| bot_tag == tag)
+                    .map(|(_, proficiency)| *proficiency)
+            })
+            .fold(0.0, f32::max)
+    }
+}
+
+impl GalacticCouncilMember for SpecialistBot {
+    delegate_to_inner!(
+        name,
+        expertise,
+        vote,
+        vote_with_context,
+        confidence,
+        approve_options,
+        rank_options,
+        preferences,
+        propose_option,
+        comment,
+        observe_winner,
+        trace
+    );
+
+    fn vote_or_abstain(
+        &self,
+        event: &Event,
+        galaxy: &GalaxyState,
+        score: &ScoreTracker,
+    ) -> Option<usize> {
+        if self.depth(event) < self.threshold {
+            return None;
+        }
+        self.inner.vote_or_abstain(event, galaxy, score)
+    }
+
+    fn will_vote(&self, event: &Event, galaxy: &GalaxyState) -> bool {
+        self.depth(event) >= self.threshold && self.inner.will_vote(event, galaxy)
+    }
+}
+
+/// Adapter that lets a galactic bot sit in a classic [`CouncilMember`]
+/// council, for reporting through the legacy [`SimulationReport`](crate::SimulationReport).
+///
+/// Feed it each round's event with [`GalacticAsClassic::on_round_start`].
+/// The bot's pick maps to [`Decision::Approve`] for the first option,
+/// [`Decision::Reject`] for the last, and [`Decision::Abstain`] for anything
+/// in between (or before any event has been set).
+#[derive(Clone)]
+pub struct GalacticAsClassic {
+    inner: Box<dyn GalacticCouncilMember>,
+    current: Option<(Event, GalaxyState)>,
+}
+
+impl GalacticAsClassic {
+    pub fn new(inner: Box<dyn GalacticCouncilMember>) -> Self {
+        Self {
+            inner,
+            current: None,
+        }
+    }
+
+    /// Set the event and galaxy the next classic votes are about.
+    pub fn on_round_start(&mut self, event: Event, galaxy: GalaxyState) {
+        self.current = Some((event, galaxy));
+    }
+}
+
+impl CouncilMember for GalacticAsClassic {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn vote(&self, _ctx: &Context) -> Decision {
+        let Some((event, galaxy)) = &self.current else {
+            return Decision::Abstain;
+        };
+        let last = event.options.len().saturating_sub(1);
+        match self.inner.vote(event, galaxy) {
+            0 => Decision::Approve,
+            choice if choice >= last => Decision::Reject,
+            _ => Decision::Abstain,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::event::{Outcome, ResponseOption};
+    use crate::test_util::make_event;
 
+    #[derive(Clone)]
     struct TestExplorer;
 
     impl GalacticCouncilMember for TestExplorer {
@@ -74,16 +688,20 @@ mod tests {
                     outcome: Outcome {
                         description: "A".to_string(),
                         score_delta: 0,
+                        headline: None,
                         state_changes: vec![],
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Option B".to_string(),
                     outcome: Outcome {
                         description: "B".to_string(),
                         score_delta: 0,
+                        headline: None,
                         state_changes: vec![],
                     },
+                    required_discovery: None,
                 },
             ],
         };
@@ -91,4 +709,164 @@ mod tests {
         let choice = bot.vote(&event, &galaxy);
         assert!(choice < event.options.len());
     }
+
+    #[derive(Clone)]
+    struct WildBot;
+
+    impl GalacticCouncilMember for WildBot {
+        fn name(&self) -> &'static str {
+            "wild-bot"
+        }
+
+        fn expertise(&self) -> &[(&'static str, f32)] {
+            &[]
+        }
+
+        fn vote(&self, _event: &Event, _galaxy: &GalaxyState) -> usize {
+            99
+        }
+    }
+
+    #[test]
+    fn checked_member_clamps_and_warns() {
+        let bot = CheckedMember::new(Box::new(WildBot));
+        let event = make_event(3);
+        let galaxy = GalaxyState::new();
+
+        assert_eq!(bot.vote(&event, &galaxy), 2);
+        let warnings = bot.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("wild-bot"));
+        assert!(warnings[0].contains("99"));
+    }
+
+    #[test]
+    fn checked_member_passes_valid_votes_silently() {
+        let bot = CheckedMember::new(Box::new(TestExplorer));
+        let event = make_event(3);
+        assert_eq!(bot.vote(&event, &GalaxyState::new()), 0);
+        assert_eq!(bot.name(), "test-explorer");
+        assert!(bot.warnings().is_empty());
+    }
+
+    #[test]
+    fn default_ballots_follow_vote() {
+        let bot = TestExplorer;
+        let event = make_event(3);
+        let galaxy = GalaxyState::new();
+        assert_eq!(bot.approve_options(&event, &galaxy), vec![0]);
+        assert_eq!(bot.rank_options(&event, &galaxy), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn default_preferences_wrap_vote() {
+        let bot = NamedMember::new("renamed", Box::new(TestExplorer));
+        let prefs = bot.preferences(&make_event(3), &GalaxyState::new());
+        assert_eq!(prefs, vec![(0, "preferred")]);
+    }
+
+    #[test]
+    fn variety_bot_forgets_outside_window() {
+        let bot = VarietyBot::new(Box::new(TestExplorer), 2);
+        let event = make_event(3);
+        let galaxy = GalaxyState::new();
+
+        bot.observe_winner(&event, 0);
+        assert_eq!(bot.vote(&event, &galaxy), 1);
+        assert_eq!(bot.rank_options(&event, &galaxy), vec![1, 2, 0]);
+
+        bot.observe_winner(&event, 1);
+        bot.observe_winner(&event, 2);
+        assert_eq!(bot.recent_winners(), vec![1, 2]);
+        assert_eq!(bot.vote(&event, &galaxy), 0);
+        assert_eq!(bot.clone().recent_winners(), vec![1, 2]);
+    }
+
+    #[test]
+    fn variety_bot_asks_the_inner_bot_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        #[derive(Clone)]
+        struct CountingBot(Arc<AtomicUsize>);
+
+        impl GalacticCouncilMember for CountingBot {
+            fn name(&self) -> &'static str {
+                "counting-bot"
+            }
+
+            fn expertise(&self) -> &[(&'static str, f32)] {
+                &[]
+            }
+
+            fn vote(&self, _event: &Event, _galaxy: &GalaxyState) -> usize {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                0
+            }
+        }
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let bot = VarietyBot::new(Box::new(CountingBot(calls.clone())), 2);
+        let event = make_event(3);
+        bot.observe_winner(&event, 0);
+
+        assert_eq!(bot.vote(&event, &GalaxyState::new()), 1);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn galactic_as_classic_maps_first_last_and_middle() {
+        #[derive(Clone)]
+        struct PickBot(usize);
+
+        impl GalacticCouncilMember for PickBot {
+            fn name(&self) -> &'static str {
+                "pick-bot"
+            }
+
+            fn expertise(&self) -> &[(&'static str, f32)] {
+                &[]
+            }
+
+            fn vote(&self, _event: &Event, _galaxy: &GalaxyState) -> usize {
+                self.0
+            }
+        }
+
+        let ctx = Context {
+            round: 1,
+            previous_tally: None,
+            seed: 0,
+        };
+        let decide = |choice: usize| {
+            let mut bot = GalacticAsClassic::new(Box::new(PickBot(choice)));
+            assert_eq!(bot.vote(&ctx), Decision::Abstain);
+            bot.on_round_start(make_event(3), GalaxyState::new());
+            bot.vote(&ctx)
+        };
+        assert_eq!(decide(0), Decision::Approve);
+        assert_eq!(decide(1), Decision::Abstain);
+        assert_eq!(decide(2), Decision::Reject);
+        assert_eq!(
+            GalacticAsClassic::new(Box::new(PickBot(0))).name(),
+            "pick-bot"
+        );
+    }
+
+    #[test]
+    fn specialist_abstains_outside_expertise() {
+        let bot = SpecialistBot::new(Box::new(TestExplorer), 0.5);
+        let score = ScoreTracker::new();
+        let galaxy = GalaxyState::new();
+        let mut event = make_event(3);
+
+        event.relevant_expertise = vec![("diplomacy".to_string(), 0.8)];
+        assert_eq!(bot.depth(&event), 0.0);
+        assert_eq!(bot.vote_or_abstain(&event, &galaxy, &score), None);
+
+        event.relevant_expertise =
+            vec![("diplomacy".to_string(), 0.4), ("science".to_string(), 0.6)];
+        assert_eq!(bot.depth(&event), 0.9);
+        assert_eq!(bot.vote_or_abstain(&event, &galaxy, &score), Some(0));
+    }
 }
@@ -0,0 +1,210 @@
+//! Factions: groups of galactic bots whose votes are correlated.
+
+use crate::event::{Event, RngCore};
+use crate::explorer::GalacticCouncilMember;
+use crate::galaxy::GalaxyState;
+use crate::scoring::ScoreTracker;
+use crate::voting::{
+    apply_confidence, calculate_vote_weight_with_base, resolve_votes, Vote, BASE_WEIGHT,
+};
+
+/// A voting bloc within the council.
+///
+/// Members first vote on their own; the faction line is the plurality of
+/// those picks (lower index breaks ties). Each member then follows the line
+/// with probability `cohesion`, otherwise keeps their own pick. Members
+/// that decline ([`GalacticCouncilMember::will_vote`]) or abstain
+/// ([`GalacticCouncilMember::vote_or_abstain`]) cast no vote and have no
+/// say in the line.
+#[derive(Clone)]
+pub struct Faction {
+    /// Faction display name.
+    pub name: String,
+    /// Bots belonging to the faction.
+    pub members: Vec<Box<dyn GalacticCouncilMember>>,
+    /// How strongly members follow the faction line (0.0 to 1.0).
+    pub cohesion: f32,
+}
+
+impl Faction {
+    /// Create a faction; `cohesion` is clamped to 0.0..=1.0.
+    pub fn new(name: &str, members: Vec<Box<dyn GalacticCouncilMember>>, cohesion: f32) -> Self {
+        Self {
+            name: name.to_string(),
+            members,
+            cohesion: cohesion.clamp(0.0, 1.0),
+        }
+    }
+
+    /// The option the faction's members pick most often.
+    pub fn faction_line(&self, picks: &[usize], num_options: usize) -> usize {
+        let counts: Vec<Vote> = picks
+            .iter()
+            .map(|&chosen_option| Vote {
+                bot_name: self.name.clone(),
+                chosen_option,
+                weight: 1.0,
+            })
+            .collect();
+        resolve_votes(&counts, num_options)
+    }
+
+    /// Cast every voting member's vote, blended with the faction line.
+    pub fn cast_votes(
+        &self,
+        event: &Event,
+        galaxy: &GalaxyState,
+        score: &ScoreTracker,
+        rng: &mut dyn RngCore,
+    ) -> Vec<Vote> {
+        self.cast_votes_with_base(event, galaxy, score, rng, BASE_WEIGHT)
+    }
+
+    /// Like [`cast_votes`](Self::cast_votes), weighting votes from
+    /// `base_weight` instead of [`BASE_WEIGHT`].
+    pub fn cast_votes_with_base(
+        &self,
+        event: &Event,
+        galaxy: &GalaxyState,
+        score: &ScoreTracker,
+        rng: &mut dyn RngCore,
+        base_weight: f32,
+    ) -> Vec<Vote> {
+        self.cast_member_votes(event, galaxy, score, rng, base_weight)
+            .into_iter()
+            .map(|(_, vote)| vote)
+            .collect()
+    }
+
+    /// Like [`cast_votes_with_base`](Self::cast_votes_with_base), pairing
+    /// each vote with the member that cast it.
+    pub(crate) fn cast_member_votes(
+        &self,
+        event: &Event,
+        galaxy: &GalaxyState,
+        score: &ScoreTracker,
+        rng: &mut dyn RngCore,
+        base_weight: f32,
+    ) -> Vec<(&dyn GalacticCouncilMember, Vote)> {
+        let max_index = event.options.len().saturating_sub(1);
+        let picks: Vec<(&dyn GalacticCouncilMember, usize)> = self
+            .members
+            .iter()
+            .filter(|m| m.will_vote(event, galaxy))
+            .filter_map(|m| {
+                let own = m.vote_or_abstain(event, galaxy, score)?;
+                Some((m.as_ref(), own.min(max_index)))
+            })
+            .collect();
+        let own_picks: Vec<usize> = picks.iter().map(|&(_, own)| own).collect();
+        let line = self.faction_line(&own_picks, event.options.len());
+
+        picks
+            .into_iter()
+            .map(|(member, own)| {
+                let roll = rng.next_u32() as f64 / (u32::MAX as f64 + 1.0);
+                let chosen_option = if roll < self.cohesion as f64 {
+                    line
+                } else {
+                    own
+                };
+                let vote = Vote {
+                    bot_name: member.name().to_string(),
+                    chosen_option,
+                    weight: apply_confidence(
+                        calculate_vote_weight_with_base(member, event, base_weight),
+                        member.confidence(event, galaxy),
+                    ),
+                };
+                (member, vote)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{make_event, FixedBot};
+    use rand::SeedableRng;
+
+    fn faction(cohesion: f32) -> Faction {
+        let members: Vec<Box<dyn GalacticCouncilMember>> = [0, 2, 2, 1, 2, 0]
+            .into_iter()
+            .map(|choice| Box::new(FixedBot { choice }) as Box<dyn GalacticCouncilMember>)
+            .collect();
+        Faction::new("Hawks", members, cohesion)
+    }
+
+    #[test]
+    fn high_cohesion_faction_votes_unanimously() {
+        let faction = faction(1.0);
+        let event = make_event(3);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let votes = faction.cast_votes(&event, &GalaxyState::new(), &ScoreTracker::new(), &mut rng);
+
+        assert_eq!(votes.len(), 6);
+        assert!(votes.iter().all(|v| v.chosen_option == 2));
+    }
+
+    #[test]
+    fn zero_cohesion_keeps_own_picks() {
+        let faction = faction(0.0);
+        let event = make_event(3);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let votes = faction.cast_votes(&event, &GalaxyState::new(), &ScoreTracker::new(), &mut rng);
+
+        let picks: Vec<usize> = votes.iter().map(|v| v.chosen_option).collect();
+        assert_eq!(picks, vec![0, 2, 2, 1, 2, 0]);
+    }
+
+    /// Sits out every event.
+    #[derive(Clone)]
+    struct ShyBot;
+
+    impl GalacticCouncilMember for ShyBot {
+        fn name(&self) -> &'static str {
+            "shy-bot"
+        }
+
+        fn expertise(&self) -> &[(&'static str, f32)] {
+            &[]
+        }
+
+        fn vote(&self, _event: &Event, _galaxy: &GalaxyState) -> usize {
+            1
+        }
+
+        fn will_vote(&self, _event: &Event, _galaxy: &GalaxyState) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn members_that_decline_cast_no_vote_and_skip_the_line() {
+        let members: Vec<Box<dyn GalacticCouncilMember>> = vec![
+            Box::new(ShyBot),
+            Box::new(ShyBot),
+            Box::new(FixedBot { choice: 2 }),
+            Box::new(crate::explorer::SpecialistBot::new(
+                Box::new(FixedBot { choice: 1 }),
+                0.5,
+            )),
+        ];
+        let faction = Faction::new("Doves", members, 1.0);
+        let event = make_event(3);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let votes = faction.cast_votes(&event, &GalaxyState::new(), &ScoreTracker::new(), &mut rng);
+
+        // The two shy bots would have made option 1 the line.
+        assert_eq!(votes.len(), 1);
+        assert_eq!(votes[0].bot_name, "fixed-bot");
+        assert_eq!(votes[0].chosen_option, 2);
+    }
+
+    #[test]
+    fn cohesion_is_clamped() {
+        assert_eq!(faction(3.0).cohesion, 1.0);
+        assert_eq!(faction(-1.0).cohesion, 0.0);
+    }
+}
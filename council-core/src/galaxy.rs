@@ -1,9 +1,36 @@
 //! Galaxy state tracking for the exploration simulation.
 
-use std::collections::HashMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Upper bound for [`GalaxyState::morale`].
+pub const MAX_MORALE: i32 = 100;
+
+/// Morale of a fresh galaxy from [`GalaxyState::new`].
+pub const STARTING_MORALE: i32 = 50;
+
+/// Default cap on threat severity (see [`GalaxyState::max_severity`]).
+pub const DEFAULT_MAX_SEVERITY: u32 = 10;
+
+/// Military strength gained from each allied species (and lost when an
+/// alliance ends).
+pub const ALLY_STRENGTH: u32 = 2;
+
+/// Name of the sector every galaxy starts with; it can never be removed.
+pub const HOME_SECTOR: &str = "Home Sector";
+
+/// Resource kind spent and gained by supply events.
+pub const FUEL: &str = "fuel";
 
 /// The full state of the galaxy, modified by council decisions.
-#[derive(Debug, Clone, Default)]
+///
+/// Deserializing fills missing fields from [`GalaxyState::new`], so a
+/// scenario file only needs to list what differs from a fresh galaxy. The
+/// serde derives need the `serde` feature.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct GalaxyState {
     /// Current simulation round.
     pub round: u32,
@@ -17,6 +44,30 @@ pub struct GalaxyState {
     pub discoveries: Vec<Discovery>,
     /// Active threats facing the council.
     pub threats: Vec<Threat>,
+    /// Crew and population morale, from 0 to [`MAX_MORALE`].
+    pub morale: i32,
+    /// Fleet strength available to face threats; allies each add [`ALLY_STRENGTH`].
+    pub military_strength: u32,
+    /// Stockpiles by kind (e.g. [`FUEL`]), never below zero.
+    pub resources: HashMap<String, i32>,
+    /// Temporary buffs and debuffs, expired by `advance_round`.
+    pub modifiers: Vec<TimedModifier>,
+    /// Highest severity a threat can reach through `apply_changes`.
+    pub max_severity: u32,
+    /// Threats that will become active later, as `(rounds remaining, threat)`.
+    pub pending_threats: Vec<(u32, Threat)>,
+    /// Whether `apply_changes` appends to `change_log`.
+    pub record_changes: bool,
+    /// Every applied change tagged with the round it was applied in.
+    ///
+    /// Empty unless `record_changes` is set (see [`GalaxyState::with_change_log`]).
+    pub change_log: Vec<(u32, StateChange)>,
+    /// Whether `advance_round` appends to `relation_history`.
+    pub track_relations: bool,
+    /// Relation with each species at the start of every round.
+    ///
+    /// Empty unless `track_relations` is set (see [`GalaxyState::with_relation_history`]).
+    pub relation_history: HashMap<String, Vec<Relation>>,
 }
 
 impl GalaxyState {
@@ -25,25 +76,160 @@ impl GalaxyState {
         Self {
             round: 0,
             explored_sectors: vec![Sector {
-                name: "Home Sector".to_string(),
+                name: HOME_SECTOR.to_string(),
                 sector_type: SectorType::Habitable,
             }],
             known_species: Vec::new(),
             relations: HashMap::new(),
             discoveries: Vec::new(),
             threats: Vec::new(),
+            morale: STARTING_MORALE,
+            military_strength: 0,
+            resources: HashMap::new(),
+            modifiers: Vec::new(),
+            max_severity: DEFAULT_MAX_SEVERITY,
+            pending_threats: Vec::new(),
+            record_changes: false,
+            change_log: Vec::new(),
+            track_relations: false,
+            relation_history: HashMap::new(),
+        }
+    }
+
+    /// Copy of `threat` with severity capped at `max_severity`.
+    fn capped(&self, threat: &Threat) -> Threat {
+        Threat {
+            severity: threat.severity.min(self.max_severity),
+            ..threat.clone()
+        }
+    }
+
+    /// Create a new galaxy state that records every applied change.
+    ///
+    /// The log allows replaying or auditing a run from scratch.
+    pub fn with_change_log() -> Self {
+        Self {
+            record_changes: true,
+            ..Self::new()
+        }
+    }
+
+    /// Create a new galaxy state that records relation history each round.
+    pub fn with_relation_history() -> Self {
+        Self {
+            track_relations: true,
+            ..Self::new()
+        }
+    }
+
+    /// Create a new galaxy state that starts at `round`, for mid-game
+    /// scenarios; the next played round is `round + 1`.
+    pub fn with_round(round: u32) -> Self {
+        Self {
+            round,
+            ..Self::new()
+        }
+    }
+
+    /// Move to the next round, activating any scheduled threats whose
+    /// countdown reaches zero.
+    ///
+    /// When `track_relations` is set, the current relations are first
+    /// appended to `relation_history`.
+    pub fn advance_round(&mut self) {
+        if self.track_relations {
+            for (species, relation) in &self.relations {
+                self.relation_history
+                    .entry(species.clone())
+                    .or_default()
+                    .push(*relation);
+            }
+        }
+        self.round += 1;
+        let mut still_pending = Vec::new();
+        for (remaining, threat) in self.pending_threats.drain(..) {
+            if remaining <= 1 {
+                if !self.threats.iter().any(|t| t.name == threat.name) {
+                    self.threats.push(threat);
+                }
+            } else {
+                still_pending.push((remaining - 1, threat));
+            }
+        }
+        self.pending_threats = still_pending;
+        for modifier in &mut self.modifiers {
+            modifier.rounds_remaining = modifier.rounds_remaining.saturating_sub(1);
+        }
+        self.modifiers.retain(|m| m.rounds_remaining > 0);
+    }
+
+    /// Apply changes like [`apply_changes`](Self::apply_changes), tagging
+    /// every discovery they add with the round and event that produced it.
+    ///
+    /// Returns the score earned by the changes, as [`apply_changes_scored`](Self::apply_changes_scored).
+    pub fn apply_changes_tagged(
+        &mut self,
+        changes: &[StateChange],
+        round: u32,
+        source: &str,
+    ) -> i32 {
+        let before = self.discoveries.len();
+        let score = self.apply_changes_scored(changes);
+        for discovery in &mut self.discoveries[before..] {
+            discovery.source_round = Some(round);
+            discovery.source_event = Some(source.to_string());
+        }
+        score
+    }
+
+    /// Apply changes like [`apply_changes`](Self::apply_changes), returning
+    /// the score they earn: each [`StateChange::ScoreScaledBy`] is valued by
+    /// [`scaled_score`](Self::scaled_score) against the galaxy as it stands
+    /// when that change is reached.
+    pub fn apply_changes_scored(&mut self, changes: &[StateChange]) -> i32 {
+        let mut score = 0;
+        for change in changes {
+            self.apply_changes(std::slice::from_ref(change));
+            score += self.scaled_score(change);
+        }
+        score
+    }
+
+    /// Score a [`StateChange::ScoreScaledBy`] would earn right now (0 for
+    /// every other change).
+    pub fn scaled_score(&self, change: &StateChange) -> i32 {
+        match change {
+            StateChange::ScoreScaledBy {
+                per_ally,
+                per_sector,
+            } => {
+                self.allied_count() as i32 * per_ally
+                    + self.explored_sectors.len() as i32 * per_sector
+            }
+            _ => 0,
         }
     }
 
     /// Apply a list of state changes from an event outcome.
     pub fn apply_changes(&mut self, changes: &[StateChange]) {
         for change in changes {
+            if self.record_changes {
+                self.change_log.push((self.round, change.clone()));
+            }
             match change {
                 StateChange::AddSector(sector) => {
                     if !self.explored_sectors.iter().any(|s| s.name == sector.name) {
                         self.explored_sectors.push(sector.clone());
                     }
                 }
+                StateChange::SetSectorType {
+                    sector,
+                    sector_type,
+                } => {
+                    if let Some(s) = self.explored_sectors.iter_mut().find(|s| &s.name == sector) {
+                        s.sector_type = *sector_type;
+                    }
+                }
                 StateChange::AddSpecies(species) => {
                     if !self.known_species.iter().any(|s| s.name == species.name) {
                         self.known_species.push(species.clone());
@@ -51,15 +237,95 @@ impl GalaxyState {
                             .insert(species.name.clone(), Relation::Unknown);
                     }
                 }
+                StateChange::RemoveSpecies(name) => self.remove_species(name),
                 StateChange::SetRelation { species, relation } => {
-                    self.relations.insert(species.clone(), *relation);
+                    self.set_relation(species, *relation);
+                }
+                StateChange::ImproveRelationIfAbove { species, floor } => {
+                    if let Some(&current) = self.relations.get(species) {
+                        if current.is_at_least(*floor) {
+                            self.set_relation(species, improve_relation(current));
+                        }
+                    }
+                }
+                StateChange::MergeSpecies { absorbed, into } => {
+                    self.merge_species(absorbed, into);
+                }
+                StateChange::AdjustStrength(delta) => self.adjust_strength(*delta),
+                // Scored by `apply_changes_scored`; the galaxy itself is unchanged.
+                StateChange::ScoreScaledBy { .. } => {}
+                StateChange::AddModifier(modifier) => {
+                    if modifier.rounds_remaining > 0 {
+                        self.modifiers.push(modifier.clone());
+                    }
+                }
+                StateChange::AddResource { kind, amount } => {
+                    let stock = self.resources.entry(kind.clone()).or_insert(0);
+                    *stock = stock.saturating_add(*amount).max(0);
+                }
+                StateChange::ShiftRelationsByTrait { trait_name, delta } => {
+                    let matching: Vec<String> = self
+                        .known_species
+                        .iter()
+                        .filter(|s| s.traits.iter().any(|t| t == trait_name))
+                        .map(|s| s.name.clone())
+                        .collect();
+                    for species in matching {
+                        self.adjust_relation(&species, *delta);
+                    }
+                }
+                StateChange::AdjustRelation { species, steps } => {
+                    self.adjust_relation(species, *steps);
                 }
                 StateChange::AddDiscovery(discovery) => {
                     self.discoveries.push(discovery.clone());
                 }
+                StateChange::AddDiscoveryUnique(discovery) => {
+                    if !self.discoveries.iter().any(|d| d.name == discovery.name) {
+                        self.discoveries.push(discovery.clone());
+                    }
+                }
+                StateChange::AddDiscoveryIfAllied { species, discovery } => {
+                    let allied = self.relations.get(species) == Some(&Relation::Allied);
+                    if allied && !self.discoveries.iter().any(|d| d.name == discovery.name) {
+                        self.discoveries.push(discovery.clone());
+                    }
+                }
+                StateChange::AddDiscoveries(discoveries) => {
+                    self.discoveries.extend(discoveries.iter().cloned());
+                }
+                StateChange::AddDiscoveriesUnique(discoveries) => {
+                    for discovery in discoveries {
+                        if !self.discoveries.iter().any(|d| d.name == discovery.name) {
+                            self.discoveries.push(discovery.clone());
+                        }
+                    }
+                }
                 StateChange::AddThreat(threat) => {
                     if !self.threats.iter().any(|t| t.name == threat.name) {
-                        self.threats.push(threat.clone());
+                        self.threats.push(self.capped(threat));
+                    }
+                }
+                StateChange::AddThreatIfExists { trigger, threat } => {
+                    let triggered = self.threats.iter().any(|t| &t.name == trigger);
+                    if triggered && !self.threats.iter().any(|t| t.name == threat.name) {
+                        self.threats.push(self.capped(threat));
+                    }
+                }
+                StateChange::ScheduleThreat { threat, in_rounds } => {
+                    let threat = self.capped(threat);
+                    if *in_rounds == 0 {
+                        if !self.threats.iter().any(|t| t.name == threat.name) {
+                            self.threats.push(threat);
+                        }
+                    } else {
+                        self.pending_threats.push((*in_rounds, threat));
+                    }
+                }
+                StateChange::AdjustMorale(delta) => self.adjust_morale(*delta),
+                StateChange::RemoveSector(name) => {
+                    if name != HOME_SECTOR && self.explored_sectors.len() > 1 {
+                        self.explored_sectors.retain(|s| &s.name != name);
                     }
                 }
                 StateChange::RemoveThreat(name) => {
@@ -67,7 +333,9 @@ impl GalaxyState {
                 }
                 StateChange::ModifyThreatSeverity { name, delta } => {
                     if let Some(threat) = self.threats.iter_mut().find(|t| &t.name == name) {
-                        threat.severity = (threat.severity as i32 + delta).max(0) as u32;
+                        threat.severity = (threat.severity as i64 + *delta as i64)
+                            .clamp(0, self.max_severity as i64)
+                            as u32;
                         if threat.severity == 0 {
                             self.threats.retain(|t| &t.name != name);
                         }
@@ -78,15 +346,171 @@ impl GalaxyState {
     }
 
     /// Process ongoing threats, returning score penalty.
+    ///
+    /// Each threat also saps morale by its severity.
     pub fn process_threats(&mut self) -> i32 {
+        self.process_threats_with_escalation(0)
+    }
+
+    /// Like [`process_threats`](Self::process_threats), but a threat's
+    /// severity also rises by 1 (up to `max_severity`) each time it has
+    /// been active another `escalate_every` rounds, before the penalty is
+    /// counted. `0` never escalates.
+    pub fn process_threats_with_escalation(&mut self, escalate_every: u32) -> i32 {
         let mut penalty = 0i32;
+        let mut pressure = 0i32;
         for threat in &mut self.threats {
             threat.rounds_active += 1;
+            if escalate_every > 0 && threat.rounds_active.is_multiple_of(escalate_every) {
+                threat.severity = threat.severity.saturating_add(1).min(self.max_severity);
+            }
             penalty -= (threat.severity * 3) as i32;
+            pressure += threat.severity as i32;
         }
+        if penalty < 0 {
+            penalty = (penalty + self.modifier_total(ModifierKind::ThreatPenalty)).min(0);
+        }
+        self.adjust_morale(-pressure);
         penalty
     }
 
+    /// Forget a species and its relation, returning an ally's strength.
+    fn remove_species(&mut self, name: &str) {
+        let before = self.known_species.len();
+        self.known_species.retain(|s| s.name != name);
+        if self.known_species.len() == before {
+            return;
+        }
+        self.set_relation(name, Relation::Unknown);
+        self.relations.remove(name);
+        self.relation_history.remove(name);
+    }
+
+    /// Fold `absorbed` into `into`; see [`StateChange::MergeSpecies`].
+    fn merge_species(&mut self, absorbed: &str, into: &str) {
+        if absorbed == into {
+            return;
+        }
+        let Some(index) = self.known_species.iter().position(|s| s.name == absorbed) else {
+            return;
+        };
+        if !self.known_species.iter().any(|s| s.name == into) {
+            return;
+        }
+        let gone = self.known_species[index].clone();
+        let relation_of = |galaxy: &Self, name: &str| {
+            galaxy
+                .relations
+                .get(name)
+                .copied()
+                .unwrap_or(Relation::Unknown)
+        };
+        let absorbed_relation = relation_of(self, absorbed);
+        let into_relation = relation_of(self, into);
+        // Drop the absorbed species first so a lost ally gives back its
+        // strength before the survivor's relation is set.
+        self.remove_species(absorbed);
+
+        let survivor = self
+            .known_species
+            .iter_mut()
+            .find(|s| s.name == into)
+            .expect("checked above");
+        for trait_name in gone.traits {
+            if !survivor.traits.contains(&trait_name) {
+                survivor.traits.push(trait_name);
+            }
+        }
+        let best = if absorbed_relation.is_at_least(into_relation) {
+            absorbed_relation
+        } else {
+            into_relation
+        };
+        self.set_relation(into, best);
+    }
+
+    /// Step a relation with [`step_relation`], starting from `Unknown` if
+    /// there is none yet.
+    fn adjust_relation(&mut self, species: &str, steps: i32) {
+        let current = self
+            .relations
+            .get(species)
+            .copied()
+            .unwrap_or(Relation::Unknown);
+        self.set_relation(species, step_relation(current, steps));
+    }
+
+    /// Set a relation, gaining or losing [`ALLY_STRENGTH`] when an alliance
+    /// forms or ends.
+    fn set_relation(&mut self, species: &str, relation: Relation) {
+        let previous = self.relations.insert(species.to_string(), relation);
+        let was_allied = previous == Some(Relation::Allied);
+        let is_allied = relation == Relation::Allied;
+        if is_allied && !was_allied {
+            self.adjust_strength(ALLY_STRENGTH as i32);
+        } else if was_allied && !is_allied {
+            self.adjust_strength(-(ALLY_STRENGTH as i32));
+        }
+    }
+
+    /// Amount of a resource in stock (0 if never gained).
+    pub fn resource(&self, kind: &str) -> i32 {
+        self.resources.get(kind).copied().unwrap_or(0)
+    }
+
+    /// Military strength including active [`ModifierKind::Strength`]
+    /// modifiers (never below 0).
+    pub fn effective_strength(&self) -> u32 {
+        self.military_strength
+            .saturating_add_signed(self.modifier_total(ModifierKind::Strength))
+    }
+
+    /// Summed magnitude of the active modifiers of `kind`.
+    fn modifier_total(&self, kind: ModifierKind) -> i32 {
+        self.modifiers
+            .iter()
+            .filter(|m| m.kind == kind)
+            .map(|m| m.magnitude)
+            .sum()
+    }
+
+    /// Change military strength by `delta`, saturating at 0.
+    pub fn adjust_strength(&mut self, delta: i32) {
+        self.military_strength = self.military_strength.saturating_add_signed(delta);
+    }
+
+    /// Change morale by `delta`, clamped to `0..=MAX_MORALE`.
+    pub fn adjust_morale(&mut self, delta: i32) {
+        self.morale = self.morale.saturating_add(delta).clamp(0, MAX_MORALE);
+    }
+
+    /// Direction a species' relation has moved over the last
+    /// [`TREND_WINDOW`] recorded rounds, including the current relation.
+    ///
+    /// `Stable` when there is no history for the species.
+    pub fn relation_trend(&self, species: &str) -> Trend {
+        let mut recent: Vec<Relation> = self
+            .relation_history
+            .get(species)
+            .map(|h| {
+                h.iter()
+                    .rev()
+                    .take(TREND_WINDOW - 1)
+                    .rev()
+                    .copied()
+                    .collect()
+            })
+            .unwrap_or_default();
+        if let Some(current) = self.relations.get(species) {
+            recent.push(*current);
+        }
+        match (recent.first(), recent.last()) {
+            (Some(first), Some(last)) if last.standing() > first.standing() => Trend::Improving,
+            (Some(first), Some(last)) if last.standing() < first.standing() => Trend::Declining,
+            _ => Trend::Stable,
+        }
+    }
+
     /// Count allied species.
     pub fn allied_count(&self) -> usize {
         self.relations
@@ -102,10 +526,114 @@ impl GalaxyState {
             .filter(|r| matches!(r, Relation::Hostile))
             .count()
     }
+
+    /// Names of the species at `relation`, sorted.
+    pub fn species_with_relation(&self, relation: Relation) -> Vec<&str> {
+        let mut names: Vec<&str> = self
+            .relations
+            .iter()
+            .filter(|(_, r)| **r == relation)
+            .map(|(name, _)| name.as_str())
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Number of species at each relation tier; every tier is present,
+    /// including those with no species.
+    pub fn relation_counts(&self) -> HashMap<Relation, usize> {
+        let mut counts: HashMap<Relation, usize> = Relation::ALL.iter().map(|&r| (r, 0)).collect();
+        for relation in self.relations.values() {
+            *counts.entry(*relation).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Rough worth of the galaxy to the council, for lookahead bots.
+    ///
+    /// Uses the end-game bonuses (allies +10, hostiles -5, discoveries +5
+    /// each), adds +2 per explored sector and subtracts the per-round threat
+    /// penalty (3 per point of active severity).
+    pub fn heuristic_value(&self) -> i32 {
+        let threat_pressure: u32 = self.threats.iter().map(|t| t.severity).sum();
+        self.allied_count() as i32 * 10 - self.hostile_count() as i32 * 5
+            + self.discoveries.len() as i32 * 5
+            + self.explored_sectors.len() as i32 * 2
+            - threat_pressure as i32 * 3
+    }
+
+    /// Compact multi-line status report for CLI output and LLM prompts.
+    ///
+    /// One `LABEL: ...` line each for the round, sectors by type, species,
+    /// relation tiers, discoveries by category and active threats; empty
+    /// sections read `(none)`.
+    pub fn summary(&self) -> String {
+        fn breakdown(counts: BTreeMap<String, usize>) -> String {
+            let total: usize = counts.values().sum();
+            if counts.is_empty() {
+                return total.to_string();
+            }
+            let parts = counts
+                .iter()
+                .map(|(label, count)| format!("{} {}", label, count))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{} ({})", total, parts)
+        }
+        fn or_none(s: String) -> String {
+            if s.is_empty() {
+                "(none)".to_string()
+            } else {
+                s
+            }
+        }
+
+        let mut sectors = BTreeMap::new();
+        for sector in &self.explored_sectors {
+            *sectors
+                .entry(format!("{:?}", sector.sector_type))
+                .or_insert(0) += 1;
+        }
+        let mut categories = BTreeMap::new();
+        for discovery in &self.discoveries {
+            *categories.entry(discovery.category.clone()).or_insert(0) += 1;
+        }
+        let relations = Relation::ALL
+            .iter()
+            .rev()
+            .map(|&tier| (tier, self.species_with_relation(tier)))
+            .filter(|(_, names)| !names.is_empty())
+            .map(|(tier, names)| format!("{:?} {} ({})", tier, names.len(), names.join(", ")))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let threats = self
+            .threats
+            .iter()
+            .map(|t| format!("{}(sev={}, rounds={})", t.name, t.severity, t.rounds_active))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!(
+            "ROUND: {}\nSECTORS: {}\nSPECIES: {}\nRELATIONS: {}\nDISCOVERIES: {}\nTHREATS: {}\n",
+            self.round,
+            breakdown(sectors),
+            self.known_species.len(),
+            or_none(relations),
+            breakdown(categories),
+            or_none(threats),
+        )
+    }
+}
+
+impl Default for GalaxyState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// A region of space that has been explored.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Sector {
     pub name: String,
     pub sector_type: SectorType,
@@ -113,6 +641,7 @@ pub struct Sector {
 
 /// Types of space sectors.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SectorType {
     Habitable,
     AsteroidField,
@@ -123,13 +652,37 @@ pub enum SectorType {
 
 /// An alien species encountered by the council.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Species {
     pub name: String,
     pub traits: Vec<String>,
 }
 
-/// Diplomatic relation with a species.
+/// What a [`TimedModifier`] affects.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ModifierKind {
+    /// Added to [`GalaxyState::effective_strength`].
+    Strength,
+    /// Added to each round's threat penalty while threats are active
+    /// (positive softens it, but never turns it into a bonus).
+    ThreatPenalty,
+}
+
+/// A temporary effect, e.g. "emergency mobilization: +3 strength for 2 rounds".
+///
+/// Active from the round it is added for `rounds_remaining` rounds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TimedModifier {
+    pub kind: ModifierKind,
+    pub magnitude: i32,
+    pub rounds_remaining: u32,
+}
+
+/// Diplomatic relation with a species.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Relation {
     Unknown,
     Hostile,
@@ -139,15 +692,106 @@ pub enum Relation {
     Allied,
 }
 
+/// Number of rounds [`GalaxyState::relation_trend`] looks back over.
+pub const TREND_WINDOW: usize = 3;
+
+/// Direction a diplomatic relation is moving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Trend {
+    Improving,
+    Stable,
+    Declining,
+}
+
+impl Relation {
+    /// Every tier, from `Unknown` then `Hostile` up to `Allied`.
+    pub const ALL: [Relation; 6] = [
+        Relation::Unknown,
+        Relation::Hostile,
+        Relation::Wary,
+        Relation::Neutral,
+        Relation::Friendly,
+        Relation::Allied,
+    ];
+
+    /// Position on the diplomatic ladder (Hostile lowest, Allied highest).
+    ///
+    /// `Unknown` sits alongside `Wary`, matching how the step helpers treat it.
+    fn standing(self) -> u8 {
+        match self {
+            Relation::Hostile => 0,
+            Relation::Unknown | Relation::Wary => 1,
+            Relation::Neutral => 2,
+            Relation::Friendly => 3,
+            Relation::Allied => 4,
+        }
+    }
+
+    /// Whether this relation is at or above `floor` on the diplomatic ladder.
+    pub fn is_at_least(self, floor: Relation) -> bool {
+        self.standing() >= floor.standing()
+    }
+}
+
+/// Improve a relation by one step (Hostile -> Wary -> Neutral -> Friendly -> Allied;
+/// Unknown improves to Neutral).
+pub fn improve_relation(current: Relation) -> Relation {
+    match current {
+        Relation::Hostile => Relation::Wary,
+        Relation::Unknown | Relation::Wary => Relation::Neutral,
+        Relation::Neutral => Relation::Friendly,
+        Relation::Friendly | Relation::Allied => Relation::Allied,
+    }
+}
+
+/// Degrade a relation by one step (Allied -> Friendly -> Neutral -> Wary -> Hostile).
+pub fn degrade_relation(current: Relation) -> Relation {
+    match current {
+        Relation::Allied => Relation::Friendly,
+        Relation::Friendly => Relation::Neutral,
+        Relation::Neutral => Relation::Wary,
+        Relation::Wary | Relation::Unknown => Relation::Hostile,
+        Relation::Hostile => Relation::Hostile,
+    }
+}
+
+/// Move a relation `steps` steps up (positive) or down (negative) the
+/// ladder, stopping at `Allied` and `Hostile`.
+pub fn step_relation(current: Relation, steps: i32) -> Relation {
+    let step = if steps > 0 {
+        improve_relation
+    } else {
+        degrade_relation
+    };
+    // No relation is more than a ladder's length from either end.
+    let steps = steps.unsigned_abs().min(Relation::ALL.len() as u32);
+    (0..steps).fold(current, |relation, _| step(relation))
+}
+
 /// A technology or artifact discovered.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Discovery {
     pub name: String,
     pub category: String,
+    /// Round whose outcome produced this discovery, if known.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub source_round: Option<u32>,
+    /// Name of the event template that produced this discovery, if known.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub source_event: Option<String>,
 }
 
 /// An active threat facing the council.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Threat {
     pub name: String,
     pub severity: u32,
@@ -155,15 +799,113 @@ pub struct Threat {
 }
 
 /// Changes that can be applied to galaxy state.
-#[derive(Debug, Clone)]
+///
+/// Idempotency contract: applying the same batch twice leaves the galaxy as
+/// if it were applied once for `AddSector`, `AddSpecies`, `AddThreat`,
+/// `AddThreatIfExists`, `AddDiscoveryUnique`, `AddDiscoveryIfAllied` and
+/// `AddDiscoveriesUnique` (deduplicated by name), `SetSectorType`,
+/// `SetRelation`, `RemoveSector`, `RemoveSpecies`, `MergeSpecies` and
+/// `RemoveThreat`. `AddDiscovery`, `AddDiscoveries`, `ScheduleThreat` and
+/// `AddModifier` are additive, and the relative changes
+/// (`ImproveRelationIfAbove`, `AdjustRelation`, `ShiftRelationsByTrait`,
+/// `ModifyThreatSeverity`, `AdjustMorale`, `AdjustStrength`, `AddResource`)
+/// apply each time. `ScoreScaledBy` never changes the galaxy.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum StateChange {
     AddSector(Sector),
+    /// Change the type of an already explored sector (no-op if absent).
+    SetSectorType {
+        sector: String,
+        sector_type: SectorType,
+    },
+    /// Lose an explored sector. No-op for [`HOME_SECTOR`] or the last
+    /// remaining sector, so templates can rely on at least one sector.
+    RemoveSector(String),
     AddSpecies(Species),
-    SetRelation { species: String, relation: Relation },
+    /// Forget a species (e.g. extinction), dropping its relation. No-op if
+    /// the species is unknown.
+    RemoveSpecies(String),
+    SetRelation {
+        species: String,
+        relation: Relation,
+    },
+    /// Move a relation `steps` steps up (positive) or down (negative) the
+    /// ladder relative to its value when applied, clamped at `Hostile` and
+    /// `Allied`. A species without a relation starts from `Unknown`.
+    AdjustRelation {
+        species: String,
+        steps: i32,
+    },
+    /// Improve a relation by one step, but only if it is already at or above `floor`.
+    ImproveRelationIfAbove {
+        species: String,
+        floor: Relation,
+    },
     AddDiscovery(Discovery),
+    /// Add a discovery unless one with the same name is already known.
+    AddDiscoveryUnique(Discovery),
+    /// Add a discovery shared by an ally: only while `species` is
+    /// [`Relation::Allied`], and unless one with the same name is known.
+    AddDiscoveryIfAllied {
+        species: String,
+        discovery: Discovery,
+    },
+    /// Add several discoveries at once.
+    AddDiscoveries(Vec<Discovery>),
+    /// Add several discoveries, skipping names already known.
+    AddDiscoveriesUnique(Vec<Discovery>),
     AddThreat(Threat),
+    /// Add `threat` only while a threat named `trigger` is active, for
+    /// escalation chains (e.g. "Space Pirates" into "Pirate Armada").
+    AddThreatIfExists {
+        trigger: String,
+        threat: Threat,
+    },
+    /// Activate a threat `in_rounds` rounds from now (see
+    /// [`GalaxyState::advance_round`]); `0` activates it immediately.
+    ScheduleThreat {
+        threat: Threat,
+        in_rounds: u32,
+    },
     RemoveThreat(String),
-    ModifyThreatSeverity { name: String, delta: i32 },
+    ModifyThreatSeverity {
+        name: String,
+        delta: i32,
+    },
+    /// Raise or lower morale, clamped to `0..=MAX_MORALE`.
+    AdjustMorale(i32),
+    /// Raise or lower military strength (never below 0).
+    AdjustStrength(i32),
+    /// Start a temporary modifier (ignored if `rounds_remaining` is 0).
+    AddModifier(TimedModifier),
+    /// Earn score scaled by the galaxy when applied: `per_ally` for each
+    /// allied species plus `per_sector` for each explored sector (see
+    /// [`GalaxyState::apply_changes_scored`]). Leaves the galaxy unchanged.
+    ScoreScaledBy {
+        per_ally: i32,
+        per_sector: i32,
+    },
+    /// Add `amount` of a resource, or spend it when negative; the stock
+    /// never drops below 0.
+    AddResource {
+        kind: String,
+        amount: i32,
+    },
+    /// Two species unite: `absorbed` leaves the map, its traits are added to
+    /// `into`, and `into` keeps the better of the two relations. No-op if
+    /// either species is unknown or they are the same.
+    MergeSpecies {
+        absorbed: String,
+        into: String,
+    },
+    /// Move the relation of every known species with `trait_name` among its
+    /// traits `delta` steps up (positive) or down (negative) the ladder.
+    /// Species without a relation start from `Unknown`.
+    ShiftRelationsByTrait {
+        trait_name: String,
+        delta: i32,
+    },
 }
 
 #[cfg(test)]
@@ -177,6 +919,502 @@ mod tests {
         assert_eq!(galaxy.explored_sectors[0].name, "Home Sector");
     }
 
+    #[test]
+    fn with_round_sets_starting_round() {
+        let mut galaxy = GalaxyState::with_round(12);
+        assert_eq!(galaxy.round, 12);
+        assert_eq!(galaxy.explored_sectors.len(), 1);
+        galaxy.advance_round();
+        assert_eq!(galaxy.round, 13);
+    }
+
+    #[test]
+    fn adjust_relation_steps_and_clamps() {
+        let mut galaxy = GalaxyState::new();
+        galaxy
+            .relations
+            .insert("Velari".to_string(), Relation::Neutral);
+        galaxy
+            .relations
+            .insert("Krath".to_string(), Relation::Friendly);
+        galaxy.apply_changes(&[
+            StateChange::AdjustRelation {
+                species: "Velari".to_string(),
+                steps: 2,
+            },
+            StateChange::AdjustRelation {
+                species: "Krath".to_string(),
+                steps: -3,
+            },
+        ]);
+        assert_eq!(galaxy.relations["Velari"], Relation::Allied);
+        assert_eq!(galaxy.relations["Krath"], Relation::Hostile);
+        assert_eq!(galaxy.military_strength, ALLY_STRENGTH);
+
+        assert_eq!(step_relation(Relation::Friendly, 5), Relation::Allied);
+        assert_eq!(step_relation(Relation::Wary, -4), Relation::Hostile);
+        assert_eq!(step_relation(Relation::Unknown, 0), Relation::Unknown);
+        assert_eq!(step_relation(Relation::Hostile, i32::MAX), Relation::Allied);
+        assert_eq!(step_relation(Relation::Allied, i32::MIN), Relation::Hostile);
+        assert_eq!(
+            step_relation(Relation::Unknown, i32::MIN),
+            Relation::Hostile
+        );
+    }
+
+    #[test]
+    fn idle_threats_escalate_every_n_rounds() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.threats.push(Threat {
+            name: "Void Swarm".to_string(),
+            severity: 2,
+            rounds_active: 0,
+        });
+
+        let penalties: Vec<i32> = (0..6)
+            .map(|_| galaxy.process_threats_with_escalation(3))
+            .collect();
+        // Severity 2 for rounds 1-2, 3 from round 3, 4 from round 6.
+        assert_eq!(penalties, vec![-6, -6, -9, -9, -9, -12]);
+        assert_eq!(galaxy.threats[0].severity, 4);
+
+        galaxy.max_severity = 4;
+        for _ in 0..3 {
+            galaxy.process_threats_with_escalation(3);
+        }
+        assert_eq!(galaxy.threats[0].severity, 4);
+        assert_eq!(galaxy.process_threats(), -12);
+    }
+
+    #[test]
+    fn escalating_a_maxed_out_threat_caps_instead_of_overflowing() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.threats.push(Threat {
+            name: "Void Swarm".to_string(),
+            severity: u32::MAX,
+            rounds_active: 0,
+        });
+
+        galaxy.process_threats_with_escalation(1);
+        assert_eq!(galaxy.threats[0].severity, galaxy.max_severity);
+    }
+
+    #[test]
+    fn relation_queries_group_species_by_tier() {
+        let mut galaxy = GalaxyState::new();
+        for (name, relation) in [
+            ("Krath", Relation::Hostile),
+            ("Velari", Relation::Allied),
+            ("Oonai", Relation::Allied),
+            ("Zorblax", Relation::Hostile),
+            ("Thessi", Relation::Neutral),
+        ] {
+            galaxy.relations.insert(name.to_string(), relation);
+        }
+
+        assert_eq!(
+            galaxy.species_with_relation(Relation::Hostile),
+            vec!["Krath", "Zorblax"]
+        );
+        assert_eq!(
+            galaxy.species_with_relation(Relation::Allied),
+            vec!["Oonai", "Velari"]
+        );
+        assert!(galaxy.species_with_relation(Relation::Wary).is_empty());
+
+        let counts = galaxy.relation_counts();
+        assert_eq!(counts.len(), Relation::ALL.len());
+        assert_eq!(counts[&Relation::Hostile], 2);
+        assert_eq!(counts[&Relation::Allied], 2);
+        assert_eq!(counts[&Relation::Neutral], 1);
+        assert_eq!(counts[&Relation::Wary], 0);
+        assert_eq!(counts[&Relation::Friendly], 0);
+        assert_eq!(counts[&Relation::Unknown], 0);
+    }
+
+    #[test]
+    fn strength_buff_lasts_exactly_its_rounds() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.military_strength = 4;
+        galaxy.advance_round();
+        galaxy.apply_changes(&[StateChange::AddModifier(TimedModifier {
+            kind: ModifierKind::Strength,
+            magnitude: 3,
+            rounds_remaining: 2,
+        })]);
+
+        assert_eq!(galaxy.effective_strength(), 7);
+        galaxy.advance_round();
+        assert_eq!(galaxy.effective_strength(), 7);
+        galaxy.advance_round();
+        assert_eq!(galaxy.effective_strength(), 4);
+        assert!(galaxy.modifiers.is_empty());
+        assert_eq!(galaxy.military_strength, 4);
+    }
+
+    #[test]
+    fn threat_penalty_modifier_softens_but_never_rewards() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.threats.push(Threat {
+            name: "Void Swarm".to_string(),
+            severity: 2,
+            rounds_active: 0,
+        });
+        let shield = |magnitude| {
+            StateChange::AddModifier(TimedModifier {
+                kind: ModifierKind::ThreatPenalty,
+                magnitude,
+                rounds_remaining: 1,
+            })
+        };
+        galaxy.apply_changes(&[shield(4)]);
+        assert_eq!(galaxy.process_threats(), -2);
+        galaxy.apply_changes(&[shield(10)]);
+        assert_eq!(galaxy.process_threats(), 0);
+    }
+
+    #[test]
+    fn resources_accumulate_and_floor_at_zero() {
+        let mut galaxy = GalaxyState::new();
+        assert_eq!(galaxy.resource(FUEL), 0);
+        let fuel = |amount| StateChange::AddResource {
+            kind: FUEL.to_string(),
+            amount,
+        };
+
+        galaxy.apply_changes(&[fuel(5), fuel(3)]);
+        assert_eq!(galaxy.resource(FUEL), 8);
+        galaxy.apply_changes(&[fuel(-12)]);
+        assert_eq!(galaxy.resource(FUEL), 0);
+        galaxy.apply_changes(&[fuel(-1), fuel(2)]);
+        assert_eq!(galaxy.resource(FUEL), 2);
+        assert_eq!(galaxy.resource("ore"), 0);
+
+        galaxy.apply_changes(&[fuel(i32::MAX), fuel(i32::MAX)]);
+        assert_eq!(galaxy.resource(FUEL), i32::MAX);
+        galaxy.apply_changes(&[fuel(i32::MIN)]);
+        assert_eq!(galaxy.resource(FUEL), 0);
+    }
+
+    #[test]
+    fn shift_relations_by_trait_targets_matching_species() {
+        let mut galaxy = GalaxyState::new();
+        for (name, trait_name) in [("Velari", "peaceful"), ("Krath", "aggressive")] {
+            galaxy.known_species.push(Species {
+                name: name.to_string(),
+                traits: vec![trait_name.to_string()],
+            });
+            galaxy.relations.insert(name.to_string(), Relation::Neutral);
+        }
+
+        galaxy.apply_changes(&[StateChange::ShiftRelationsByTrait {
+            trait_name: "peaceful".to_string(),
+            delta: 2,
+        }]);
+        assert_eq!(galaxy.relations["Velari"], Relation::Allied);
+        assert_eq!(galaxy.relations["Krath"], Relation::Neutral);
+
+        galaxy.apply_changes(&[StateChange::ShiftRelationsByTrait {
+            trait_name: "aggressive".to_string(),
+            delta: -1,
+        }]);
+        assert_eq!(galaxy.relations["Krath"], Relation::Wary);
+        assert_eq!(galaxy.relations["Velari"], Relation::Allied);
+    }
+
+    #[test]
+    fn remove_species_drops_relation_and_is_idempotent() {
+        let mut galaxy = GalaxyState::with_relation_history();
+        galaxy.apply_changes(&[
+            StateChange::AddSpecies(Species {
+                name: "Velari".to_string(),
+                traits: vec![],
+            }),
+            StateChange::SetRelation {
+                species: "Velari".to_string(),
+                relation: Relation::Allied,
+            },
+        ]);
+        galaxy.advance_round();
+        assert_eq!(galaxy.military_strength, ALLY_STRENGTH);
+
+        let extinct = StateChange::RemoveSpecies("Velari".to_string());
+        galaxy.apply_changes(std::slice::from_ref(&extinct));
+        assert!(galaxy.known_species.is_empty());
+        assert!(galaxy.relations.is_empty());
+        assert!(galaxy.relation_history.is_empty());
+        assert_eq!(galaxy.military_strength, 0);
+
+        let before = galaxy.clone();
+        galaxy.apply_changes(&[extinct, StateChange::RemoveSpecies("Krath".to_string())]);
+        assert_eq!(galaxy, before);
+    }
+
+    #[test]
+    fn merge_species_unions_traits_and_keeps_better_relation() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.apply_changes(&[
+            StateChange::AddSpecies(Species {
+                name: "Velari".to_string(),
+                traits: vec!["peaceful".to_string(), "curious".to_string()],
+            }),
+            StateChange::AddSpecies(Species {
+                name: "Oonai".to_string(),
+                traits: vec!["curious".to_string(), "nomadic".to_string()],
+            }),
+            StateChange::SetRelation {
+                species: "Velari".to_string(),
+                relation: Relation::Allied,
+            },
+            StateChange::SetRelation {
+                species: "Oonai".to_string(),
+                relation: Relation::Wary,
+            },
+        ]);
+        assert_eq!(galaxy.military_strength, ALLY_STRENGTH);
+
+        let merge = StateChange::MergeSpecies {
+            absorbed: "Velari".to_string(),
+            into: "Oonai".to_string(),
+        };
+        galaxy.apply_changes(std::slice::from_ref(&merge));
+
+        assert_eq!(galaxy.known_species.len(), 1);
+        assert!(!galaxy.relations.contains_key("Velari"));
+        assert_eq!(
+            galaxy.known_species[0].traits,
+            vec!["curious", "nomadic", "peaceful"]
+        );
+        assert_eq!(galaxy.relations["Oonai"], Relation::Allied);
+        assert_eq!(galaxy.military_strength, ALLY_STRENGTH);
+
+        // Already merged, unknown or self merges change nothing.
+        let before = galaxy.clone();
+        galaxy.apply_changes(&[
+            merge,
+            StateChange::MergeSpecies {
+                absorbed: "Oonai".to_string(),
+                into: "Krath".to_string(),
+            },
+            StateChange::MergeSpecies {
+                absorbed: "Oonai".to_string(),
+                into: "Oonai".to_string(),
+            },
+        ]);
+        assert_eq!(galaxy, before);
+    }
+
+    #[test]
+    fn allied_discovery_requires_alliance() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.known_species.push(Species {
+            name: "Velari".to_string(),
+            traits: vec![],
+        });
+        galaxy
+            .relations
+            .insert("Velari".to_string(), Relation::Friendly);
+        let shared = StateChange::AddDiscoveryIfAllied {
+            species: "Velari".to_string(),
+            discovery: discovery("Velari Warp Theory"),
+        };
+
+        galaxy.apply_changes(std::slice::from_ref(&shared));
+        assert!(galaxy.discoveries.is_empty());
+
+        galaxy.apply_changes(&[StateChange::SetRelation {
+            species: "Velari".to_string(),
+            relation: Relation::Allied,
+        }]);
+        galaxy.apply_changes(&[shared.clone(), shared]);
+        assert_eq!(galaxy.discoveries.len(), 1);
+        assert_eq!(galaxy.discoveries[0].name, "Velari Warp Theory");
+    }
+
+    #[test]
+    fn score_scaled_by_values_allies_and_sectors_when_reached() {
+        let mut galaxy = GalaxyState::new();
+        let ally = |name: &str| StateChange::SetRelation {
+            species: name.to_string(),
+            relation: Relation::Allied,
+        };
+        let festival = StateChange::ScoreScaledBy {
+            per_ally: 2,
+            per_sector: 1,
+        };
+        galaxy.apply_changes(&[ally("Velari"), ally("Korth")]);
+
+        let score = galaxy.apply_changes_scored(&[festival.clone(), ally("Zenthi"), festival]);
+        // 2 allies + home sector, then 3 allies + home sector
+        assert_eq!(score, (2 * 2 + 1) + (3 * 2 + 1));
+        assert_eq!(galaxy.allied_count(), 3);
+        assert_eq!(galaxy.scaled_score(&ally("Velari")), 0);
+    }
+
+    #[test]
+    fn summary_reports_each_section() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.advance_round();
+        let discovery = |name: &str, category: &str| Discovery {
+            name: name.to_string(),
+            category: category.to_string(),
+            source_round: None,
+            source_event: None,
+        };
+        galaxy.apply_changes(&[
+            StateChange::AddSector(Sector {
+                name: "Crimson Nebula".to_string(),
+                sector_type: SectorType::Nebula,
+            }),
+            StateChange::SetRelation {
+                species: "Velari".to_string(),
+                relation: Relation::Allied,
+            },
+            StateChange::SetRelation {
+                species: "Korth".to_string(),
+                relation: Relation::Hostile,
+            },
+            StateChange::AddDiscovery(discovery("Warp Theory", "technology")),
+            StateChange::AddDiscovery(discovery("Shield Matrix", "technology")),
+            StateChange::AddDiscovery(discovery("Star Map", "artifact")),
+            StateChange::AddThreat(Threat {
+                name: "Void Swarm".to_string(),
+                severity: 3,
+                rounds_active: 0,
+            }),
+        ]);
+
+        let summary = galaxy.summary();
+        assert!(summary.contains("ROUND: 1\n"));
+        assert!(summary.contains("SECTORS: 2 (Habitable 1, Nebula 1)"));
+        assert!(summary.contains("RELATIONS: Allied 1 (Velari), Hostile 1 (Korth)"));
+        assert!(summary.contains("DISCOVERIES: 3 (artifact 1, technology 2)"));
+        assert!(summary.contains("THREATS: Void Swarm(sev=3, rounds=0)"));
+
+        let empty = GalaxyState::new().summary();
+        assert!(empty.contains("RELATIONS: (none)"));
+        assert!(empty.contains("DISCOVERIES: 0\n"));
+        assert!(empty.contains("THREATS: (none)"));
+    }
+
+    #[test]
+    fn tagged_apply_records_discovery_source() {
+        let mut galaxy = GalaxyState::new();
+        let discovery = |name: &str| Discovery {
+            name: name.to_string(),
+            category: "technology".to_string(),
+            source_round: None,
+            source_event: None,
+        };
+        galaxy.apply_changes(&[StateChange::AddDiscovery(discovery("Warp Theory"))]);
+        galaxy.apply_changes_tagged(
+            &[StateChange::AddDiscovery(discovery("Shield Technology"))],
+            7,
+            "Ancient Artifact",
+        );
+
+        assert_eq!(galaxy.discoveries[0].source_round, None);
+        assert_eq!(galaxy.discoveries[1].source_round, Some(7));
+        assert_eq!(
+            galaxy.discoveries[1].source_event.as_deref(),
+            Some("Ancient Artifact")
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn populated_galaxy_round_trips_through_json() {
+        let mut galaxy = GalaxyState::with_change_log();
+        galaxy.advance_round();
+        galaxy.apply_changes_tagged(
+            &[
+                StateChange::AddSector(Sector {
+                    name: "Cygnus Drift".to_string(),
+                    sector_type: SectorType::Nebula,
+                }),
+                StateChange::AddSpecies(Species {
+                    name: "Velari".to_string(),
+                    traits: vec!["peaceful".to_string()],
+                }),
+                StateChange::SetRelation {
+                    species: "Velari".to_string(),
+                    relation: Relation::Allied,
+                },
+                StateChange::AddDiscovery(Discovery {
+                    name: "Warp Theory".to_string(),
+                    category: "science".to_string(),
+                    source_round: None,
+                    source_event: None,
+                }),
+                StateChange::AddThreat(Threat {
+                    name: "Void Swarm".to_string(),
+                    severity: 3,
+                    rounds_active: 1,
+                }),
+                StateChange::ScheduleThreat {
+                    threat: Threat {
+                        name: "Rogue Comet".to_string(),
+                        severity: 1,
+                        rounds_active: 0,
+                    },
+                    in_rounds: 2,
+                },
+            ],
+            1,
+            "Test",
+        );
+
+        let json = serde_json::to_string(&galaxy).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["relations"]["Velari"], "Allied");
+
+        let restored: GalaxyState = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, galaxy);
+    }
+
+    #[test]
+    fn heuristic_value_rewards_allies_and_penalizes_threats() {
+        let mut galaxy = GalaxyState::new();
+        assert_eq!(galaxy.heuristic_value(), 2);
+
+        galaxy
+            .relations
+            .insert("Velari".to_string(), Relation::Allied);
+        galaxy
+            .relations
+            .insert("Krath".to_string(), Relation::Hostile);
+        galaxy.threats.push(Threat {
+            name: "Void Swarm".to_string(),
+            severity: 2,
+            rounds_active: 0,
+        });
+        assert_eq!(galaxy.heuristic_value(), 2 + 10 - 5 - 6);
+    }
+
+    #[test]
+    fn conditional_threat_needs_its_trigger() {
+        let escalate = StateChange::AddThreatIfExists {
+            trigger: "Space Pirates".to_string(),
+            threat: Threat {
+                name: "Pirate Armada".to_string(),
+                severity: 5,
+                rounds_active: 0,
+            },
+        };
+        let mut galaxy = GalaxyState::new();
+        galaxy.apply_changes(std::slice::from_ref(&escalate));
+        assert!(galaxy.threats.is_empty());
+
+        galaxy.threats.push(Threat {
+            name: "Space Pirates".to_string(),
+            severity: 2,
+            rounds_active: 1,
+        });
+        galaxy.apply_changes(&[escalate.clone(), escalate]);
+        let names: Vec<&str> = galaxy.threats.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["Space Pirates", "Pirate Armada"]);
+    }
+
     #[test]
     fn apply_add_sector() {
         let mut galaxy = GalaxyState::new();
@@ -188,6 +1426,40 @@ mod tests {
         assert_eq!(galaxy.explored_sectors.len(), 2);
     }
 
+    #[test]
+    fn remove_sector_keeps_home() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.apply_changes(&[StateChange::AddSector(Sector {
+            name: "Kepler Rim".to_string(),
+            sector_type: SectorType::Void,
+        })]);
+
+        galaxy.apply_changes(&[StateChange::RemoveSector("Kepler Rim".to_string())]);
+        assert_eq!(galaxy.explored_sectors.len(), 1);
+        assert_eq!(galaxy.explored_sectors[0].name, HOME_SECTOR);
+
+        galaxy.apply_changes(&[StateChange::RemoveSector(HOME_SECTOR.to_string())]);
+        assert_eq!(galaxy.explored_sectors.len(), 1);
+    }
+
+    #[test]
+    fn set_sector_type_updates_in_place() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.apply_changes(&[StateChange::SetSectorType {
+            sector: "Home Sector".to_string(),
+            sector_type: SectorType::Void,
+        }]);
+        assert_eq!(galaxy.explored_sectors.len(), 1);
+        assert_eq!(galaxy.explored_sectors[0].sector_type, SectorType::Void);
+
+        // Unknown sectors are ignored
+        galaxy.apply_changes(&[StateChange::SetSectorType {
+            sector: "Nowhere".to_string(),
+            sector_type: SectorType::Nebula,
+        }]);
+        assert_eq!(galaxy.explored_sectors.len(), 1);
+    }
+
     #[test]
     fn apply_add_species_sets_unknown_relation() {
         let mut galaxy = GalaxyState::new();
@@ -227,4 +1499,364 @@ mod tests {
         }]);
         assert!(galaxy.threats.is_empty());
     }
+
+    #[test]
+    fn improve_relation_if_above_respects_floor() {
+        let mut galaxy = GalaxyState::new();
+        galaxy
+            .relations
+            .insert("Velari".to_string(), Relation::Friendly);
+        galaxy
+            .relations
+            .insert("Krelax".to_string(), Relation::Hostile);
+
+        galaxy.apply_changes(&[
+            StateChange::ImproveRelationIfAbove {
+                species: "Velari".to_string(),
+                floor: Relation::Neutral,
+            },
+            StateChange::ImproveRelationIfAbove {
+                species: "Krelax".to_string(),
+                floor: Relation::Neutral,
+            },
+        ]);
+
+        assert_eq!(galaxy.relations.get("Velari"), Some(&Relation::Allied));
+        assert_eq!(galaxy.relations.get("Krelax"), Some(&Relation::Hostile));
+    }
+
+    #[test]
+    fn change_log_records_applied_changes_with_round() {
+        let mut galaxy = GalaxyState::with_change_log();
+        galaxy.round = 4;
+        galaxy.apply_changes(&[
+            StateChange::AddSector(Sector {
+                name: "Gamma Drift".to_string(),
+                sector_type: SectorType::Void,
+            }),
+            StateChange::AddThreat(Threat {
+                name: "Void Swarm".to_string(),
+                severity: 2,
+                rounds_active: 0,
+            }),
+            StateChange::RemoveThreat("Void Swarm".to_string()),
+        ]);
+
+        assert_eq!(galaxy.change_log.len(), 3);
+        assert!(galaxy.change_log.iter().all(|(round, _)| *round == 4));
+        assert!(matches!(
+            galaxy.change_log[2].1,
+            StateChange::RemoveThreat(ref name) if name == "Void Swarm"
+        ));
+    }
+
+    #[test]
+    fn change_log_is_empty_by_default() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.apply_changes(&[StateChange::RemoveThreat("Nothing".to_string())]);
+        assert!(galaxy.change_log.is_empty());
+    }
+
+    fn idempotency_batch() -> Vec<StateChange> {
+        vec![
+            StateChange::AddSector(Sector {
+                name: "Beta Reach".to_string(),
+                sector_type: SectorType::Nebula,
+            }),
+            StateChange::AddSpecies(Species {
+                name: "Velari".to_string(),
+                traits: vec![],
+            }),
+            StateChange::AddThreat(Threat {
+                name: "Void Swarm".to_string(),
+                severity: 2,
+                rounds_active: 0,
+            }),
+        ]
+    }
+
+    #[test]
+    fn add_sector_species_threat_are_idempotent() {
+        let batch = idempotency_batch();
+        let mut once = GalaxyState::new();
+        once.apply_changes(&batch);
+        let mut twice = GalaxyState::new();
+        twice.apply_changes(&batch);
+        twice.apply_changes(&batch);
+
+        assert_eq!(once.explored_sectors, twice.explored_sectors);
+        assert_eq!(once.known_species, twice.known_species);
+        assert_eq!(once.relations, twice.relations);
+        assert_eq!(once.threats, twice.threats);
+    }
+
+    #[test]
+    fn add_species_twice_keeps_existing_relation() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.apply_changes(&idempotency_batch());
+        galaxy
+            .relations
+            .insert("Velari".to_string(), Relation::Friendly);
+        galaxy.apply_changes(&idempotency_batch());
+        assert_eq!(galaxy.relations.get("Velari"), Some(&Relation::Friendly));
+    }
+
+    #[test]
+    fn set_relation_and_remove_threat_are_idempotent() {
+        let batch = vec![
+            StateChange::SetRelation {
+                species: "Velari".to_string(),
+                relation: Relation::Wary,
+            },
+            StateChange::RemoveThreat("Void Swarm".to_string()),
+        ];
+        let mut galaxy = GalaxyState::new();
+        galaxy.apply_changes(&idempotency_batch());
+        galaxy.apply_changes(&batch);
+        galaxy.apply_changes(&batch);
+        assert_eq!(galaxy.relations.get("Velari"), Some(&Relation::Wary));
+        assert!(galaxy.threats.is_empty());
+    }
+
+    #[test]
+    fn add_discovery_is_additive() {
+        let batch = vec![StateChange::AddDiscovery(Discovery {
+            name: "Quantum Lattice".to_string(),
+            category: "technology".to_string(),
+            source_round: None,
+            source_event: None,
+        })];
+        let mut galaxy = GalaxyState::new();
+        galaxy.apply_changes(&batch);
+        galaxy.apply_changes(&batch);
+        assert_eq!(galaxy.discoveries.len(), 2);
+    }
+
+    #[test]
+    fn add_discovery_unique_is_idempotent() {
+        let batch = vec![StateChange::AddDiscoveryUnique(Discovery {
+            name: "Quantum Lattice".to_string(),
+            category: "technology".to_string(),
+            source_round: None,
+            source_event: None,
+        })];
+        let mut galaxy = GalaxyState::new();
+        galaxy.apply_changes(&batch);
+        galaxy.apply_changes(&batch);
+        assert_eq!(galaxy.discoveries.len(), 1);
+    }
+
+    #[test]
+    fn scheduled_threat_activates_after_countdown() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.advance_round();
+        assert_eq!(galaxy.round, 1);
+        galaxy.apply_changes(&[StateChange::ScheduleThreat {
+            threat: Threat {
+                name: "Krelax Armada".to_string(),
+                severity: 4,
+                rounds_active: 0,
+            },
+            in_rounds: 2,
+        }]);
+
+        // Round 1 and 2: still looming
+        assert!(galaxy.threats.is_empty());
+        galaxy.advance_round();
+        assert!(galaxy.threats.is_empty());
+        assert_eq!(galaxy.pending_threats.len(), 1);
+
+        // Round 3: arrives
+        galaxy.advance_round();
+        assert_eq!(galaxy.round, 3);
+        assert_eq!(galaxy.threats.len(), 1);
+        assert_eq!(galaxy.threats[0].name, "Krelax Armada");
+        assert!(galaxy.pending_threats.is_empty());
+    }
+
+    #[test]
+    fn schedule_threat_zero_rounds_is_immediate() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.apply_changes(&[StateChange::ScheduleThreat {
+            threat: Threat {
+                name: "Rogue Comet".to_string(),
+                severity: 1,
+                rounds_active: 0,
+            },
+            in_rounds: 0,
+        }]);
+        assert_eq!(galaxy.threats.len(), 1);
+        assert!(galaxy.pending_threats.is_empty());
+    }
+
+    fn discovery(name: &str) -> Discovery {
+        Discovery {
+            name: name.to_string(),
+            category: "salvage".to_string(),
+            source_round: None,
+            source_event: None,
+        }
+    }
+
+    #[test]
+    fn add_discoveries_adds_all_at_once() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.apply_changes(&[StateChange::AddDiscoveries(vec![
+            discovery("Ion Coil"),
+            discovery("Star Chart"),
+            discovery("Hull Alloy"),
+        ])]);
+        let names: Vec<&str> = galaxy.discoveries.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["Ion Coil", "Star Chart", "Hull Alloy"]);
+    }
+
+    #[test]
+    fn add_discoveries_unique_skips_known_names() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.apply_changes(&[StateChange::AddDiscovery(discovery("Ion Coil"))]);
+        let batch = [StateChange::AddDiscoveriesUnique(vec![
+            discovery("Ion Coil"),
+            discovery("Star Chart"),
+            discovery("Star Chart"),
+        ])];
+        galaxy.apply_changes(&batch);
+        galaxy.apply_changes(&batch);
+        assert_eq!(galaxy.discoveries.len(), 2);
+    }
+
+    #[test]
+    fn morale_adjustments_clamp_to_range() {
+        let mut galaxy = GalaxyState::new();
+        assert_eq!(galaxy.morale, STARTING_MORALE);
+        galaxy.apply_changes(&[StateChange::AdjustMorale(80)]);
+        assert_eq!(galaxy.morale, MAX_MORALE);
+        galaxy.apply_changes(&[StateChange::AdjustMorale(-250)]);
+        assert_eq!(galaxy.morale, 0);
+        galaxy.apply_changes(&[StateChange::AdjustMorale(i32::MIN)]);
+        assert_eq!(galaxy.morale, 0);
+        galaxy.morale = MAX_MORALE;
+        galaxy.apply_changes(&[StateChange::AdjustMorale(i32::MAX)]);
+        assert_eq!(galaxy.morale, MAX_MORALE);
+    }
+
+    #[test]
+    fn threats_sap_morale() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.threats.push(Threat {
+            name: "Space Pirates".to_string(),
+            severity: 2,
+            rounds_active: 0,
+        });
+        galaxy.threats.push(Threat {
+            name: "Void Swarm".to_string(),
+            severity: 3,
+            rounds_active: 0,
+        });
+        galaxy.process_threats();
+        assert_eq!(galaxy.morale, STARTING_MORALE - 5);
+    }
+
+    #[test]
+    fn relation_trend_reports_improving() {
+        let mut galaxy = GalaxyState::with_relation_history();
+        for relation in [Relation::Wary, Relation::Neutral, Relation::Friendly] {
+            galaxy.relations.insert("Velari".to_string(), relation);
+            galaxy.advance_round();
+        }
+        assert_eq!(
+            galaxy.relation_history.get("Velari"),
+            Some(&vec![Relation::Wary, Relation::Neutral, Relation::Friendly])
+        );
+        assert_eq!(galaxy.relation_trend("Velari"), Trend::Improving);
+
+        galaxy
+            .relations
+            .insert("Velari".to_string(), Relation::Hostile);
+        assert_eq!(galaxy.relation_trend("Velari"), Trend::Declining);
+        assert_eq!(galaxy.relation_trend("Krelax"), Trend::Stable);
+    }
+
+    #[test]
+    fn relation_history_is_opt_in() {
+        let mut galaxy = GalaxyState::new();
+        galaxy
+            .relations
+            .insert("Velari".to_string(), Relation::Neutral);
+        galaxy.advance_round();
+        assert!(galaxy.relation_history.is_empty());
+        assert_eq!(galaxy.relation_trend("Velari"), Trend::Stable);
+    }
+
+    #[test]
+    fn alliances_change_military_strength() {
+        let mut galaxy = GalaxyState::new();
+        galaxy
+            .relations
+            .insert("Velari".to_string(), Relation::Friendly);
+        galaxy.apply_changes(&[StateChange::SetRelation {
+            species: "Velari".to_string(),
+            relation: Relation::Allied,
+        }]);
+        assert_eq!(galaxy.military_strength, ALLY_STRENGTH);
+
+        // Re-affirming the alliance does not stack
+        galaxy.apply_changes(&[StateChange::SetRelation {
+            species: "Velari".to_string(),
+            relation: Relation::Allied,
+        }]);
+        assert_eq!(galaxy.military_strength, ALLY_STRENGTH);
+
+        galaxy.apply_changes(&[StateChange::SetRelation {
+            species: "Velari".to_string(),
+            relation: Relation::Wary,
+        }]);
+        assert_eq!(galaxy.military_strength, 0);
+    }
+
+    #[test]
+    fn adjust_strength_saturates_at_zero() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.apply_changes(&[StateChange::AdjustStrength(3)]);
+        assert_eq!(galaxy.military_strength, 3);
+        galaxy.apply_changes(&[StateChange::AdjustStrength(-10)]);
+        assert_eq!(galaxy.military_strength, 0);
+    }
+
+    #[test]
+    fn threat_severity_is_capped() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.threats.push(Threat {
+            name: "Void Swarm".to_string(),
+            severity: 5,
+            rounds_active: 0,
+        });
+        galaxy.apply_changes(&[StateChange::ModifyThreatSeverity {
+            name: "Void Swarm".to_string(),
+            delta: 100,
+        }]);
+        assert_eq!(galaxy.threats[0].severity, DEFAULT_MAX_SEVERITY);
+
+        galaxy.max_severity = 3;
+        galaxy.apply_changes(&[StateChange::AddThreat(Threat {
+            name: "Krelax Armada".to_string(),
+            severity: 8,
+            rounds_active: 0,
+        })]);
+        assert_eq!(galaxy.threats[1].severity, 3);
+    }
+
+    #[test]
+    fn extreme_negative_delta_removes_threat() {
+        let mut galaxy = GalaxyState::new();
+        galaxy.threats.push(Threat {
+            name: "Void Swarm".to_string(),
+            severity: 5,
+            rounds_active: 0,
+        });
+        galaxy.apply_changes(&[StateChange::ModifyThreatSeverity {
+            name: "Void Swarm".to_string(),
+            delta: i32::MIN,
+        }]);
+        assert!(galaxy.threats.is_empty());
+    }
 }
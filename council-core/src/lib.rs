@@ -1,43 +1,90 @@
+use std::collections::HashMap;
 use std::fmt;
 
 // ============================================================================
 // Galactic Exploration Modules (new simulation system)
 // ============================================================================
 
+pub mod comparison;
 pub mod event;
 pub mod explorer;
+pub mod faction;
 pub mod galaxy;
+pub mod milestone;
 pub mod ollama;
+pub mod orchestrator;
+pub mod registry;
+#[cfg(feature = "serde")]
+pub mod scenario;
 pub mod scoring;
 pub mod templates;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
 pub mod voting;
 
 // Re-export commonly used types for convenience
+pub use comparison::{compare_councils, CouncilComparison, Leader, RoundComparison};
 pub use event::{Event, EventTemplate, Outcome, ResponseOption};
-pub use explorer::GalacticCouncilMember;
+pub use explorer::{
+    CheckedMember, CloneMember, GalacticAsClassic, GalacticCouncilMember, NamedMember,
+    SpecialistBot, VarietyBot, VoteTrace,
+};
+pub use faction::Faction;
 pub use galaxy::{
-    Discovery, GalaxyState, Relation, Sector, SectorType, Species, StateChange, Threat,
+    Discovery, GalaxyState, ModifierKind, Relation, Sector, SectorType, Species, StateChange,
+    Threat, TimedModifier, Trend,
+};
+pub use milestone::{Milestone, MilestoneConfig};
+pub use orchestrator::{
+    run_galactic, verify_against_rng_log, GalacticOrchestrator, GalacticReport, GalaxySnapshot,
+    OrchestratorConfig, RoundRecord,
+};
+pub use registry::{BotFactory, BotRegistry};
+#[cfg(feature = "serde")]
+pub use scenario::{run_scenario, Scenario};
+pub use scoring::{ScoreEvent, ScoreTracker, StreakKind};
+pub use templates::{
+    default_templates, generate_event, generate_event_weighted, generate_event_with_fallback,
+    generate_named_event,
+};
+pub use voting::{
+    apply_confidence, calculate_vote_weight, calculate_vote_weight_with_base, resolve_approval,
+    resolve_plurality, resolve_ranked_choice, resolve_votes, resolve_votes_detailed,
+    resolve_votes_hashed, resolve_votes_ranked, resolve_votes_stable, resolve_votes_with,
+    tally_votes, RankedVote, ResolutionMode, TieBreak, Vote, BASE_WEIGHT,
 };
-pub use scoring::{ScoreEvent, ScoreTracker};
-pub use templates::{default_templates, generate_event};
-pub use voting::{calculate_vote_weight, resolve_votes, Vote, BASE_WEIGHT};
 
 // ============================================================================
 // Legacy Simple Voting System (for backward compatibility)
 // ============================================================================
 
+pub mod simulation;
+
+pub use simulation::{
+    simulate_galaxy, simulate_galaxy_until, simulate_rounds, simulate_rounds_deadline,
+    simulate_rounds_from, simulate_rounds_logged, simulate_rounds_tally_only, GalaxyReport,
+    GalaxyRoundRecord, RoundResult, SimulationReport, StopCondition,
+};
+
 /// Shared simulation context passed to all council members.
 pub struct Context {
     pub round: u32,
     pub previous_tally: Option<RoundTally>,
+    /// Seed of the run (see [`simulate_rounds_from`]), 0 unless one was
+    /// given; bots that need randomness can derive it from this and `round`.
+    pub seed: u64,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct RoundTally {
     pub approvals: u32,
     pub rejections: u32,
     pub abstentions: u32,
+    /// Total custom decisions across all labels.
     pub customs: u32,
+    /// Custom decisions counted per label (sums to `customs`). After a
+    /// [`reset`](Self::reset) earlier labels stay with a count of 0.
+    pub custom_labels: HashMap<String, u32>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -55,7 +102,27 @@ impl RoundTally {
             Decision::Approve => self.approvals += 1,
             Decision::Reject => self.rejections += 1,
             Decision::Abstain => self.abstentions += 1,
-            Decision::Custom(_) => self.customs += 1,
+            Decision::Custom(label) => {
+                self.customs += 1;
+                // Only a label's first sighting allocates its key.
+                if let Some(count) = self.custom_labels.get_mut(*label) {
+                    *count += 1;
+                } else {
+                    self.custom_labels.insert(label.to_string(), 1);
+                }
+            }
+        }
+    }
+
+    /// Zero every count, keeping the labels seen so far (at 0) so that
+    /// recording them again allocates nothing.
+    pub fn reset(&mut self) {
+        self.approvals = 0;
+        self.rejections = 0;
+        self.abstentions = 0;
+        self.customs = 0;
+        for count in self.custom_labels.values_mut() {
+            *count = 0;
         }
     }
 
@@ -66,6 +133,22 @@ impl RoundTally {
         )
     }
 
+    /// Describe custom decisions per label, most used first (ties by label),
+    /// e.g. `"wildcard: 3, chaos: 2"`. Labels at 0 are left out.
+    pub fn describe_custom_labels(&self) -> String {
+        let mut labels: Vec<(&String, &u32)> = self
+            .custom_labels
+            .iter()
+            .filter(|(_, &count)| count > 0)
+            .collect();
+        labels.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        labels
+            .iter()
+            .map(|(label, count)| format!("{}: {}", label, count))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
     pub fn dominant(&self) -> DominantOutcome {
         let values = [
             (self.approvals, DominantOutcome::Approve),
@@ -117,6 +200,16 @@ impl fmt::Display for Decision {
 pub trait CouncilMember {
     fn name(&self) -> &'static str;
     fn vote(&self, ctx: &Context) -> Decision;
+
+    /// Called by the simulators once each round is complete, with every
+    /// member's decision and the round's tally. Does nothing by default.
+    ///
+    /// Members are shared as `&self`, so a bot that remembers past rounds
+    /// keeps its history behind interior mutability: a `Cell`/`RefCell` is
+    /// enough for [`simulate_rounds`], while [`simulate_rounds_deadline`]
+    /// needs `Send + Sync` state such as a `Mutex` or atomics.
+    /// [`simulate_rounds_tally_only`] never calls it.
+    fn observe(&self, _result: &RoundResult) {}
 }
 
 #[cfg(test)]
@@ -141,6 +234,7 @@ mod tests {
         let ctx = Context {
             round: 1,
             previous_tally: None,
+            seed: 0,
         };
         assert!(matches!(bot.vote(&ctx), Decision::Approve));
     }
@@ -172,4 +266,60 @@ mod tests {
         };
         assert_eq!(tally.dominant(), DominantOutcome::Custom);
     }
+
+    #[test]
+    fn dominant_outcome_covers_every_category_and_tie_shape() {
+        let tally = |approvals, rejections, abstentions, customs| RoundTally {
+            approvals,
+            rejections,
+            abstentions,
+            customs,
+            ..RoundTally::default()
+        };
+
+        assert_eq!(tally(0, 0, 0, 0).dominant(), DominantOutcome::Tie);
+        assert_eq!(tally(3, 3, 3, 3).dominant(), DominantOutcome::Tie);
+        assert_eq!(tally(1, 4, 4, 4).dominant(), DominantOutcome::Tie);
+        assert_eq!(tally(2, 0, 0, 2).dominant(), DominantOutcome::Tie);
+        assert_eq!(tally(0, 1, 5, 5).dominant(), DominantOutcome::Tie);
+
+        assert_eq!(tally(5, 1, 1, 1).dominant(), DominantOutcome::Approve);
+        assert_eq!(tally(0, 1, 0, 0).dominant(), DominantOutcome::Reject);
+        assert_eq!(tally(2, 2, 3, 0).dominant(), DominantOutcome::Abstain);
+        // A tie for second place does not block a single leader.
+        assert_eq!(tally(1, 1, 0, 4).dominant(), DominantOutcome::Custom);
+    }
+
+    #[test]
+    fn record_tracks_custom_labels() {
+        let mut tally = RoundTally::default();
+        tally.record(&Decision::Custom("wildcard"));
+        tally.record(&Decision::Custom("chaos"));
+        tally.record(&Decision::Custom("wildcard"));
+        tally.record(&Decision::Approve);
+
+        assert_eq!(tally.customs, 3);
+        assert_eq!(tally.custom_labels.get("wildcard"), Some(&2));
+        assert_eq!(tally.custom_labels.get("chaos"), Some(&1));
+        assert_eq!(tally.custom_labels.len(), 2);
+        assert_eq!(tally.describe_custom_labels(), "wildcard: 2, chaos: 1");
+    }
+
+    #[test]
+    fn reset_keeps_labels_for_reuse() {
+        let mut tally = RoundTally::default();
+        tally.record(&Decision::Custom("wildcard"));
+        tally.record(&Decision::Custom("chaos"));
+        tally.reset();
+
+        assert_eq!(tally.customs, 0);
+        assert_eq!(tally.custom_labels.get("wildcard"), Some(&0));
+        assert_eq!(tally.describe_custom_labels(), "");
+
+        tally.record(&Decision::Custom("wildcard"));
+        tally.record(&Decision::Custom("wildcard"));
+        assert_eq!(tally.customs, 2);
+        assert_eq!(tally.custom_labels.len(), 2);
+        assert_eq!(tally.describe_custom_labels(), "wildcard: 2");
+    }
 }
@@ -0,0 +1,86 @@
+//! One-time score bonuses for galaxy milestones.
+
+use crate::galaxy::GalaxyState;
+
+/// A point of progress the council can reach once per game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Milestone {
+    /// At least one species is allied.
+    FirstAlly,
+    /// At least this many sectors are explored (including the home sector).
+    SectorsExplored(usize),
+    /// An outcome removed an active threat.
+    FirstThreatResolved,
+}
+
+impl Milestone {
+    /// Whether the milestone holds, given the galaxy and how many threats
+    /// outcomes have resolved so far.
+    pub fn is_reached(&self, galaxy: &GalaxyState, threats_resolved: u32) -> bool {
+        match self {
+            Milestone::FirstAlly => galaxy.allied_count() >= 1,
+            Milestone::SectorsExplored(count) => galaxy.explored_sectors.len() >= *count,
+            Milestone::FirstThreatResolved => threats_resolved >= 1,
+        }
+    }
+
+    /// Short narrative label, e.g. `"5 sectors explored"`.
+    pub fn describe(&self) -> String {
+        match self {
+            Milestone::FirstAlly => "first ally".to_string(),
+            Milestone::SectorsExplored(count) => format!("{} sectors explored", count),
+            Milestone::FirstThreatResolved => "first threat resolved".to_string(),
+        }
+    }
+}
+
+/// Milestones and the one-time bonus each one awards.
+///
+/// Empty by default, so no bonuses are granted unless configured.
+#[derive(Debug, Clone, Default)]
+pub struct MilestoneConfig {
+    pub bonuses: Vec<(Milestone, i32)>,
+}
+
+impl MilestoneConfig {
+    /// Add a milestone bonus.
+    pub fn with(mut self, milestone: Milestone, bonus: i32) -> Self {
+        self.bonuses.push((milestone, bonus));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::galaxy::{Relation, Sector, SectorType};
+
+    #[test]
+    fn sectors_explored_counts_home_sector() {
+        let mut galaxy = GalaxyState::new();
+        assert!(Milestone::SectorsExplored(1).is_reached(&galaxy, 0));
+        assert!(!Milestone::SectorsExplored(2).is_reached(&galaxy, 0));
+        galaxy.explored_sectors.push(Sector {
+            name: "Outer Rim".to_string(),
+            sector_type: SectorType::Void,
+        });
+        assert!(Milestone::SectorsExplored(2).is_reached(&galaxy, 0));
+    }
+
+    #[test]
+    fn first_ally_and_threat_resolved() {
+        let mut galaxy = GalaxyState::new();
+        assert!(!Milestone::FirstAlly.is_reached(&galaxy, 0));
+        galaxy
+            .relations
+            .insert("Velari".to_string(), Relation::Allied);
+        assert!(Milestone::FirstAlly.is_reached(&galaxy, 0));
+
+        assert!(!Milestone::FirstThreatResolved.is_reached(&galaxy, 0));
+        assert!(Milestone::FirstThreatResolved.is_reached(&galaxy, 1));
+        assert_eq!(
+            Milestone::FirstThreatResolved.describe(),
+            "first threat resolved"
+        );
+    }
+}
@@ -5,10 +5,13 @@
 
 use crate::event::Event;
 use crate::galaxy::GalaxyState;
-use serde::Deserialize;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
 
 /// LLM backend API type.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum LlmApi {
     /// Ollama native API (`POST /api/generate`).
     Ollama,
@@ -24,22 +27,332 @@ pub struct OllamaConfig {
     ///
     /// - `LlmApi::Ollama`: accepts `127.0.0.1:11434` or `http://127.0.0.1:11434`.
     /// - `LlmApi::OpenAiChatCompletions`: accepts `http://127.0.0.1:1234/v1`.
+    ///
+    /// `https://` endpoints need the `tls` feature.
     pub host: String,
     pub model: String,
     pub api: LlmApi,
     /// Optional API key (LM Studio often accepts any value).
     pub api_key: Option<String>,
+    /// Extra attempts after a transient failure ([`LlmError::is_transient`]);
+    /// 0 tries once.
+    pub retries: u8,
+    /// Wait before the first retry, doubled before each later one.
+    pub backoff: Duration,
+    /// Sampling temperature sent with every request; 0.0 keeps output as
+    /// deterministic as the model allows.
+    pub temperature: f32,
+    /// Sampling seed sent with every request, for reproducible output.
+    pub seed: Option<u64>,
+    /// Responses to reuse for repeated prompts; share one cache across bots
+    /// by cloning the `Arc`. Off when `None`.
+    pub cache: Option<Arc<LlmCache>>,
+    /// Where requests are sent; `None` uses [`TcpTransport`]. Tests can
+    /// plug in a [`MockTransport`] to run without a server.
+    pub transport: Option<Arc<dyn LlmTransport>>,
+}
+
+impl OllamaConfig {
+    /// A config for `model` at `host` speaking `api`, with no API key,
+    /// retries, cache or custom transport, temperature 0.0 and no seed.
+    /// Override the rest with struct update syntax.
+    pub fn new(host: impl Into<String>, model: impl Into<String>, api: LlmApi) -> Self {
+        Self {
+            host: host.into(),
+            model: model.into(),
+            api,
+            api_key: None,
+            retries: 0,
+            backoff: Duration::ZERO,
+            temperature: 0.0,
+            seed: None,
+            cache: None,
+            transport: None,
+        }
+    }
+}
+
+/// Sends a prompt to an LLM and returns its raw text reply.
+///
+/// [`llm_generate`] and friends apply caching and retries around it.
+pub trait LlmTransport: fmt::Debug + Send + Sync {
+    fn generate(&self, cfg: &OllamaConfig, prompt: &str) -> Result<String, LlmError>;
+
+    /// Send a prompt split into system and user parts. Defaults to
+    /// [`generate`](LlmTransport::generate) on the two joined by a newline.
+    fn generate_with_system(
+        &self,
+        cfg: &OllamaConfig,
+        system: &str,
+        user: &str,
+    ) -> Result<String, LlmError> {
+        self.generate(cfg, &join_prompt(system, user))
+    }
+}
+
+/// The real transport: HTTP over TCP (or TLS with the `tls` feature) to
+/// `cfg.host`, speaking `cfg.api`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TcpTransport;
+
+impl LlmTransport for TcpTransport {
+    fn generate(&self, cfg: &OllamaConfig, prompt: &str) -> Result<String, LlmError> {
+        let sampling = Sampling::of(cfg);
+        match cfg.api {
+            LlmApi::Ollama => {
+                ollama_stream_sampled(&cfg.host, &cfg.model, prompt, sampling, &mut |_| {})
+            }
+            LlmApi::OpenAiChatCompletions => openai_chat_sampled(
+                &cfg.host,
+                cfg.api_key.as_deref(),
+                &cfg.model,
+                None,
+                prompt,
+                sampling,
+            ),
+        }
+    }
+
+    fn generate_with_system(
+        &self,
+        cfg: &OllamaConfig,
+        system: &str,
+        user: &str,
+    ) -> Result<String, LlmError> {
+        match cfg.api {
+            LlmApi::Ollama => self.generate(cfg, &join_prompt(system, user)),
+            LlmApi::OpenAiChatCompletions => openai_chat_sampled(
+                &cfg.host,
+                cfg.api_key.as_deref(),
+                &cfg.model,
+                Some(system),
+                user,
+                Sampling::of(cfg),
+            ),
+        }
+    }
+}
+
+/// Lock `mutex`, recovering the data if a panicking holder poisoned it; the
+/// guarded state here is only ever pushed to or replaced whole.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Offline transport for tests: replays canned responses in order, repeating
+/// the last one once the rest are used, and records every prompt it was sent.
+///
+/// With no responses at all every call fails with [`LlmError::Connect`].
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    responses: Mutex<VecDeque<Result<String, LlmError>>>,
+    prompts: Mutex<Vec<String>>,
+}
+
+impl MockTransport {
+    pub fn new<I, S>(responses: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::with_results(responses.into_iter().map(|r| Ok(r.into())))
+    }
+
+    /// Replay successes and failures alike.
+    pub fn with_results(results: impl IntoIterator<Item = Result<String, LlmError>>) -> Self {
+        Self {
+            responses: Mutex::new(results.into_iter().collect()),
+            prompts: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Every prompt received so far, oldest first (system and user parts
+    /// joined).
+    pub fn prompts(&self) -> Vec<String> {
+        lock(&self.prompts).clone()
+    }
+}
+
+impl LlmTransport for MockTransport {
+    fn generate(&self, _cfg: &OllamaConfig, prompt: &str) -> Result<String, LlmError> {
+        lock(&self.prompts).push(prompt.to_string());
+        let mut responses = lock(&self.responses);
+        if responses.len() > 1 {
+            responses.pop_front().unwrap()
+        } else {
+            responses.front().cloned().unwrap_or(Err(LlmError::Connect))
+        }
+    }
+}
+
+/// The transport `cfg` sends through.
+fn transport_of(cfg: &OllamaConfig) -> &dyn LlmTransport {
+    match &cfg.transport {
+        Some(transport) => transport.as_ref(),
+        None => &TcpTransport,
+    }
+}
+
+/// Everything that shapes a request: two requests with equal keys get the
+/// same response from a deterministic endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    host: String,
+    api: LlmApi,
+    model: String,
+    /// `f32` bits, so the key can be `Eq`.
+    temperature: u32,
+    seed: Option<u64>,
+    system: Option<String>,
+    prompt: String,
+}
+
+impl CacheKey {
+    fn new(cfg: &OllamaConfig, system: Option<&str>, prompt: &str) -> Self {
+        Self {
+            host: cfg.host.clone(),
+            api: cfg.api,
+            model: cfg.model.clone(),
+            temperature: cfg.temperature.to_bits(),
+            seed: cfg.seed,
+            system: system.map(str::to_string),
+            prompt: prompt.to_string(),
+        }
+    }
+}
+
+/// In-memory store of LLM responses keyed by the full request (host, API,
+/// model, sampling settings and prompt), so reruns with identical requests
+/// skip the endpoint.
+///
+/// Only successful responses are stored.
+#[derive(Debug, Default)]
+pub struct LlmCache {
+    entries: Mutex<HashMap<CacheKey, String>>,
+}
+
+impl LlmCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of cached responses.
+    pub fn len(&self) -> usize {
+        lock(&self.entries).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop every cached response.
+    pub fn clear(&self) {
+        lock(&self.entries).clear();
+    }
+
+    /// The cached response for `key`, or the result of `generate`, stored
+    /// when it succeeds.
+    fn get_or_generate(
+        &self,
+        key: CacheKey,
+        generate: impl FnOnce() -> Result<String, LlmError>,
+    ) -> Result<String, LlmError> {
+        if let Some(hit) = lock(&self.entries).get(&key) {
+            return Ok(hit.clone());
+        }
+        let response = generate()?;
+        lock(&self.entries).insert(key, response.clone());
+        Ok(response)
+    }
+}
+
+/// Run `generate` through `cfg`'s cache, if it has one.
+fn cached(
+    cfg: &OllamaConfig,
+    system: Option<&str>,
+    prompt: &str,
+    generate: impl FnOnce() -> Result<String, LlmError>,
+) -> Result<String, LlmError> {
+    match &cfg.cache {
+        Some(cache) => cache.get_or_generate(CacheKey::new(cfg, system, prompt), generate),
+        None => generate(),
+    }
+}
+
+/// Sampling settings carried into a request body.
+#[derive(Debug, Clone, Copy, Default)]
+struct Sampling {
+    temperature: f32,
+    seed: Option<u64>,
+}
+
+impl Sampling {
+    fn of(cfg: &OllamaConfig) -> Self {
+        Self {
+            temperature: cfg.temperature,
+            seed: cfg.seed,
+        }
+    }
+}
+
+/// Why an LLM request failed.
+///
+/// Callers can retry [`is_transient`](LlmError::is_transient) failures
+/// (`Connect`, `Timeout`) and fall back deterministically on the rest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LlmError {
+    /// The host could not be resolved or reached, or the connection broke.
+    Connect,
+    /// Connecting, writing or reading took longer than the client allows.
+    Timeout,
+    /// The server answered with a non-2xx status code.
+    Http(u16),
+    /// The endpoint or the response could not be parsed.
+    Parse(String),
+    /// The model's text held no usable option index.
+    NoChoice,
+    /// The server or the model returned nothing.
+    EmptyResponse,
+    /// The endpoint cannot be used as configured (e.g. `https://` without
+    /// the `tls` feature).
+    Config(String),
 }
 
-#[derive(Debug, Deserialize)]
-struct ChoiceJson {
-    choice: usize,
-    #[allow(dead_code)]
-    reason: Option<String>,
+impl LlmError {
+    /// Whether retrying the same request might succeed.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, LlmError::Connect | LlmError::Timeout)
+    }
+
+    fn from_io(err: std::io::Error) -> Self {
+        use std::io::ErrorKind;
+        match err.kind() {
+            ErrorKind::TimedOut | ErrorKind::WouldBlock => LlmError::Timeout,
+            ErrorKind::InvalidData => LlmError::Parse(err.to_string()),
+            _ => LlmError::Connect,
+        }
+    }
 }
 
+impl fmt::Display for LlmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LlmError::Connect => write!(f, "connection failed"),
+            LlmError::Timeout => write!(f, "request timed out"),
+            LlmError::Http(code) => write!(f, "HTTP error: {}", code),
+            LlmError::Parse(detail) => write!(f, "invalid response: {}", detail),
+            LlmError::NoChoice => write!(f, "no valid choice found in response"),
+            LlmError::EmptyResponse => write!(f, "empty response"),
+            LlmError::Config(detail) => write!(f, "invalid endpoint: {}", detail),
+        }
+    }
+}
+
+impl std::error::Error for LlmError {}
+
 /// Parse a host string like "http://127.0.0.1:11434", "https://example.com:8080",
-/// or "127.0.0.1:11434" into (hostname, port). Defaults to port 11434.
+/// or "127.0.0.1:11434" into (hostname, port). Defaults to port 443 for
+/// `https://` hosts and 11434 otherwise.
 /// Returns Err on empty hostname or invalid port.
 ///
 /// Note: This helper is intended for host:port style endpoints (Ollama).
@@ -54,35 +367,60 @@ pub fn parse_host(host: &str) -> Result<(String, u16), String> {
     if hostname.is_empty() {
         return Err("empty hostname".to_string());
     }
+    let default_port = match Scheme::of(host) {
+        Scheme::Http => "11434",
+        Scheme::Https => "443",
+    };
     let port = parts
         .next()
-        .unwrap_or("11434")
+        .unwrap_or(default_port)
         .trim()
         .parse::<u16>()
         .map_err(|_| "invalid port".to_string())?;
     Ok((hostname, port))
 }
 
-/// Parse an HTTP url (http only) into (hostname, port, path_prefix).
+/// URL scheme of an LLM endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheme {
+    Http,
+    /// Needs the `tls` feature to connect.
+    Https,
+}
+
+impl Scheme {
+    /// `Https` for `https://` urls, otherwise `Http` (including bare hosts).
+    pub fn of(url: &str) -> Self {
+        if url.starts_with("https://") {
+            Scheme::Https
+        } else {
+            Scheme::Http
+        }
+    }
+}
+
+/// Parse an HTTP(S) url into (hostname, port, path_prefix, scheme).
 ///
 /// Accepts:
 /// - `http://127.0.0.1:1234/v1`
+/// - `https://api.example.com/v1` (port defaults to 443)
 /// - `127.0.0.1:1234/v1`
 /// - `127.0.0.1:1234`
 ///
 /// Notes:
-/// - `https://` is rejected (no TLS in this minimal client).
 /// - `path_prefix` is empty or starts with `/`.
-pub fn parse_http_url(url: &str) -> Result<(String, u16, String), String> {
+/// - Parsing never fails on the scheme; connecting to an `https://` url
+///   requires the `tls` feature.
+pub fn parse_http_url(url: &str) -> Result<(String, u16, String, Scheme), String> {
     if url.trim().is_empty() {
         return Err("empty url".to_string());
     }
 
-    if url.starts_with("https://") {
-        return Err("https not supported".to_string());
-    }
-
-    let u = url.strip_prefix("http://").unwrap_or(url);
+    let scheme = Scheme::of(url);
+    let u = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
     let (hostport, path) = match u.split_once('/') {
         Some((a, b)) => (a, format!("/{}", b)),
         None => (u, "".to_string()),
@@ -93,42 +431,49 @@ pub fn parse_http_url(url: &str) -> Result<(String, u16, String), String> {
     if hostname.is_empty() {
         return Err("missing host".to_string());
     }
+    let default_port = match scheme {
+        Scheme::Http => "80",
+        Scheme::Https => "443",
+    };
     let port = parts
         .next()
-        .unwrap_or("80")
+        .unwrap_or(default_port)
         .trim()
         .parse::<u16>()
         .map_err(|_| "invalid port".to_string())?;
 
-    Ok((hostname, port, path))
+    Ok((hostname, port, path, scheme))
 }
 
 /// Parse an HTTP status line like "HTTP/1.1 200 OK" and return the status code
-/// for 2xx responses, or an error for non-2xx or malformed lines.
-pub fn parse_http_status(status_line: &str) -> Result<u16, String> {
+/// for 2xx responses, [`LlmError::Http`] for other codes, or
+/// [`LlmError::Parse`] for malformed lines.
+pub fn parse_http_status(status_line: &str) -> Result<u16, LlmError> {
     let parts: Vec<&str> = status_line.splitn(3, ' ').collect();
     if parts.len() < 2 {
-        return Err("invalid HTTP status line".to_string());
+        return Err(LlmError::Parse("invalid HTTP status line".to_string()));
     }
     let code: u16 = parts[1]
         .parse()
-        .map_err(|_| "invalid HTTP status code".to_string())?;
+        .map_err(|_| LlmError::Parse("invalid HTTP status code".to_string()))?;
     if (200..300).contains(&code) {
         Ok(code)
     } else {
-        let reason = if parts.len() >= 3 {
-            parts[2]
-        } else {
-            "Unknown"
-        };
-        Err(format!("HTTP error: {} {}", code, reason))
+        Err(LlmError::Http(code))
     }
 }
 
 /// Extract the first JSON object `{...}` from a string that may contain
 /// surrounding text.
+///
+/// A ```` ``` ```` code fence is stripped first. When the payload is a
+/// top-level array, its first object is returned.
 pub fn extract_first_json_object(s: &str) -> Option<&str> {
+    let s = strip_code_fence(s);
     let start = s.find('{')?;
+    if s[..start].contains('[') {
+        return first_balanced_object(&s[start..]);
+    }
     let end = s.rfind('}')?;
     if end < start {
         return None;
@@ -136,6 +481,52 @@ pub fn extract_first_json_object(s: &str) -> Option<&str> {
     Some(&s[start..=end])
 }
 
+/// Return the contents of the first ```` ``` ```` fence (minus its language
+/// tag line), or the input unchanged if there is no complete fence.
+fn strip_code_fence(s: &str) -> &str {
+    let Some(open) = s.find("```") else {
+        return s;
+    };
+    let after = &s[open + 3..];
+    let body_start = after.find('\n').map(|i| i + 1).unwrap_or(after.len());
+    let body = &after[body_start..];
+    match body.find("```") {
+        Some(close) => &body[..close],
+        None => s,
+    }
+}
+
+/// Slice the object starting at `s[0] == '{'` up to its matching brace,
+/// skipping braces inside string literals.
+fn first_balanced_object(s: &str) -> Option<&str> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' => depth += 1,
+            '}' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(&s[..=i]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
 /// Clamp a choice index to valid bounds for the given number of options.
 pub fn clamp_choice(choice: usize, len: usize) -> usize {
     if len == 0 {
@@ -145,37 +536,72 @@ pub fn clamp_choice(choice: usize, len: usize) -> usize {
     }
 }
 
+/// Words that introduce the option index in free text ("I pick option 2").
+const CHOICE_KEYWORDS: [&str; 5] = ["choice", "option", "pick", "index", "choose"];
+
+/// Confidence reported for a choice recovered by the bare integer scan,
+/// which may have picked up an unrelated number in free text.
+pub const BARE_SCAN_CONFIDENCE: f32 = 0.5;
+
 /// Extract a choice index from an LLM response using multiple strategies:
 /// 1. JSON with integer choice field
 /// 2. JSON with string choice field
-/// 3. Bare integer scan
+/// 3. Bare integer scan: a number right after a keyword like "option" or
+///    "choice", else the first number in the text
 ///
 /// The result is clamped to valid bounds for the given number of options.
-pub fn extract_choice(response: &str, options_len: usize) -> Result<usize, String> {
-    // Strategy 1: Try JSON with integer choice: {"choice": 2}
+/// A blank response is [`LlmError::EmptyResponse`]; one without any number
+/// is [`LlmError::NoChoice`].
+pub fn extract_choice(response: &str, options_len: usize) -> Result<usize, LlmError> {
+    extract_choice_with_confidence(response, options_len).map(|(choice, _)| choice)
+}
+
+/// Like [`extract_choice`], also returning how much to trust the parse:
+/// 1.0 when the choice came from JSON, [`BARE_SCAN_CONFIDENCE`] when it
+/// came from the bare integer scan.
+pub fn extract_choice_with_confidence(
+    response: &str,
+    options_len: usize,
+) -> Result<(usize, f32), LlmError> {
+    if response.trim().is_empty() {
+        return Err(LlmError::EmptyResponse);
+    }
+    // Strategies 1 and 2: JSON with an integer or string choice:
+    // {"choice": 2} or {"choice": "2"}
     if let Some(json_str) = extract_first_json_object(response) {
-        if let Ok(parsed) = serde_json::from_str::<ChoiceJson>(json_str) {
-            return Ok(clamp_choice(parsed.choice, options_len));
-        }
-        // Strategy 2: Try JSON with string choice: {"choice": "2"}
         if let Ok(v) = serde_json::from_str::<serde_json::Value>(json_str) {
-            if let Some(choice_str) = v.get("choice").and_then(|c| c.as_str()) {
-                if let Ok(n) = choice_str.parse::<usize>() {
-                    return Ok(clamp_choice(n, options_len));
-                }
+            let choice = v.get("choice").and_then(|c| match c {
+                serde_json::Value::Number(n) => n.as_u64().map(|n| n as usize),
+                serde_json::Value::String(s) => s.parse::<usize>().ok(),
+                _ => None,
+            });
+            if let Some(n) = choice {
+                return Ok((clamp_choice(n, options_len), 1.0));
             }
         }
     }
-    // Strategy 3: Bare integer scan - find first digit
-    for word in response.split_whitespace() {
-        if let Ok(n) = word
-            .trim_matches(|c: char| !c.is_ascii_digit())
+    // Strategy 3: Bare integer scan - prefer "option 2" over stray numbers
+    // such as "Round 12", then fall back to the first number
+    let number = |word: &str| {
+        word.trim_matches(|c: char| !c.is_ascii_digit())
             .parse::<usize>()
-        {
-            return Ok(clamp_choice(n, options_len));
+            .ok()
+    };
+    let words: Vec<&str> = response.split_whitespace().collect();
+    let after_keyword = words.windows(2).find_map(|pair| {
+        let keyword = pair[0]
+            .trim_matches(|c: char| !c.is_alphanumeric())
+            .to_ascii_lowercase();
+        if CHOICE_KEYWORDS.contains(&keyword.as_str()) {
+            number(pair[1])
+        } else {
+            None
         }
+    });
+    match after_keyword.or_else(|| words.iter().find_map(|word| number(word))) {
+        Some(n) => Ok((clamp_choice(n, options_len), BARE_SCAN_CONFIDENCE)),
+        None => Err(LlmError::NoChoice),
     }
-    Err("no valid choice found in response".to_string())
 }
 
 /// Check if an Ollama instance is reachable at the given host (host:port style).
@@ -197,13 +623,13 @@ pub fn can_connect(host: &str) -> bool {
     TcpStream::connect_timeout(&addr, Duration::from_millis(300)).is_ok()
 }
 
-/// Check if an HTTP endpoint (http only) is reachable.
+/// Check if an HTTP(S) endpoint accepts TCP connections.
 pub fn can_connect_http(url: &str) -> bool {
     use std::net::{TcpStream, ToSocketAddrs};
     use std::time::Duration;
 
     let parsed = match parse_http_url(url) {
-        Ok((h, p, _)) => (h, p),
+        Ok((h, p, _, _)) => (h, p),
         Err(_) => return false,
     };
     let addr = match (parsed.0.as_str(), parsed.1).to_socket_addrs() {
@@ -224,39 +650,127 @@ pub fn can_connect_llm(cfg: &OllamaConfig) -> bool {
     }
 }
 
-/// Send a generate request to Ollama and return the response text.
-///
-/// Applies connection timeout (5s), read/write timeouts (30s), buffer limit (1MB),
-/// and validates HTTP status code.
-pub fn ollama_generate(host: &str, model: &str, prompt: &str) -> Result<String, String> {
-    use std::io::{Read, Write};
+/// A connected request stream, plain TCP or TLS-wrapped.
+trait HttpStream: std::io::Read + std::io::Write {}
+
+impl<T: std::io::Read + std::io::Write> HttpStream for T {}
+
+/// Open a stream to `hostname:port` with the client's timeouts (5s connect,
+/// 30s read/write), wrapping it in TLS for [`Scheme::Https`].
+fn connect(scheme: Scheme, hostname: &str, port: u16) -> Result<Box<dyn HttpStream>, LlmError> {
     use std::net::{TcpStream, ToSocketAddrs};
     use std::time::Duration;
 
-    let (hostname, port) = parse_host(host)?;
-
-    let addr = (hostname.as_str(), port)
+    let addr = (hostname, port)
         .to_socket_addrs()
-        .map_err(|_| "failed to resolve host".to_string())?
+        .map_err(|_| LlmError::Connect)?
         .next()
-        .ok_or_else(|| "failed to resolve host".to_string())?;
-
-    let body = serde_json::json!({
-        "model": model,
-        "prompt": prompt,
-        "stream": false
-    })
-    .to_string();
+        .ok_or(LlmError::Connect)?;
 
-    let mut stream = TcpStream::connect_timeout(&addr, Duration::from_secs(5))
-        .map_err(|_| "connection failed".to_string())?;
+    let stream =
+        TcpStream::connect_timeout(&addr, Duration::from_secs(5)).map_err(LlmError::from_io)?;
 
     stream
         .set_read_timeout(Some(Duration::from_secs(30)))
-        .map_err(|_| "failed to set read timeout".to_string())?;
+        .map_err(LlmError::from_io)?;
     stream
         .set_write_timeout(Some(Duration::from_secs(30)))
-        .map_err(|_| "failed to set write timeout".to_string())?;
+        .map_err(LlmError::from_io)?;
+
+    match scheme {
+        Scheme::Http => Ok(Box::new(stream)),
+        Scheme::Https => tls_wrap(hostname, stream),
+    }
+}
+
+#[cfg(feature = "tls")]
+fn tls_wrap(hostname: &str, stream: std::net::TcpStream) -> Result<Box<dyn HttpStream>, LlmError> {
+    use rustls::pki_types::ServerName;
+    use std::sync::Arc;
+
+    let roots = rustls::RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+    };
+    let config = rustls::ClientConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_safe_default_protocol_versions()
+    .map_err(|e| LlmError::Config(format!("tls setup failed: {}", e)))?
+    .with_root_certificates(roots)
+    .with_no_client_auth();
+    let server_name = ServerName::try_from(hostname.to_string())
+        .map_err(|_| LlmError::Config("invalid tls server name".to_string()))?;
+    let conn = rustls::ClientConnection::new(Arc::new(config), server_name)
+        .map_err(|e| LlmError::Config(format!("tls setup failed: {}", e)))?;
+    Ok(Box::new(rustls::StreamOwned::new(conn, stream)))
+}
+
+#[cfg(not(feature = "tls"))]
+fn tls_wrap(
+    _hostname: &str,
+    _stream: std::net::TcpStream,
+) -> Result<Box<dyn HttpStream>, LlmError> {
+    Err(LlmError::Config(
+        "https requires the `tls` feature".to_string(),
+    ))
+}
+
+/// Read a whole response (up to `limit` bytes) from a `Connection: close`
+/// request.
+///
+/// Servers that drop a TLS connection without `close_notify` end the read
+/// with `UnexpectedEof`; that still counts as the end of the response once
+/// some bytes have arrived.
+fn read_response(stream: &mut dyn HttpStream, limit: u64) -> Result<String, LlmError> {
+    use std::io::{ErrorKind, Read};
+
+    let mut raw = Vec::new();
+    match stream.take(limit).read_to_end(&mut raw) {
+        Ok(_) => {}
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof && !raw.is_empty() => {}
+        Err(e) => return Err(LlmError::from_io(e)),
+    }
+    if raw.is_empty() {
+        return Err(LlmError::EmptyResponse);
+    }
+    String::from_utf8(raw).map_err(|_| LlmError::Parse("response is not UTF-8".to_string()))
+}
+
+/// Send a generate request to Ollama and return the response text.
+///
+/// Collects the chunks of [`ollama_generate_stream`], so it shares its
+/// timeouts, buffer limit and HTTP status validation.
+pub fn ollama_generate(host: &str, model: &str, prompt: &str) -> Result<String, LlmError> {
+    ollama_generate_stream(host, model, prompt, &mut |_| {})
+}
+
+/// Send a streaming generate request to Ollama, calling `on_token` with each
+/// chunk of text as it arrives, and return the full response text.
+///
+/// Applies connection timeout (5s), read/write timeouts (30s), buffer limit (1MB),
+/// and validates HTTP status code. Reads until a chunk reports `done`.
+pub fn ollama_generate_stream(
+    host: &str,
+    model: &str,
+    prompt: &str,
+    on_token: &mut dyn FnMut(&str),
+) -> Result<String, LlmError> {
+    ollama_stream_sampled(host, model, prompt, Sampling::default(), on_token)
+}
+
+fn ollama_stream_sampled(
+    host: &str,
+    model: &str,
+    prompt: &str,
+    sampling: Sampling,
+    on_token: &mut dyn FnMut(&str),
+) -> Result<String, LlmError> {
+    use std::io::{BufRead, BufReader, Read, Write};
+
+    let (hostname, port) = parse_host(host).map_err(LlmError::Config)?;
+    let mut stream = connect(Scheme::of(host), &hostname, port)?;
+
+    let body = ollama_generate_body(model, prompt, sampling).to_string();
 
     let req = format!(
         "POST /api/generate HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
@@ -266,30 +780,142 @@ pub fn ollama_generate(host: &str, model: &str, prompt: &str) -> Result<String,
     );
     stream
         .write_all(req.as_bytes())
-        .map_err(|_| "write failed".to_string())?;
+        .map_err(LlmError::from_io)?;
 
-    let mut raw = String::new();
-    stream
-        .take(1_048_576)
-        .read_to_string(&mut raw)
-        .map_err(|_| "read failed".to_string())?;
+    let mut reader = BufReader::new(stream.take(1_048_576));
 
     // Validate HTTP status from the first line
-    let first_line = raw
-        .lines()
-        .next()
-        .ok_or_else(|| "empty response".to_string())?;
-    parse_http_status(first_line)?;
+    let mut line = String::new();
+    reader.read_line(&mut line).map_err(LlmError::from_io)?;
+    if line.is_empty() {
+        return Err(LlmError::EmptyResponse);
+    }
+    parse_http_status(line.trim_end())?;
 
-    let (_, body_str) = raw.split_once("\r\n\r\n").ok_or("invalid http response")?;
+    let mut is_chunked = false;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).map_err(LlmError::from_io)? == 0 {
+            return Err(LlmError::Parse("invalid http response".to_string()));
+        }
+        let header = line.trim_end().to_ascii_lowercase();
+        if header.is_empty() {
+            break;
+        }
+        if header.starts_with("transfer-encoding:") && header.contains("chunked") {
+            is_chunked = true;
+        }
+    }
 
-    let v: serde_json::Value = serde_json::from_str(body_str).map_err(|e| e.to_string())?;
-    let resp = v
-        .get("response")
-        .and_then(|x| x.as_str())
-        .ok_or("missing response field")?;
+    if is_chunked {
+        read_ndjson_stream(&mut BufReader::new(ChunkedReader::new(reader)), on_token)
+    } else {
+        read_ndjson_stream(&mut reader, on_token)
+    }
+}
+
+/// Request body for a streaming `/api/generate` call, with sampling settings
+/// in `options`.
+fn ollama_generate_body(model: &str, prompt: &str, sampling: Sampling) -> serde_json::Value {
+    let mut options = serde_json::json!({ "temperature": sampling.temperature });
+    if let Some(seed) = sampling.seed {
+        options["seed"] = seed.into();
+    }
+    serde_json::json!({
+        "model": model,
+        "prompt": prompt,
+        "stream": true,
+        "options": options
+    })
+}
 
-    Ok(resp.to_string())
+/// Read Ollama's newline-delimited JSON stream, passing each non-empty
+/// `response` chunk to `on_token`, until an object reports `done: true`.
+/// Returns the concatenated text.
+fn read_ndjson_stream(
+    body: &mut dyn std::io::BufRead,
+    on_token: &mut dyn FnMut(&str),
+) -> Result<String, LlmError> {
+    let mut out = String::new();
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if body.read_line(&mut line).map_err(LlmError::from_io)? == 0 {
+            return Err(LlmError::Parse("stream ended before done".to_string()));
+        }
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let v: serde_json::Value =
+            serde_json::from_str(line).map_err(|e| LlmError::Parse(e.to_string()))?;
+        if let Some(error) = v.get("error").and_then(|x| x.as_str()) {
+            return Err(LlmError::Parse(error.to_string()));
+        }
+        if let Some(token) = v.get("response").and_then(|x| x.as_str()) {
+            if !token.is_empty() {
+                on_token(token);
+                out.push_str(token);
+            }
+        }
+        if v.get("done").and_then(|x| x.as_bool()) == Some(true) {
+            return Ok(out);
+        }
+    }
+}
+
+/// Incremental decoder for a `Transfer-Encoding: chunked` body, so a
+/// stream can be consumed as it arrives.
+struct ChunkedReader<R> {
+    inner: R,
+    remaining: usize,
+    done: bool,
+}
+
+impl<R> ChunkedReader<R> {
+    fn new(inner: R) -> Self {
+        Self {
+            inner,
+            remaining: 0,
+            done: false,
+        }
+    }
+}
+
+impl<R: std::io::BufRead> std::io::Read for ChunkedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        use std::io::{Error, ErrorKind};
+
+        if self.done || buf.is_empty() {
+            return Ok(0);
+        }
+        if self.remaining == 0 {
+            let mut size_line = String::new();
+            // Skip the CRLF that ends the previous chunk's data
+            while size_line.trim().is_empty() {
+                size_line.clear();
+                if self.inner.read_line(&mut size_line)? == 0 {
+                    self.done = true;
+                    return Ok(0);
+                }
+            }
+            let size_hex = size_line.split(';').next().unwrap_or("").trim();
+            let size = usize::from_str_radix(size_hex, 16)
+                .map_err(|_| Error::new(ErrorKind::InvalidData, "invalid chunk size"))?;
+            if size == 0 {
+                self.done = true;
+                return Ok(0);
+            }
+            self.remaining = size;
+        }
+        let len = buf.len().min(self.remaining);
+        let read = self.inner.read(&mut buf[..len])?;
+        if read == 0 {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "chunk cut short"));
+        }
+        self.remaining -= read;
+        Ok(read)
+    }
 }
 
 fn normalize_openai_path_prefix(prefix: &str) -> String {
@@ -312,47 +938,18 @@ fn build_openai_chat_path(prefix: &str) -> String {
     }
 }
 
-fn decode_chunked(body: &str) -> Result<String, String> {
-    // Very small, non-streaming chunked decoder for JSON bodies.
+/// Decode a complete `Transfer-Encoding: chunked` body through
+/// [`ChunkedReader`]. Chunk extensions (`size;name=value`) are ignored.
+pub(crate) fn decode_chunked(body: &str) -> Result<String, LlmError> {
     let mut out = String::new();
-    let mut i = 0;
-    let bytes = body.as_bytes();
-
-    while i < bytes.len() {
-        // Read chunk size line
-        let mut j = i;
-        while j + 1 < bytes.len() && !(bytes[j] == b'\r' && bytes[j + 1] == b'\n') {
-            j += 1;
-        }
-        if j + 1 >= bytes.len() {
-            return Err("invalid chunked encoding".to_string());
-        }
-        let size_line = &body[i..j];
-        let size_hex = size_line.split(';').next().unwrap_or("").trim();
-        let size =
-            usize::from_str_radix(size_hex, 16).map_err(|_| "invalid chunk size".to_string())?;
-        i = j + 2; // skip \r\n
-
-        if size == 0 {
-            break;
-        }
-        if i + size > bytes.len() {
-            return Err("chunk exceeds body length".to_string());
-        }
-        out.push_str(&body[i..i + size]);
-        i += size;
-
-        // skip trailing \r\n
-        if i + 1 < bytes.len() && bytes[i] == b'\r' && bytes[i + 1] == b'\n' {
-            i += 2;
-        }
-    }
-
+    std::io::Read::read_to_string(&mut ChunkedReader::new(body.as_bytes()), &mut out)
+        .map_err(|e| LlmError::Parse(e.to_string()))?;
     Ok(out)
 }
 
-fn openai_extract_content(body_str: &str) -> Result<String, String> {
-    let v: serde_json::Value = serde_json::from_str(body_str).map_err(|e| e.to_string())?;
+fn openai_extract_content(body_str: &str) -> Result<String, LlmError> {
+    let v: serde_json::Value =
+        serde_json::from_str(body_str).map_err(|e| LlmError::Parse(e.to_string()))?;
     // Chat completions: choices[0].message.content
     if let Some(s) = v
         .get("choices")
@@ -372,7 +969,32 @@ fn openai_extract_content(body_str: &str) -> Result<String, String> {
     {
         return Ok(s.to_string());
     }
-    Err("missing choices[0] content".to_string())
+    Err(LlmError::Parse("missing choices[0] content".to_string()))
+}
+
+/// Request body for a Chat Completions call: an optional system message,
+/// then the user message.
+fn openai_chat_body(
+    model: &str,
+    system: Option<&str>,
+    user: &str,
+    sampling: Sampling,
+) -> serde_json::Value {
+    let mut messages = Vec::new();
+    if let Some(system) = system {
+        messages.push(serde_json::json!({"role": "system", "content": system}));
+    }
+    messages.push(serde_json::json!({"role": "user", "content": user}));
+    let mut body = serde_json::json!({
+        "model": model,
+        "messages": messages,
+        "stream": false,
+        "temperature": sampling.temperature
+    });
+    if let Some(seed) = sampling.seed {
+        body["seed"] = seed.into();
+    }
+    body
 }
 
 /// Send a Chat Completions request to an OpenAI-compatible endpoint (LM Studio).
@@ -383,39 +1005,38 @@ pub fn openai_chat_generate(
     api_key: Option<&str>,
     model: &str,
     prompt: &str,
-) -> Result<String, String> {
-    use std::io::{Read, Write};
-    use std::net::{TcpStream, ToSocketAddrs};
-    use std::time::Duration;
+) -> Result<String, LlmError> {
+    openai_chat_generate_with_system(base_url, api_key, model, None, prompt)
+}
 
-    let (hostname, port, prefix) = parse_http_url(base_url)?;
-    let path = build_openai_chat_path(&prefix);
+/// Like [`openai_chat_generate`], sending `system` (when given) as a system
+/// message ahead of the `user` message.
+pub fn openai_chat_generate_with_system(
+    base_url: &str,
+    api_key: Option<&str>,
+    model: &str,
+    system: Option<&str>,
+    user: &str,
+) -> Result<String, LlmError> {
+    openai_chat_sampled(base_url, api_key, model, system, user, Sampling::default())
+}
 
-    let addr = (hostname.as_str(), port)
-        .to_socket_addrs()
-        .map_err(|_| "failed to resolve host".to_string())?
-        .next()
-        .ok_or_else(|| "failed to resolve host".to_string())?;
+fn openai_chat_sampled(
+    base_url: &str,
+    api_key: Option<&str>,
+    model: &str,
+    system: Option<&str>,
+    user: &str,
+    sampling: Sampling,
+) -> Result<String, LlmError> {
+    use std::io::Write;
 
-    let body = serde_json::json!({
-        "model": model,
-        "messages": [
-            {"role": "user", "content": prompt}
-        ],
-        "stream": false,
-        "temperature": 0
-    })
-    .to_string();
+    let (hostname, port, prefix, scheme) = parse_http_url(base_url).map_err(LlmError::Config)?;
+    let path = build_openai_chat_path(&prefix);
 
-    let mut stream = TcpStream::connect_timeout(&addr, Duration::from_secs(5))
-        .map_err(|_| "connection failed".to_string())?;
+    let body = openai_chat_body(model, system, user, sampling).to_string();
 
-    stream
-        .set_read_timeout(Some(Duration::from_secs(30)))
-        .map_err(|_| "failed to set read timeout".to_string())?;
-    stream
-        .set_write_timeout(Some(Duration::from_secs(30)))
-        .map_err(|_| "failed to set write timeout".to_string())?;
+    let mut stream = connect(scheme, &hostname, port)?;
 
     let mut auth_header = String::new();
     if let Some(k) = api_key {
@@ -435,20 +1056,15 @@ pub fn openai_chat_generate(
     );
     stream
         .write_all(req.as_bytes())
-        .map_err(|_| "write failed".to_string())?;
+        .map_err(LlmError::from_io)?;
 
-    let mut raw = String::new();
-    stream
-        .take(2_097_152)
-        .read_to_string(&mut raw)
-        .map_err(|_| "read failed".to_string())?;
+    let raw = read_response(&mut *stream, 2_097_152)?;
 
-    let (hdrs, body_str) = raw.split_once("\r\n\r\n").ok_or("invalid http response")?;
+    let (hdrs, body_str) = raw
+        .split_once("\r\n\r\n")
+        .ok_or_else(|| LlmError::Parse("invalid http response".to_string()))?;
 
-    let first_line = hdrs
-        .lines()
-        .next()
-        .ok_or_else(|| "empty response".to_string())?;
+    let first_line = hdrs.lines().next().ok_or(LlmError::EmptyResponse)?;
     parse_http_status(first_line)?;
 
     let is_chunked = hdrs.lines().any(|l| {
@@ -466,28 +1082,94 @@ pub fn openai_chat_generate(
 }
 
 /// Generate a response using either Ollama or an OpenAI-compatible endpoint.
-pub fn llm_generate(cfg: &OllamaConfig, prompt: &str) -> Result<String, String> {
-    match cfg.api {
-        LlmApi::Ollama => ollama_generate(&cfg.host, &cfg.model, prompt),
-        LlmApi::OpenAiChatCompletions => {
-            openai_chat_generate(&cfg.host, cfg.api_key.as_deref(), &cfg.model, prompt)
+///
+/// Connection failures and timeouts are retried up to `cfg.retries` times
+/// with exponential backoff (`cfg.backoff`, then twice that, ...); other
+/// errors are returned at once. With `cfg.cache` set, a prompt already
+/// answered with the same host, API, model and sampling settings is served
+/// from the cache.
+pub fn llm_generate(cfg: &OllamaConfig, prompt: &str) -> Result<String, LlmError> {
+    let transport = transport_of(cfg);
+    cached(cfg, None, prompt, || {
+        with_retries(cfg.retries, cfg.backoff, || transport.generate(cfg, prompt))
+    })
+}
+
+/// Like [`llm_generate`] with the prompt split into a system and a user part
+/// (see [`build_system_and_user`]): OpenAI-compatible endpoints get them as
+/// separate messages, while Ollama gets them joined into the same text as
+/// [`build_galactic_prompt`].
+pub fn llm_generate_with_system(
+    cfg: &OllamaConfig,
+    system: &str,
+    user: &str,
+) -> Result<String, LlmError> {
+    let transport = transport_of(cfg);
+    cached(cfg, Some(system), user, || {
+        with_retries(cfg.retries, cfg.backoff, || {
+            transport.generate_with_system(cfg, system, user)
+        })
+    })
+}
+
+/// Run `attempt`, retrying transient failures up to `retries` times and
+/// sleeping `backoff * 2^n` before retry `n`.
+fn with_retries<T>(
+    retries: u8,
+    backoff: Duration,
+    mut attempt: impl FnMut() -> Result<T, LlmError>,
+) -> Result<T, LlmError> {
+    let mut delay = backoff;
+    let mut left = retries;
+    loop {
+        match attempt() {
+            Err(e) if e.is_transient() && left > 0 => {
+                left -= 1;
+                if !delay.is_zero() {
+                    std::thread::sleep(delay);
+                }
+                delay = delay.saturating_mul(2);
+            }
+            result => return result,
         }
     }
 }
 
 /// Choose among options using either Ollama or an OpenAI-compatible endpoint.
-pub fn llm_choose(cfg: &OllamaConfig, prompt: &str, options_len: usize) -> Result<usize, String> {
+pub fn llm_choose(cfg: &OllamaConfig, prompt: &str, options_len: usize) -> Result<usize, LlmError> {
     let response = llm_generate(cfg, prompt)?;
     extract_choice(&response, options_len)
 }
 
+/// Like [`llm_choose`] for a prompt split by [`build_system_and_user`].
+pub fn llm_choose_with_system(
+    cfg: &OllamaConfig,
+    system: &str,
+    user: &str,
+    options_len: usize,
+) -> Result<usize, LlmError> {
+    let response = llm_generate_with_system(cfg, system, user)?;
+    extract_choice(&response, options_len)
+}
+
+/// Like [`llm_choose`], also returning the parse confidence from
+/// [`extract_choice_with_confidence`].
+pub fn llm_choose_with_confidence(
+    cfg: &OllamaConfig,
+    prompt: &str,
+    options_len: usize,
+) -> Result<(usize, f32), LlmError> {
+    let response = llm_generate(cfg, prompt)?;
+    extract_choice_with_confidence(&response, options_len)
+}
+
 /// Deliberate (comment + preferred choice) using either backend.
 pub fn llm_deliberate(
     cfg: &OllamaConfig,
     personality: &str,
     event: &Event,
     galaxy: &GalaxyState,
-) -> Result<(usize, String), String> {
+) -> Result<(usize, String), LlmError> {
     let prompt = build_deliberation_prompt(personality, event, galaxy);
     let response = llm_generate(cfg, &prompt)?;
     let choice = extract_choice(&response, event.options.len())?;
@@ -504,7 +1186,7 @@ pub fn ollama_choose(
     model: &str,
     prompt: &str,
     options_len: usize,
-) -> Result<usize, String> {
+) -> Result<usize, LlmError> {
     let response = ollama_generate(host, model, prompt)?;
     extract_choice(&response, options_len)
 }
@@ -518,7 +1200,7 @@ pub fn ollama_deliberate(
     personality: &str,
     event: &Event,
     galaxy: &GalaxyState,
-) -> Result<(usize, String), String> {
+) -> Result<(usize, String), LlmError> {
     let prompt = build_deliberation_prompt(personality, event, galaxy);
     let response = ollama_generate(host, model, &prompt)?;
     let choice = extract_choice(&response, event.options.len())?;
@@ -527,76 +1209,135 @@ pub fn ollama_deliberate(
 }
 
 /// Build a galactic event prompt with a personality prefix.
+///
+/// This is the default [`PromptBuilder`] preset.
 pub fn build_galactic_prompt(personality: &str, event: &Event, galaxy: &GalaxyState) -> String {
-    let threats = galaxy
-        .threats
-        .iter()
-        .map(|t| format!("{}(sev={}, rounds={})", t.name, t.severity, t.rounds_active))
-        .collect::<Vec<_>>()
-        .join(", ");
-
-    let species = galaxy
-        .relations
-        .iter()
-        .map(|(n, r)| format!("{}={:?}", n, r))
-        .collect::<Vec<_>>()
-        .join(", ");
+    PromptBuilder::new()
+        .with_personality(personality)
+        .build(event, galaxy)
+}
 
-    let mut s = String::new();
-    s.push_str(personality);
-    s.push_str("\n\n");
-    s.push_str("You are participating as a council member in a galactic exploration simulation.\n");
-    s.push_str("Your task: pick the best option index for the council, given the event and galaxy state.\n");
-    s.push_str("Return ONLY a JSON object: {\"choice\": <integer>, \"reason\": <short string>}\n");
-    s.push_str("Do not include any other text.\n\n");
+/// Split the [`build_galactic_prompt`] text for chat models: the personality
+/// and JSON-output instructions as the system message, the galaxy state,
+/// event and options as the user message.
+pub fn build_system_and_user(
+    personality: &str,
+    event: &Event,
+    galaxy: &GalaxyState,
+) -> (String, String) {
+    PromptBuilder::new()
+        .with_personality(personality)
+        .build_system_and_user(event, galaxy)
+}
 
-    s.push_str(&format!("ROUND: {}\n", galaxy.round));
-    s.push_str(&format!("SECTORS: {}\n", galaxy.explored_sectors.len()));
-    s.push_str(&format!("SPECIES: {}\n", galaxy.known_species.len()));
-    s.push_str(&format!(
-        "RELATIONS: {}\n",
-        if species.is_empty() {
-            "(none)"
-        } else {
-            &species
+/// Single-text form of a split prompt, as sent to models without roles.
+fn join_prompt(system: &str, user: &str) -> String {
+    format!("{}\n{}", system, user)
+}
+
+/// Composable builder for galactic voting prompts.
+///
+/// Sections can be toggled independently so experiments don't need their own
+/// copy of the prompt text. The defaults match [`build_galactic_prompt`].
+#[derive(Debug, Clone)]
+pub struct PromptBuilder {
+    personality: String,
+    threats: bool,
+    relations: bool,
+    option_outcomes: bool,
+}
+
+impl PromptBuilder {
+    /// Create a builder with threats and relations on and option outcomes off.
+    pub fn new() -> Self {
+        Self {
+            personality: String::new(),
+            threats: true,
+            relations: true,
+            option_outcomes: false,
         }
-    ));
-    s.push_str(&format!(
-        "THREATS: {}\n\n",
-        if threats.is_empty() {
-            "(none)"
-        } else {
-            &threats
+    }
+
+    /// Set the personality text that opens the prompt.
+    pub fn with_personality(mut self, personality: &str) -> Self {
+        self.personality = personality.to_string();
+        self
+    }
+
+    /// Include the "THREATS:" line.
+    pub fn with_threats(mut self, enabled: bool) -> Self {
+        self.threats = enabled;
+        self
+    }
+
+    /// Include the "RELATIONS:" line.
+    pub fn with_relations(mut self, enabled: bool) -> Self {
+        self.relations = enabled;
+        self
+    }
+
+    /// Show each option's outcome description and score change.
+    pub fn with_option_outcomes(mut self, enabled: bool) -> Self {
+        self.option_outcomes = enabled;
+        self
+    }
+
+    /// Render the prompt for an event.
+    pub fn build(&self, event: &Event, galaxy: &GalaxyState) -> String {
+        let (system, user) = self.build_system_and_user(event, galaxy);
+        join_prompt(&system, &user)
+    }
+
+    /// Render the prompt as `(system, user)` messages: the personality and
+    /// instructions, then the galaxy state, event and options.
+    pub fn build_system_and_user(&self, event: &Event, galaxy: &GalaxyState) -> (String, String) {
+        let mut system = String::new();
+        system.push_str(&self.personality);
+        system.push_str("\n\n");
+        system.push_str(
+            "You are participating as a council member in a galactic exploration simulation.\n",
+        );
+        system.push_str("Your task: pick the best option index for the council, given the event and galaxy state.\n");
+        system.push_str(
+            "Return ONLY a JSON object: {\"choice\": <integer>, \"reason\": <short string>}\n",
+        );
+        system.push_str("Do not include any other text.\n");
+
+        let mut user = String::new();
+        for line in galaxy.summary().lines() {
+            let hidden = (!self.relations && line.starts_with("RELATIONS:"))
+                || (!self.threats && line.starts_with("THREATS:"));
+            if !hidden {
+                user.push_str(line);
+                user.push('\n');
+            }
+        }
+        user.push('\n');
+
+        user.push_str("EVENT:\n");
+        user.push_str(&event.description);
+        user.push_str("\n\nOPTIONS:\n");
+        for (i, opt) in event.options.iter().enumerate() {
+            if self.option_outcomes {
+                user.push_str(&format!("{}: {}\n", i, opt.briefing()));
+            } else {
+                user.push_str(&format!("{}: {}\n", i, opt.description));
+            }
         }
-    ));
+        (system, user)
+    }
+}
 
-    s.push_str("EVENT:\n");
-    s.push_str(&event.description);
-    s.push_str("\n\nOPTIONS:\n");
-    for (i, opt) in event.options.iter().enumerate() {
-        s.push_str(&format!("{}: {}\n", i, opt.description));
+impl Default for PromptBuilder {
+    fn default() -> Self {
+        Self::new()
     }
-    s
 }
 
 /// Build a deliberation prompt used to generate a short council statement.
 ///
 /// The model should return ONLY JSON: {"choice": <int>, "comment": <short string>}.
 pub fn build_deliberation_prompt(personality: &str, event: &Event, galaxy: &GalaxyState) -> String {
-    let threats = galaxy
-        .threats
-        .iter()
-        .map(|t| format!("{}(sev={}, rounds={})", t.name, t.severity, t.rounds_active))
-        .collect::<Vec<_>>()
-        .join(", ");
-
-    let species = galaxy
-        .relations
-        .iter()
-        .map(|(n, r)| format!("{}={:?}", n, r))
-        .collect::<Vec<_>>()
-        .join(", ");
-
     let mut s = String::new();
     s.push_str(personality);
     s.push_str("\n\n");
@@ -605,25 +1346,8 @@ pub fn build_deliberation_prompt(personality: &str, event: &Event, galaxy: &Gala
     s.push_str("Return ONLY a JSON object: {\"choice\": <integer>, \"comment\": <short string>}\n");
     s.push_str("Do not include any other text.\n\n");
 
-    s.push_str(&format!("ROUND: {}\n", galaxy.round));
-    s.push_str(&format!("SECTORS: {}\n", galaxy.explored_sectors.len()));
-    s.push_str(&format!("SPECIES: {}\n", galaxy.known_species.len()));
-    s.push_str(&format!(
-        "RELATIONS: {}\n",
-        if species.is_empty() {
-            "(none)"
-        } else {
-            &species
-        }
-    ));
-    s.push_str(&format!(
-        "THREATS: {}\n\n",
-        if threats.is_empty() {
-            "(none)"
-        } else {
-            &threats
-        }
-    ));
+    s.push_str(&galaxy.summary());
+    s.push('\n');
 
     s.push_str("EVENT:\n");
     s.push_str(&event.description);
@@ -689,6 +1413,27 @@ mod tests {
         assert!(extract_first_json_object("no json here").is_none());
     }
 
+    #[test]
+    fn test_extract_json_fenced_object() {
+        let s = "Here you go:\n```json\n{\"choice\": 1}\n```\nHope that helps {not json}";
+        assert_eq!(extract_first_json_object(s), Some("{\"choice\": 1}"));
+    }
+
+    #[test]
+    fn test_extract_json_fenced_array() {
+        let s = "```\n[{\"choice\": 0, \"reason\": \"a}b\"}, {\"choice\": 2}]\n```";
+        assert_eq!(
+            extract_first_json_object(s),
+            Some("{\"choice\": 0, \"reason\": \"a}b\"}")
+        );
+    }
+
+    #[test]
+    fn test_extract_json_raw_array() {
+        let s = "[{\"choice\": 3}, {\"choice\": 1}]";
+        assert_eq!(extract_first_json_object(s), Some("{\"choice\": 3}"));
+    }
+
     #[test]
     fn test_clamp_choice_bounds() {
         assert_eq!(clamp_choice(0, 0), 0);
@@ -705,8 +1450,10 @@ mod tests {
                 outcome: Outcome {
                     description: format!("Outcome {}", i),
                     score_delta: 0,
+                    headline: None,
                     state_changes: vec![],
                 },
+                required_discovery: None,
             })
             .collect();
         Event {
@@ -751,6 +1498,156 @@ mod tests {
         assert!(prompt.contains("SECTORS:"));
     }
 
+    #[test]
+    fn test_prompt_builder_can_omit_relations() {
+        let event = make_test_event(2);
+        let mut galaxy = GalaxyState::new();
+        galaxy
+            .relations
+            .insert("Velari".to_string(), crate::galaxy::Relation::Friendly);
+
+        let full = PromptBuilder::new().build(&event, &galaxy);
+        assert!(full.contains("RELATIONS: Friendly 1 (Velari)"));
+
+        let prompt = PromptBuilder::new()
+            .with_relations(false)
+            .build(&event, &galaxy);
+        assert!(!prompt.contains("RELATIONS:"));
+        assert!(prompt.contains("THREATS:"));
+    }
+
+    #[test]
+    fn test_prompt_builder_preset_matches_build_galactic_prompt() {
+        let event = make_test_event(2);
+        let galaxy = GalaxyState::new();
+        let preset = build_galactic_prompt("Bold", &event, &galaxy);
+        let built = PromptBuilder::new()
+            .with_personality("Bold")
+            .build(&event, &galaxy);
+        assert_eq!(preset, built);
+        assert!(preset.contains("THREATS: (none)\n\nEVENT:"));
+    }
+
+    #[test]
+    fn system_and_user_split_joins_back_to_the_full_prompt() {
+        let event = make_test_event(2);
+        let galaxy = GalaxyState::new();
+        let (system, user) = build_system_and_user("Bold", &event, &galaxy);
+
+        assert!(system.starts_with("Bold\n\n"));
+        assert!(system.contains("Return ONLY a JSON object"));
+        assert!(!system.contains("EVENT:"));
+        assert!(user.contains("EVENT:\nA strange signal detected"));
+        assert!(user.contains("1: Option 1"));
+        assert_eq!(
+            join_prompt(&system, &user),
+            build_galactic_prompt("Bold", &event, &galaxy)
+        );
+    }
+
+    #[test]
+    fn request_bodies_carry_temperature_and_seed() {
+        let seeded = Sampling {
+            temperature: 0.7,
+            seed: Some(42),
+        };
+        let native = ollama_generate_body("llama3", "Pick one.", seeded);
+        assert_eq!(native["options"]["temperature"], 0.7f32 as f64);
+        assert_eq!(native["options"]["seed"], 42);
+        assert_eq!(native["stream"], true);
+        let chat = openai_chat_body("llama3", None, "Pick one.", seeded);
+        assert_eq!(chat["temperature"], 0.7f32 as f64);
+        assert_eq!(chat["seed"], 42);
+
+        let default = Sampling::default();
+        let native = ollama_generate_body("llama3", "Pick one.", default);
+        assert_eq!(native["options"]["temperature"], 0.0);
+        assert!(native["options"].get("seed").is_none());
+        let chat = openai_chat_body("llama3", None, "Pick one.", default);
+        assert_eq!(chat["temperature"], 0.0);
+        assert!(chat.get("seed").is_none());
+    }
+
+    #[test]
+    fn openai_body_sends_system_then_user_message() {
+        let body = openai_chat_body(
+            "llama3",
+            Some("Be terse."),
+            "Pick one.",
+            Sampling::default(),
+        );
+        let messages = body["messages"].as_array().unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0]["role"], "system");
+        assert_eq!(messages[0]["content"], "Be terse.");
+        assert_eq!(messages[1]["role"], "user");
+        assert_eq!(messages[1]["content"], "Pick one.");
+
+        let plain = openai_chat_body("llama3", None, "Pick one.", Sampling::default());
+        assert_eq!(plain["messages"].as_array().unwrap().len(), 1);
+        assert_eq!(plain["messages"][0]["role"], "user");
+    }
+
+    #[test]
+    fn test_prompt_builder_option_outcomes() {
+        let event = make_test_event(2);
+        let prompt = PromptBuilder::new()
+            .with_option_outcomes(true)
+            .with_threats(false)
+            .build(&event, &GalaxyState::new());
+        assert!(prompt.contains("0: Option 0 -> Outcome 0 (score +0)"));
+        assert!(!prompt.contains("THREATS:"));
+    }
+
+    #[test]
+    fn parse_http_url_keeps_the_scheme() {
+        assert_eq!(
+            parse_http_url("http://127.0.0.1:1234/v1").unwrap(),
+            (
+                "127.0.0.1".to_string(),
+                1234,
+                "/v1".to_string(),
+                Scheme::Http
+            )
+        );
+        assert_eq!(
+            parse_http_url("127.0.0.1:1234").unwrap(),
+            ("127.0.0.1".to_string(), 1234, String::new(), Scheme::Http)
+        );
+        assert_eq!(
+            parse_http_url("https://api.example.com/v1").unwrap(),
+            (
+                "api.example.com".to_string(),
+                443,
+                "/v1".to_string(),
+                Scheme::Https
+            )
+        );
+        assert_eq!(
+            parse_http_url("https://api.example.com:8443").unwrap(),
+            (
+                "api.example.com".to_string(),
+                8443,
+                String::new(),
+                Scheme::Https
+            )
+        );
+        assert!(parse_http_url("https://").is_err());
+    }
+
+    #[cfg(not(feature = "tls"))]
+    #[test]
+    fn https_without_tls_feature_is_an_error() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let err = connect(Scheme::Https, "127.0.0.1", port).err().unwrap();
+        assert_eq!(
+            err,
+            LlmError::Config("https requires the `tls` feature".to_string())
+        );
+        assert!(connect(Scheme::Http, "127.0.0.1", port).is_ok());
+    }
+
     // AC-1: parse_host() handles https:// prefix, empty string returns Err, port 0 valid
     #[test]
     fn test_parse_host_strips_https_prefix() {
@@ -759,6 +1656,14 @@ mod tests {
         assert_eq!(p, 8080);
     }
 
+    #[test]
+    fn test_parse_host_defaults_https_to_443() {
+        let (h, p) = parse_host("https://example.com").unwrap();
+        assert_eq!(h, "example.com");
+        assert_eq!(p, 443);
+        assert_eq!(parse_host("http://example.com").unwrap().1, 11434);
+    }
+
     #[test]
     fn test_parse_host_empty_string_is_err() {
         assert!(parse_host("").is_err());
@@ -771,6 +1676,283 @@ mod tests {
         assert_eq!(p, 0);
     }
 
+    #[test]
+    fn cache_hits_the_transport_once_per_request() {
+        let mock = Arc::new(MockTransport::new(["a", "b", "c", "d", "e", "f", "g"]));
+        let cfg = OllamaConfig {
+            cache: Some(Arc::new(LlmCache::new())),
+            ..mock_config(&mock)
+        };
+
+        assert_eq!(llm_generate(&cfg, "Pick one.").unwrap(), "a");
+        assert_eq!(llm_generate(&cfg, "Pick one.").unwrap(), "a");
+        assert_eq!(mock.prompts().len(), 1);
+
+        assert_eq!(llm_generate(&cfg, "Pick two.").unwrap(), "b");
+        let warmer = OllamaConfig {
+            temperature: 0.7,
+            ..cfg.clone()
+        };
+        assert_eq!(llm_generate(&warmer, "Pick one.").unwrap(), "c");
+        let seeded = OllamaConfig {
+            seed: Some(7),
+            ..cfg.clone()
+        };
+        assert_eq!(llm_generate(&seeded, "Pick one.").unwrap(), "d");
+        let other_model = OllamaConfig {
+            model: "mistral".to_string(),
+            ..cfg.clone()
+        };
+        assert_eq!(llm_generate(&other_model, "Pick one.").unwrap(), "e");
+        let other_host = OllamaConfig {
+            host: "127.0.0.1:2".to_string(),
+            ..cfg.clone()
+        };
+        assert_eq!(llm_generate(&other_host, "Pick one.").unwrap(), "f");
+        let other_api = OllamaConfig {
+            api: LlmApi::OpenAiChatCompletions,
+            ..cfg.clone()
+        };
+        assert_eq!(llm_generate(&other_api, "Pick one.").unwrap(), "g");
+        assert_eq!(mock.prompts().len(), 7);
+        assert_eq!(cfg.cache.as_ref().unwrap().len(), 7);
+
+        assert_eq!(llm_generate(&cfg, "Pick one.").unwrap(), "a");
+        assert_eq!(mock.prompts().len(), 7);
+
+        let uncached = OllamaConfig { cache: None, ..cfg };
+        llm_generate(&uncached, "Pick one.").unwrap();
+        assert_eq!(mock.prompts().len(), 8);
+    }
+
+    #[test]
+    fn cache_tells_system_prompts_from_joined_ones() {
+        let mock = Arc::new(MockTransport::new(["joined", "split"]));
+        let cfg = OllamaConfig {
+            cache: Some(Arc::new(LlmCache::new())),
+            ..mock_config(&mock)
+        };
+
+        let joined = llm_generate(&cfg, &join_prompt("Be brief.", "Pick one.")).unwrap();
+        let split = llm_generate_with_system(&cfg, "Be brief.", "Pick one.").unwrap();
+        assert_eq!((joined.as_str(), split.as_str()), ("joined", "split"));
+        assert_eq!(
+            llm_generate_with_system(&cfg, "Be brief.", "Pick one.").unwrap(),
+            "split"
+        );
+        assert_eq!(mock.prompts().len(), 2);
+    }
+
+    #[test]
+    fn cache_skips_failed_requests() {
+        let mock = Arc::new(MockTransport::with_results([
+            Err(LlmError::Connect),
+            Ok("ok".to_string()),
+        ]));
+        let cfg = OllamaConfig {
+            cache: Some(Arc::new(LlmCache::new())),
+            ..mock_config(&mock)
+        };
+
+        assert_eq!(llm_generate(&cfg, "Pick one."), Err(LlmError::Connect));
+        assert!(cfg.cache.as_ref().unwrap().is_empty());
+        assert_eq!(llm_generate(&cfg, "Pick one.").unwrap(), "ok");
+        assert_eq!(cfg.cache.as_ref().unwrap().len(), 1);
+    }
+
+    fn mock_config(mock: &Arc<MockTransport>) -> OllamaConfig {
+        OllamaConfig {
+            transport: Some(mock.clone()),
+            ..OllamaConfig::new("127.0.0.1:1", "llama3", LlmApi::Ollama)
+        }
+    }
+
+    #[test]
+    fn llm_choose_runs_through_mock_transport() {
+        let mock = Arc::new(MockTransport::new([
+            "```json\n{\"choice\": 2, \"reason\": \"safest\"}\n```",
+            "I pick option 9",
+        ]));
+        let cfg = mock_config(&mock);
+
+        assert_eq!(llm_choose(&cfg, "Pick one.", 3), Ok(2));
+        assert_eq!(llm_choose(&cfg, "Pick again.", 3), Ok(2));
+        assert_eq!(llm_choose(&cfg, "Once more.", 3), Ok(2));
+        assert_eq!(mock.prompts(), ["Pick one.", "Pick again.", "Once more."]);
+
+        let joined = llm_choose_with_system(&cfg, "Be brief.", "Pick.", 3);
+        assert_eq!(joined, Ok(2));
+        assert_eq!(mock.prompts().last().unwrap(), "Be brief.\nPick.");
+    }
+
+    #[test]
+    fn llm_deliberate_runs_through_mock_transport() {
+        let mock = Arc::new(MockTransport::new([
+            "{\"choice\": 1, \"comment\": \"Hold the line.\"}",
+        ]));
+        let cfg = mock_config(&mock);
+        let event = Event {
+            description: "Raiders approach".to_string(),
+            relevant_expertise: vec![],
+            options: ["Fight", "Flee"]
+                .iter()
+                .map(|d| ResponseOption {
+                    description: d.to_string(),
+                    outcome: Outcome {
+                        description: String::new(),
+                        score_delta: 0,
+                        headline: None,
+                        state_changes: vec![],
+                    },
+                    required_discovery: None,
+                })
+                .collect(),
+        };
+        let result = llm_deliberate(&cfg, "You are calm.", &event, &GalaxyState::new());
+        assert_eq!(result, Ok((1, "Hold the line.".to_string())));
+        assert!(mock.prompts()[0].contains("Raiders approach"));
+    }
+
+    #[test]
+    fn mock_transport_failures_go_through_retries() {
+        let mock = Arc::new(MockTransport::with_results([
+            Err(LlmError::Timeout),
+            Ok("{\"choice\": 0}".to_string()),
+        ]));
+        let cfg = OllamaConfig {
+            retries: 1,
+            ..mock_config(&mock)
+        };
+        assert_eq!(llm_choose(&cfg, "Pick.", 2), Ok(0));
+        assert_eq!(mock.prompts().len(), 2);
+
+        let empty = Arc::new(MockTransport::default());
+        assert_eq!(
+            llm_generate(&mock_config(&empty), "Pick."),
+            Err(LlmError::Connect)
+        );
+    }
+
+    #[test]
+    fn mock_transport_survives_a_poisoned_lock() {
+        let mock = Arc::new(MockTransport::new(["ok"]));
+        let poisoner = mock.clone();
+        let _ = std::thread::spawn(move || {
+            let _held = poisoner.prompts.lock().unwrap();
+            panic!("poison the prompt log");
+        })
+        .join();
+        assert!(mock.prompts.is_poisoned());
+
+        assert_eq!(llm_generate(&mock_config(&mock), "Pick.").unwrap(), "ok");
+        assert_eq!(mock.prompts(), ["Pick."]);
+    }
+
+    #[test]
+    fn transient_errors_are_retried_until_success() {
+        let mut outcomes = vec![
+            Err(LlmError::Connect),
+            Err(LlmError::Timeout),
+            Ok("{\"choice\": 1}"),
+        ]
+        .into_iter();
+        let mut attempts = 0;
+        let result = with_retries(3, Duration::ZERO, || {
+            attempts += 1;
+            outcomes.next().unwrap()
+        });
+        assert_eq!(result, Ok("{\"choice\": 1}"));
+        assert_eq!(attempts, 3);
+    }
+
+    #[test]
+    fn parse_errors_and_spent_retries_stop_at_once() {
+        let mut attempts = 0;
+        let result: Result<(), _> = with_retries(3, Duration::ZERO, || {
+            attempts += 1;
+            Err(LlmError::Parse("bad json".to_string()))
+        });
+        assert!(matches!(result, Err(LlmError::Parse(_))));
+        assert_eq!(attempts, 1);
+
+        let mut attempts = 0;
+        let result: Result<(), _> = with_retries(0, Duration::ZERO, || {
+            attempts += 1;
+            Err(LlmError::Connect)
+        });
+        assert_eq!(result, Err(LlmError::Connect));
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn ndjson_stream_concatenates_chunks_until_done() {
+        let body = "{\"response\":\"{\\\"choice\\\"\",\"done\":false}\n\
+                    \n\
+                    {\"response\":\": 2}\",\"done\":false}\n\
+                    {\"response\":\"\",\"done\":true}\n\
+                    {\"response\":\"ignored\",\"done\":false}\n";
+        let mut tokens = Vec::new();
+        let text =
+            read_ndjson_stream(&mut body.as_bytes(), &mut |t| tokens.push(t.to_string())).unwrap();
+        assert_eq!(text, "{\"choice\": 2}");
+        assert_eq!(tokens, vec!["{\"choice\"", ": 2}"]);
+
+        let cut = "{\"response\":\"half\",\"done\":false}\n";
+        assert!(matches!(
+            read_ndjson_stream(&mut cut.as_bytes(), &mut |_| {}),
+            Err(LlmError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn chunked_reader_decodes_split_ndjson() {
+        use std::io::BufReader;
+
+        let line_a = "{\"response\":\"Hello\",\"done\":false}\n";
+        let line_b = "{\"response\":\" there\",\"done\":true}\n";
+        let (head, tail) = line_a.split_at(10);
+        let body = format!(
+            "{:x}\r\n{}\r\n{:x}\r\n{}{}\r\n0\r\n\r\n",
+            head.len(),
+            head,
+            tail.len() + line_b.len(),
+            tail,
+            line_b
+        );
+        let mut reader = BufReader::new(ChunkedReader::new(body.as_bytes()));
+        let text = read_ndjson_stream(&mut reader, &mut |_| {}).unwrap();
+        assert_eq!(text, "Hello there");
+    }
+
+    #[test]
+    fn test_decode_chunked_multi_chunk_with_extension() {
+        let json = "{\"response\":\"a long generation\",\"done\":true}";
+        let (a, rest) = json.split_at(7);
+        let (b, c) = rest.split_at(12);
+        let body = format!(
+            "{:x}\r\n{}\r\n{:X};name=value\r\n{}\r\n{:x}\r\n{}\r\n0\r\n\r\n",
+            a.len(),
+            a,
+            b.len(),
+            b,
+            c.len(),
+            c
+        );
+        assert_eq!(decode_chunked(&body).unwrap(), json);
+    }
+
+    #[test]
+    fn test_decode_chunked_rejects_bad_chunks() {
+        assert!(matches!(
+            decode_chunked("zz\r\nabc\r\n0\r\n\r\n"),
+            Err(LlmError::Parse(_))
+        ));
+        assert!(matches!(
+            decode_chunked("10\r\nabc"),
+            Err(LlmError::Parse(_))
+        ));
+    }
+
     // AC-4: HTTP status code validation
     #[test]
     fn test_parse_http_status_200_ok() {
@@ -780,18 +1962,23 @@ mod tests {
     #[test]
     fn test_parse_http_status_404_err() {
         let err = parse_http_status("HTTP/1.1 404 Not Found").unwrap_err();
-        assert!(err.contains("404"));
+        assert_eq!(err, LlmError::Http(404));
+        assert!(err.to_string().contains("404"));
+        assert!(!err.is_transient());
     }
 
     #[test]
     fn test_parse_http_status_500_err() {
         let err = parse_http_status("HTTP/1.1 500 Internal Server Error").unwrap_err();
-        assert!(err.contains("500"));
+        assert_eq!(err, LlmError::Http(500));
     }
 
     #[test]
     fn test_parse_http_status_malformed() {
-        assert!(parse_http_status("garbage").is_err());
+        assert!(matches!(
+            parse_http_status("garbage"),
+            Err(LlmError::Parse(_))
+        ));
     }
 
     // AC-5: Multi-strategy choice extraction
@@ -816,6 +2003,34 @@ mod tests {
         assert_eq!(extract_choice("I pick option 2 because", 4).unwrap(), 2);
     }
 
+    #[test]
+    fn test_extract_choice_confidence_drops_for_bare_scan() {
+        assert_eq!(
+            extract_choice_with_confidence("{\"choice\": 1}", 3).unwrap(),
+            (1, 1.0)
+        );
+        assert_eq!(
+            extract_choice_with_confidence("{\"choice\": \"1\"}", 3).unwrap(),
+            (1, 1.0)
+        );
+        assert_eq!(
+            extract_choice_with_confidence("I pick option 1", 3).unwrap(),
+            (1, BARE_SCAN_CONFIDENCE)
+        );
+    }
+
+    #[test]
+    fn test_extract_choice_prefers_number_after_keyword() {
+        assert_eq!(extract_choice("Round 12: I choose option 1", 3).unwrap(), 1);
+        assert_eq!(extract_choice("I pick option 1", 3).unwrap(), 1);
+        assert_eq!(
+            extract_choice("After 3 rounds, my choice: 2.", 3).unwrap(),
+            2
+        );
+        assert_eq!(extract_choice("2", 3).unwrap(), 2);
+        assert_eq!(extract_choice("Round 12 is tough", 3).unwrap(), 2);
+    }
+
     #[test]
     fn test_extract_choice_clamped() {
         assert_eq!(extract_choice("{\"choice\": 99}", 3).unwrap(), 2);
@@ -823,7 +2038,8 @@ mod tests {
 
     #[test]
     fn test_extract_choice_empty_err() {
-        assert!(extract_choice("", 3).is_err());
+        assert_eq!(extract_choice("", 3), Err(LlmError::EmptyResponse));
+        assert_eq!(extract_choice("no idea", 3), Err(LlmError::NoChoice));
     }
 
     // AC-6: can_connect() moved to council-core
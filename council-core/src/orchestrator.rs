@@ -0,0 +1,2386 @@
+//! Round-by-round driver for the galactic exploration simulation.
+//!
+//! The orchestrator ties together event generation, deliberation, weighted
+//! voting, outcome application, and threat processing. Callers can either
+//! step through rounds with [`GalacticOrchestrator::play_round`] (the CLI does
+//! this to print narrative as it goes) or run a whole game with [`run_galactic`].
+
+use crate::event::{Event, EventTemplate, Outcome, RngCore};
+use crate::explorer::{GalacticCouncilMember, VoteTrace};
+use crate::faction::Faction;
+use crate::galaxy::{GalaxyState, StateChange};
+use crate::milestone::{Milestone, MilestoneConfig};
+use crate::scoring::ScoreTracker;
+use crate::templates::generate_named_event;
+use crate::voting::{
+    apply_confidence, calculate_vote_weight_with_base, resolve_approval, resolve_plurality,
+    resolve_ranked_choice, resolve_votes, resolve_votes_hashed, ResolutionMode, Vote, BASE_WEIGHT,
+};
+use std::collections::HashMap;
+
+/// Options controlling how the orchestrator runs each round.
+#[derive(Debug, Clone, Default)]
+pub struct OrchestratorConfig {
+    /// Collect each bot's [`VoteTrace`] into the round record.
+    pub trace: bool,
+    /// Let bots publish comments before voting; comments are appended to
+    /// the event description the bots vote on.
+    pub deliberate: bool,
+    /// One-time bonuses for galaxy milestones (none by default).
+    pub milestones: MilestoneConfig,
+    /// How ballots are resolved into a winner (weighted plurality by default).
+    pub resolution: ResolutionMode,
+    /// Divide [`BASE_WEIGHT`] by the council size, so a crowd of non-experts
+    /// cannot drown out a lone specialist in large councils.
+    pub normalize_base_by_council_size: bool,
+    /// Cap on options per event, proposals included; larger events keep
+    /// their first `max_options - 1` options plus a no-op "defer" option.
+    pub max_options: Option<usize>,
+    /// Minimum options per event; smaller events (such as the one-option
+    /// quiet-period fallback) get no-op fillers like "Delay the decision"
+    /// from [`FILLER_OPTIONS`](crate::event::FILLER_OPTIONS), so voting is
+    /// never a forced choice. Applied before `max_options`; 0 disables it.
+    pub min_options: usize,
+    /// Proposal phase: up to this many options suggested by members via
+    /// [`GalacticCouncilMember::propose_option`] are appended to each event
+    /// before voting (0 disables the phase). They come last, so
+    /// `max_options` drops them first.
+    pub max_proposals: usize,
+    /// Break weighted-plurality ties with a per-round hash of the event
+    /// instead of by lowest index (see [`resolve_votes_hashed`]).
+    pub hashed_tie_break: bool,
+    /// Record every RNG draw into [`RoundRecord::rng_draws`], so
+    /// [`verify_against_rng_log`] can detect changed RNG consumption.
+    pub record_rng: bool,
+    /// Multiply the running score by this factor at the start of every
+    /// round, before the round's delta (e.g. `Some(0.99)`), so early points
+    /// lose value. No decay when `None`.
+    pub score_decay_per_round: Option<f32>,
+    /// Mission capacity: at most this many rounds may change the galaxy.
+    /// Once spent, winning outcomes still score but their state changes are
+    /// skipped (see [`RoundRecord::changes_skipped`]). Unlimited when `None`.
+    /// [`StateChange::ScoreScaledBy`] does not count as a change and is
+    /// always scored.
+    pub max_state_changing_rounds: Option<u32>,
+    /// Events the council faces each round, resolved one after another
+    /// against the evolving galaxy; each gets its own [`RoundRecord`] and
+    /// threats are processed once, after the last. 0 and 1 both mean one.
+    pub events_per_round: usize,
+    /// Brief the council on every option's outcome description and score
+    /// change (see [`Event::outcome_briefing`]), appended to the event text
+    /// that bots deliberate and vote on, so it also reaches LLM prompts.
+    pub reveal_outcomes: bool,
+}
+
+/// Everything that happened in a single round, or in one of its events
+/// when [`OrchestratorConfig::events_per_round`] is above one.
+#[derive(Debug, Clone)]
+pub struct RoundRecord {
+    /// Round number (1-indexed).
+    pub round: u32,
+    /// The event as generated, before any deliberation text was appended.
+    pub event: Event,
+    /// Deliberation comments as `(bot name, comment)` pairs.
+    pub comments: Vec<(String, String)>,
+    /// Votes cast this round.
+    pub votes: Vec<Vote>,
+    /// Vote traces as `(bot name, trace)` pairs (empty unless tracing is on).
+    pub traces: Vec<(String, VoteTrace)>,
+    /// Index of the winning option.
+    pub winner: usize,
+    /// No member voted, so the council fell back to option 0.
+    pub all_abstained: bool,
+    /// Score change from the winning outcome, including any
+    /// [`StateChange::ScoreScaledBy`](crate::galaxy::StateChange::ScoreScaledBy) earnings.
+    pub score_delta: i32,
+    /// The outcome's state changes were skipped because the
+    /// `max_state_changing_rounds` budget was spent.
+    pub changes_skipped: bool,
+    /// Penalty from active threats after the outcome was applied.
+    pub threat_penalty: i32,
+    /// Milestones first reached this round, with the bonus awarded.
+    pub milestones: Vec<(Milestone, i32)>,
+    /// Cumulative score at the end of the round.
+    pub score_total: i32,
+    /// Key galaxy stats at the end of the round.
+    pub snapshot: GalaxySnapshot,
+    /// Every RNG value drawn this round (empty unless `record_rng` is on).
+    pub rng_draws: Vec<u64>,
+}
+
+/// Headline galaxy counts captured at the end of a round.
+///
+/// Cheap to store per round, unlike a full [`GalaxyState`] clone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GalaxySnapshot {
+    pub sectors: usize,
+    pub species: usize,
+    pub threats: usize,
+    pub hostile: usize,
+    pub allied: usize,
+}
+
+impl GalaxySnapshot {
+    /// Capture the counts from a galaxy state.
+    pub fn of(galaxy: &GalaxyState) -> Self {
+        Self {
+            sectors: galaxy.explored_sectors.len(),
+            species: galaxy.known_species.len(),
+            threats: galaxy.threats.len(),
+            hostile: galaxy.hostile_count(),
+            allied: galaxy.allied_count(),
+        }
+    }
+}
+
+impl RoundRecord {
+    /// The outcome of the winning option.
+    pub fn outcome(&self) -> &Outcome {
+        &self.event.options[self.winner].outcome
+    }
+
+    /// Best available score delta minus the chosen one (0 when the council
+    /// picked a highest-scoring option).
+    pub fn regret(&self) -> i32 {
+        let best = self
+            .event
+            .options
+            .iter()
+            .map(|o| o.outcome.score_delta)
+            .max()
+            .unwrap_or(0);
+        match self.event.options.get(self.winner) {
+            Some(chosen) => best - chosen.outcome.score_delta,
+            None => 0,
+        }
+    }
+}
+
+/// Final result of a galactic simulation run.
+#[derive(Debug, Clone)]
+pub struct GalacticReport {
+    /// Per-round records in order.
+    pub rounds: Vec<RoundRecord>,
+    /// Galaxy state after the last round.
+    pub galaxy: GalaxyState,
+    /// Score history for the whole run.
+    pub score: ScoreTracker,
+    /// Per expertise tag, the summed expertise bonus (event weight ×
+    /// proficiency) of the bots that backed each round's winner.
+    pub influence_by_tag: HashMap<String, f32>,
+}
+
+impl GalacticReport {
+    /// Per-round galaxy stats as a JSON array, for front-ends that animate
+    /// the run round by round.
+    ///
+    /// Each entry is `{round, score_total, sectors, species, threats, hostile, allied}`.
+    pub fn to_timeline_json(&self) -> String {
+        let entries: Vec<serde_json::Value> = self
+            .rounds
+            .iter()
+            .map(|r| {
+                serde_json::json!({
+                    "round": r.round,
+                    "score_total": r.score_total,
+                    "sectors": r.snapshot.sectors,
+                    "species": r.snapshot.species,
+                    "threats": r.snapshot.threats,
+                    "hostile": r.snapshot.hostile,
+                    "allied": r.snapshot.allied,
+                })
+            })
+            .collect();
+        serde_json::Value::Array(entries).to_string()
+    }
+
+    /// Number of rounds played; with several events per round this is
+    /// fewer than the number of records.
+    pub fn rounds_played(&self) -> usize {
+        let mut played = 0;
+        let mut last = None;
+        for record in &self.rounds {
+            if last != Some(record.round) {
+                played += 1;
+                last = Some(record.round);
+            }
+        }
+        played
+    }
+
+    /// Average per-round [`RoundRecord::regret`]; 0.0 for an empty report.
+    ///
+    /// Zero means the council always took a highest-scoring option.
+    pub fn mean_regret(&self) -> f32 {
+        if self.rounds.is_empty() {
+            return 0.0;
+        }
+        let total: i32 = self.rounds.iter().map(|r| r.regret()).sum();
+        total as f32 / self.rounds.len() as f32
+    }
+
+    /// Render a round-by-round Markdown transcript of the run.
+    pub fn to_markdown(&self) -> String {
+        let mut s = String::from("# Council Transcript\n");
+        for record in &self.rounds {
+            s.push_str(&format!("\n## Round {}\n\n", record.round));
+            s.push_str(&record.event.description);
+            s.push_str("\n\n");
+            for (i, option) in record.event.options.iter().enumerate() {
+                s.push_str(&format!("- [{}] {}\n", i, option.description));
+            }
+            s.push('\n');
+            for vote in &record.votes {
+                s.push_str(&format!(
+                    "- {} votes [{}] (weight {:.2})\n",
+                    vote.bot_name, vote.chosen_option, vote.weight
+                ));
+            }
+            if record.all_abstained {
+                s.push_str("- every member abstained\n");
+            }
+            s.push_str(&format!("\nCouncil chooses [{}].\n\n", record.winner));
+            s.push_str(&record.outcome().to_markdown());
+            s.push_str(&format!(
+                "\n\nScore {:+} (total {})\n",
+                record.score_delta + record.threat_penalty,
+                record.score_total
+            ));
+        }
+        s.push_str(&format!("\n**Final score:** {}\n", self.score.total));
+        s
+    }
+
+    /// Render the run as a Markdown story for publishing: one section per
+    /// round with votes, comments and the outcome, then a summary.
+    pub fn to_narrative_markdown(&self) -> String {
+        let mut s = String::from("# Chronicle of the Council\n");
+        for record in &self.rounds {
+            s.push_str(&format!(
+                "\n## Round {} — {}\n\n{}\n\n",
+                record.round,
+                record.event.title(),
+                record.event.description
+            ));
+            for vote in &record.votes {
+                let option = record
+                    .event
+                    .options
+                    .get(vote.chosen_option)
+                    .map(|o| o.description.as_str())
+                    .unwrap_or("?");
+                s.push_str(&format!("- **{}** votes for {}", vote.bot_name, option));
+                if let Some((_, comment)) = record
+                    .comments
+                    .iter()
+                    .find(|(name, _)| *name == vote.bot_name)
+                {
+                    s.push_str(&format!(": \"{}\"", comment));
+                }
+                s.push('\n');
+            }
+            s.push_str(&format!(
+                "\nThe council chooses **{}**.\n\n",
+                record.event.options[record.winner].description
+            ));
+            s.push_str(&record.outcome().to_markdown());
+            if record.changes_skipped {
+                s.push_str("\n\n*Mission capacity is spent: the galaxy is left unchanged.*");
+            }
+            s.push_str(&format!("\n\n*Score: {}*\n", record.score_total));
+        }
+        s.push_str("\n## Summary\n\n");
+        s.push_str(&format!("- Rounds played: {}\n", self.rounds_played()));
+        s.push_str(&format!("- Final score: {}\n", self.score.total));
+        s.push_str(&format!(
+            "- Final rating: {}\n",
+            self.score.rating_for_rounds(self.rounds_played() as u32)
+        ));
+        s
+    }
+
+    /// Render the run as a self-contained HTML page (inline CSS, no
+    /// scripts) for sharing: a summary card, a score sparkline drawn as
+    /// inline SVG from the score history, and a round-by-round table.
+    pub fn to_html(&self) -> String {
+        let rating = self.score.rating_for_rounds(self.rounds_played() as u32);
+        let mut s = String::from(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Council Report</title>\n<style>\n\
+             body { font-family: sans-serif; margin: 2em auto; max-width: 60em; color: #222; }\n\
+             .card { border: 1px solid #ccc; border-radius: 6px; padding: 1em; margin-bottom: 1em; }\n\
+             table { border-collapse: collapse; width: 100%; }\n\
+             th, td { border-bottom: 1px solid #ddd; padding: 0.3em 0.6em; text-align: left; }\n\
+             td.num { text-align: right; }\n\
+             </style>\n</head>\n<body>\n<h1>Council Report</h1>\n",
+        );
+
+        s.push_str("<div class=\"card\">\n<h2>Summary</h2>\n<ul>\n");
+        s.push_str(&format!(
+            "<li>Rounds played: {}</li>\n",
+            self.rounds_played()
+        ));
+        s.push_str(&format!("<li>Final score: {}</li>\n", self.score.total));
+        s.push_str(&format!("<li>Rating: {}</li>\n", escape_html(rating)));
+        s.push_str(&format!(
+            "<li>Sectors explored: {}</li>\n<li>Allies: {}</li>\n<li>Discoveries: {}</li>\n",
+            self.galaxy.explored_sectors.len(),
+            self.galaxy.allied_count(),
+            self.galaxy.discoveries.len()
+        ));
+        s.push_str("</ul>\n");
+        s.push_str(&score_sparkline(&self.score));
+        s.push_str("</div>\n");
+
+        s.push_str(
+            "<table>\n<tr><th>Round</th><th>Event</th><th>Council choice</th>\
+             <th>Score change</th><th>Total</th></tr>\n",
+        );
+        for record in &self.rounds {
+            s.push_str(&format!(
+                "<tr><td class=\"num\">{}</td><td>{}</td><td>{}</td>\
+                 <td class=\"num\">{:+}</td><td class=\"num\">{}</td></tr>\n",
+                record.round,
+                escape_html(record.event.title()),
+                escape_html(&record.event.options[record.winner].description),
+                record.score_delta + record.threat_penalty,
+                record.score_total
+            ));
+        }
+        s.push_str("</table>\n</body>\n</html>\n");
+        s
+    }
+}
+
+/// Inline SVG polyline of the running score total, starting from zero.
+fn score_sparkline(score: &ScoreTracker) -> String {
+    const WIDTH: f32 = 300.0;
+    const HEIGHT: f32 = 60.0;
+
+    let mut totals = vec![0];
+    for event in &score.history {
+        totals.push(totals.last().unwrap() + event.delta);
+    }
+    let min = *totals.iter().min().unwrap();
+    let max = *totals.iter().max().unwrap();
+    let span = (max - min).max(1) as f32;
+    let step = WIDTH / (totals.len() - 1).max(1) as f32;
+    let points = totals
+        .iter()
+        .enumerate()
+        .map(|(i, total)| {
+            let x = i as f32 * step;
+            let y = HEIGHT - (total - min) as f32 / span * HEIGHT;
+            format!("{:.1},{:.1}", x, y)
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" \
+         viewBox=\"0 0 {w} {h}\" role=\"img\" aria-label=\"Score over time\">\n\
+         <polyline fill=\"none\" stroke=\"#2a6\" stroke-width=\"2\" points=\"{points}\"/>\n</svg>\n",
+        w = WIDTH,
+        h = HEIGHT,
+        points = points
+    )
+}
+
+/// Escape text for use in HTML element content.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Drives a council of galactic bots through successive rounds.
+pub struct GalacticOrchestrator<'a> {
+    bots: &'a [Box<dyn GalacticCouncilMember>],
+    factions: &'a [Faction],
+    templates: &'a [Box<dyn EventTemplate>],
+    config: OrchestratorConfig,
+    galaxy: GalaxyState,
+    score: ScoreTracker,
+    rounds: Vec<RoundRecord>,
+    milestones_reached: Vec<Milestone>,
+    threats_resolved: u32,
+    state_changing_rounds: u32,
+    influence_by_tag: HashMap<String, f32>,
+}
+
+impl<'a> GalacticOrchestrator<'a> {
+    /// Create an orchestrator starting from a fresh galaxy.
+    pub fn new(
+        bots: &'a [Box<dyn GalacticCouncilMember>],
+        templates: &'a [Box<dyn EventTemplate>],
+        config: OrchestratorConfig,
+    ) -> Self {
+        Self {
+            bots,
+            factions: &[],
+            templates,
+            config,
+            galaxy: GalaxyState::new(),
+            score: ScoreTracker::new(),
+            rounds: Vec::new(),
+            milestones_reached: Vec::new(),
+            threats_resolved: 0,
+            state_changing_rounds: 0,
+            influence_by_tag: HashMap::new(),
+        }
+    }
+
+    /// Start from `galaxy` instead of a fresh [`GalaxyState::new`].
+    pub fn with_galaxy(mut self, galaxy: GalaxyState) -> Self {
+        self.galaxy = galaxy;
+        self
+    }
+
+    /// Add factions that vote alongside the independent bots.
+    ///
+    /// Faction members deliberate like any other bot, but their votes are
+    /// blended with their faction's line (see [`Faction::cast_votes`]).
+    pub fn with_factions(mut self, factions: &'a [Faction]) -> Self {
+        self.factions = factions;
+        self
+    }
+
+    /// Current galaxy state.
+    pub fn galaxy(&self) -> &GalaxyState {
+        &self.galaxy
+    }
+
+    /// Current score tracker.
+    pub fn score(&self) -> &ScoreTracker {
+        &self.score
+    }
+
+    /// Play the next round and return its record (the last event's record
+    /// when [`OrchestratorConfig::events_per_round`] is above one).
+    pub fn play_round(&mut self, rng: &mut dyn RngCore) -> &RoundRecord {
+        self.galaxy.advance_round();
+        let round = self.galaxy.round;
+        if let Some(factor) = self.config.score_decay_per_round {
+            self.score.decay(round, factor);
+        }
+        let events = self.config.events_per_round.max(1);
+        for i in 0..events {
+            self.play_event(round, rng, i + 1 == events);
+        }
+        self.rounds.last().expect("round was just recorded")
+    }
+
+    /// Generate, vote on and resolve one event, recording it. Threats are
+    /// only processed after the round's last event.
+    fn play_event(&mut self, round: u32, rng: &mut dyn RngCore, end_of_round: bool) {
+        let mut rng = RecordingRng {
+            inner: rng,
+            draws: self.config.record_rng.then(Vec::new),
+        };
+        let (template_name, mut event) =
+            generate_named_event(self.templates, &self.galaxy, &mut rng);
+        // Drop options gated behind discoveries the galaxy lacks
+        if !event.available_options(&self.galaxy).is_empty() {
+            let galaxy = &self.galaxy;
+            event.options.retain(|o| o.is_available(galaxy));
+        }
+        event.pad_options(self.config.min_options);
+        if self.config.max_proposals > 0 {
+            let proposals: Vec<_> = self
+                .bots
+                .iter()
+                .chain(self.factions.iter().flat_map(|f| &f.members))
+                .filter_map(|bot| bot.propose_option(&event, &self.galaxy))
+                .take(self.config.max_proposals)
+                .collect();
+            event.options.extend(proposals);
+        }
+        if let Some(max) = self.config.max_options {
+            event.clamp_options(max);
+        }
+
+        let mut event_for_vote = event.clone();
+        if self.config.reveal_outcomes {
+            event_for_vote.description = format!(
+                "{}\n\nBRIEFING:\n{}",
+                event_for_vote.description,
+                event.outcome_briefing()
+            );
+        }
+
+        // Optional deliberation phase
+        let mut comments = Vec::new();
+        if self.config.deliberate {
+            let members = self
+                .bots
+                .iter()
+                .chain(self.factions.iter().flat_map(|f| &f.members));
+            for bot in members {
+                if let Some(comment) = bot.comment(&event_for_vote, &self.galaxy) {
+                    comments.push((bot.name().to_string(), comment));
+                }
+            }
+            if !comments.is_empty() {
+                let lines: Vec<String> = comments
+                    .iter()
+                    .map(|(name, comment)| format!("{}: {}", name, comment))
+                    .collect();
+                event_for_vote.description = format!(
+                    "{}\n\nCOUNCIL DELIBERATION:\n{}",
+                    event_for_vote.description,
+                    lines.join("\n")
+                );
+            }
+        }
+
+        // Collect votes
+        let council_size =
+            self.bots.len() + self.factions.iter().map(|f| f.members.len()).sum::<usize>();
+        let base_weight = if self.config.normalize_base_by_council_size && council_size > 0 {
+            BASE_WEIGHT / council_size as f32
+        } else {
+            BASE_WEIGHT
+        };
+        let mut votes = Vec::new();
+        // Who cast each vote, for crediting influence (abstainers cast none).
+        let mut voters: Vec<&dyn GalacticCouncilMember> = Vec::new();
+        let mut ballots: Vec<Vec<usize>> = Vec::new();
+        let mut traces = Vec::new();
+        for bot in self.bots {
+            if !bot.will_vote(&event_for_vote, &self.galaxy) {
+                continue;
+            }
+            // Ballot modes ask for the ballot alone and record its first
+            // entry as the vote, so each bot is consulted once.
+            let ballot = match self.config.resolution {
+                ResolutionMode::Approval => {
+                    Some(bot.approve_options(&event_for_vote, &self.galaxy))
+                }
+                ResolutionMode::RankedChoice => {
+                    Some(bot.rank_options(&event_for_vote, &self.galaxy))
+                }
+                ResolutionMode::Plurality | ResolutionMode::WeightedPlurality => None,
+            };
+            let chosen = match ballot {
+                Some(ballot) => {
+                    let Some(&first) = ballot.first() else {
+                        continue;
+                    };
+                    ballots.push(ballot);
+                    first
+                }
+                None => {
+                    let Some(chosen) =
+                        bot.vote_or_abstain(&event_for_vote, &self.galaxy, &self.score)
+                    else {
+                        continue;
+                    };
+                    chosen
+                }
+            };
+            let chosen = chosen.min(event.options.len().saturating_sub(1));
+            let weight = apply_confidence(
+                calculate_vote_weight_with_base(bot.as_ref(), &event, base_weight),
+                bot.confidence(&event_for_vote, &self.galaxy),
+            );
+            if self.config.trace {
+                if let Some(trace) = bot.trace(&event_for_vote, &self.galaxy) {
+                    traces.push((bot.name().to_string(), trace));
+                }
+            }
+            voters.push(bot.as_ref());
+            votes.push(Vote {
+                bot_name: bot.name().to_string(),
+                chosen_option: chosen,
+                weight,
+            });
+        }
+        for faction in self.factions {
+            let faction_votes = faction.cast_member_votes(
+                &event_for_vote,
+                &self.galaxy,
+                &self.score,
+                &mut rng,
+                base_weight,
+            );
+            for (member, vote) in faction_votes {
+                // Faction members follow a single line, so their ballots are that one pick.
+                ballots.push(vec![vote.chosen_option]);
+                voters.push(member);
+                votes.push(vote);
+            }
+            if self.config.trace {
+                for member in &faction.members {
+                    if let Some(trace) = member.trace(&event_for_vote, &self.galaxy) {
+                        traces.push((member.name().to_string(), trace));
+                    }
+                }
+            }
+        }
+
+        // Resolve and apply
+        let num_options = event.options.len();
+        let all_abstained = votes.is_empty();
+        let winner = match self.config.resolution {
+            _ if all_abstained => 0,
+            ResolutionMode::Plurality => resolve_plurality(&votes, num_options),
+            ResolutionMode::Approval => resolve_approval(&ballots, num_options),
+            ResolutionMode::RankedChoice => resolve_ranked_choice(&ballots, num_options),
+            ResolutionMode::WeightedPlurality if self.config.hashed_tie_break => {
+                resolve_votes_hashed(&votes, &event, round)
+            }
+            ResolutionMode::WeightedPlurality => resolve_votes(&votes, num_options),
+        };
+        let members = self
+            .bots
+            .iter()
+            .chain(self.factions.iter().flat_map(|f| &f.members));
+        for bot in members {
+            bot.observe_winner(&event, winner);
+        }
+        for (bot, vote) in voters.iter().zip(&votes) {
+            if vote.chosen_option != winner {
+                continue;
+            }
+            for (tag, event_weight) in &event.relevant_expertise {
+                if let Some((_, proficiency)) =
+                    bot.expertise().iter().find(|(bot_tag, _)| bot_tag == tag)
+                {
+                    *self.influence_by_tag.entry(tag.clone()).or_insert(0.0) +=
+                        event_weight * proficiency;
+                }
+            }
+        }
+        let outcome = &event.options[winner].outcome;
+        let threats_before: Vec<String> =
+            self.galaxy.threats.iter().map(|t| t.name.clone()).collect();
+        // Scaled scores only read the galaxy, so they neither use up nor
+        // need mission capacity.
+        let changes_galaxy = outcome
+            .state_changes
+            .iter()
+            .any(|change| !matches!(change, StateChange::ScoreScaledBy { .. }));
+        let changes_skipped = changes_galaxy
+            && self
+                .config
+                .max_state_changing_rounds
+                .is_some_and(|max| self.state_changing_rounds >= max);
+        let scaled_score = if changes_skipped {
+            outcome
+                .state_changes
+                .iter()
+                .map(|change| self.galaxy.scaled_score(change))
+                .sum()
+        } else {
+            if changes_galaxy {
+                self.state_changing_rounds += 1;
+            }
+            self.galaxy
+                .apply_changes_tagged(&outcome.state_changes, round, template_name)
+        };
+        let score_delta = outcome.score_delta + scaled_score;
+        self.score.add(round, score_delta, &outcome.description);
+        self.threats_resolved += threats_before
+            .iter()
+            .filter(|name| !self.galaxy.threats.iter().any(|t| &t.name == *name))
+            .count() as u32;
+
+        let threat_penalty = if end_of_round {
+            self.galaxy.process_threats()
+        } else {
+            0
+        };
+        if threat_penalty != 0 {
+            self.score.add(round, threat_penalty, "Unresolved threats");
+        }
+
+        let milestones = self.award_milestones(round);
+
+        self.rounds.push(RoundRecord {
+            round,
+            event,
+            comments,
+            votes,
+            traces,
+            winner,
+            all_abstained,
+            score_delta,
+            changes_skipped,
+            threat_penalty,
+            milestones,
+            score_total: self.score.total,
+            snapshot: GalaxySnapshot::of(&self.galaxy),
+            rng_draws: rng.draws.unwrap_or_default(),
+        });
+    }
+
+    /// Award bonuses for configured milestones reached for the first time.
+    fn award_milestones(&mut self, round: u32) -> Vec<(Milestone, i32)> {
+        let mut awarded = Vec::new();
+        for &(milestone, bonus) in &self.config.milestones.bonuses {
+            if self.milestones_reached.contains(&milestone)
+                || !milestone.is_reached(&self.galaxy, self.threats_resolved)
+            {
+                continue;
+            }
+            self.milestones_reached.push(milestone);
+            self.score.add(
+                round,
+                bonus,
+                &format!("Milestone: {}", milestone.describe()),
+            );
+            awarded.push((milestone, bonus));
+        }
+        awarded
+    }
+
+    /// Consume the orchestrator and return the final report.
+    pub fn finish(self) -> GalacticReport {
+        GalacticReport {
+            rounds: self.rounds,
+            galaxy: self.galaxy,
+            score: self.score,
+            influence_by_tag: self.influence_by_tag,
+        }
+    }
+}
+
+/// Passes draws through from `inner`, optionally logging them.
+///
+/// `next_u32` and `next_u64` values are logged as drawn; `fill_bytes` logs
+/// each 8-byte chunk as a little-endian value.
+struct RecordingRng<'r> {
+    inner: &'r mut dyn RngCore,
+    draws: Option<Vec<u64>>,
+}
+
+impl RngCore for RecordingRng<'_> {
+    fn next_u32(&mut self) -> u32 {
+        let value = self.inner.next_u32();
+        if let Some(draws) = &mut self.draws {
+            draws.push(value as u64);
+        }
+        value
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let value = self.inner.next_u64();
+        if let Some(draws) = &mut self.draws {
+            draws.push(value);
+        }
+        value
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.inner.fill_bytes(dest);
+        if let Some(draws) = &mut self.draws {
+            for chunk in dest.chunks(8) {
+                let mut bytes = [0u8; 8];
+                bytes[..chunk.len()].copy_from_slice(chunk);
+                draws.push(u64::from_le_bytes(bytes));
+            }
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Re-run a recorded game and check it draws the same RNG values each round.
+///
+/// `report` must come from a run with `record_rng` on; the replay uses the
+/// same council, templates and config with `rng` reseeded as for the
+/// original. An error names the first round whose draws differ, which flags
+/// changes that make old seeds produce different games.
+pub fn verify_against_rng_log(
+    report: &GalacticReport,
+    bots: &[Box<dyn GalacticCouncilMember>],
+    templates: &[Box<dyn EventTemplate>],
+    config: OrchestratorConfig,
+    rng: &mut dyn RngCore,
+) -> Result<(), String> {
+    let config = OrchestratorConfig {
+        record_rng: true,
+        ..config
+    };
+    let mut orchestrator = GalacticOrchestrator::new(bots, templates, config);
+    for _ in 0..report.rounds_played() {
+        orchestrator.play_round(rng);
+    }
+    for (recorded, replayed) in report.rounds.iter().zip(&orchestrator.rounds) {
+        if replayed.rng_draws != recorded.rng_draws {
+            return Err(format!(
+                "round {}: recorded {} RNG draws, replay drew {} with different values",
+                recorded.round,
+                recorded.rng_draws.len(),
+                replayed.rng_draws.len()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Run a complete galactic simulation for `rounds` rounds.
+///
+/// With `rounds == 0` no event is generated and the RNG is untouched: the
+/// report has no rounds, a zero score and the starting galaxy.
+pub fn run_galactic(
+    bots: &[Box<dyn GalacticCouncilMember>],
+    templates: &[Box<dyn EventTemplate>],
+    rounds: u32,
+    config: OrchestratorConfig,
+    rng: &mut dyn RngCore,
+) -> GalacticReport {
+    let mut orchestrator = GalacticOrchestrator::new(bots, templates, config);
+    for _ in 0..rounds {
+        orchestrator.play_round(rng);
+    }
+    orchestrator.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::ResponseOption;
+    use crate::templates::default_templates;
+    use crate::test_util::make_event;
+    use rand::SeedableRng;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Votes for `choice` with science expertise, and reports a trace.
+    #[derive(Clone)]
+    struct TracedBot {
+        choice: usize,
+    }
+
+    impl GalacticCouncilMember for TracedBot {
+        fn name(&self) -> &'static str {
+            "fixed-bot"
+        }
+
+        fn expertise(&self) -> &[(&'static str, f32)] {
+            &[("science", 0.5)]
+        }
+
+        fn vote(&self, _event: &Event, _galaxy: &GalaxyState) -> usize {
+            self.choice
+        }
+
+        fn trace(&self, _event: &Event, galaxy: &GalaxyState) -> Option<VoteTrace> {
+            Some(VoteTrace {
+                branch: "fixed",
+                threat_pressure: galaxy.threats.iter().map(|t| t.severity).sum(),
+                hostile_count: galaxy.hostile_count(),
+                allied_count: galaxy.allied_count(),
+                matched_expertise: vec![],
+                chosen: self.choice,
+            })
+        }
+    }
+
+    fn council() -> Vec<Box<dyn GalacticCouncilMember>> {
+        vec![
+            Box::new(TracedBot { choice: 0 }),
+            Box::new(TracedBot { choice: 1 }),
+        ]
+    }
+
+    #[test]
+    fn run_records_every_round() {
+        let bots = council();
+        let templates = default_templates();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let report = run_galactic(
+            &bots,
+            &templates,
+            5,
+            OrchestratorConfig::default(),
+            &mut rng,
+        );
+
+        assert_eq!(report.rounds.len(), 5);
+        assert_eq!(report.galaxy.round, 5);
+        assert_eq!(
+            report.rounds.last().unwrap().score_total,
+            report.score.total
+        );
+        assert!(report.rounds.iter().all(|r| r.traces.is_empty()));
+    }
+
+    #[test]
+    fn trace_flag_collects_traces() {
+        let bots = council();
+        let templates = default_templates();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let config = OrchestratorConfig {
+            trace: true,
+            ..OrchestratorConfig::default()
+        };
+        let report = run_galactic(&bots, &templates, 3, config, &mut rng);
+
+        for record in &report.rounds {
+            assert_eq!(record.traces.len(), 2);
+            assert_eq!(record.traces[0].0, "fixed-bot");
+            assert_eq!(record.traces[0].1.branch, "fixed");
+        }
+    }
+
+    #[test]
+    fn same_seed_same_report() {
+        let bots = council();
+        let templates = default_templates();
+        let run = |seed| {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            run_galactic(
+                &bots,
+                &templates,
+                10,
+                OrchestratorConfig::default(),
+                &mut rng,
+            )
+            .score
+            .total
+        };
+        assert_eq!(run(99), run(99));
+    }
+
+    #[test]
+    fn timeline_json_has_one_entry_per_round() {
+        let bots = council();
+        let templates = default_templates();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+        let report = run_galactic(
+            &bots,
+            &templates,
+            6,
+            OrchestratorConfig::default(),
+            &mut rng,
+        );
+
+        let timeline: serde_json::Value = serde_json::from_str(&report.to_timeline_json()).unwrap();
+        let entries = timeline.as_array().unwrap();
+        assert_eq!(entries.len(), 6);
+        let last = entries.last().unwrap();
+        assert_eq!(last["round"], 6);
+        assert_eq!(last["score_total"], report.score.total);
+        assert_eq!(last["sectors"], report.galaxy.explored_sectors.len() as u64);
+    }
+
+    #[test]
+    fn faction_members_vote_with_independent_bots() {
+        let bots = council();
+        let factions = vec![Faction::new(
+            "Bloc",
+            vec![
+                Box::new(TracedBot { choice: 1 }),
+                Box::new(TracedBot { choice: 1 }),
+            ],
+            1.0,
+        )];
+        let templates = default_templates();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(5);
+        let mut orchestrator =
+            GalacticOrchestrator::new(&bots, &templates, OrchestratorConfig::default())
+                .with_factions(&factions);
+
+        let record = orchestrator.play_round(&mut rng);
+        assert_eq!(record.votes.len(), 4);
+    }
+
+    #[test]
+    fn markdown_transcript_shows_headlines() {
+        let bots = council();
+        let templates = default_templates();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(8);
+        let mut report = run_galactic(
+            &bots,
+            &templates,
+            2,
+            OrchestratorConfig::default(),
+            &mut rng,
+        );
+
+        let plain = report.to_markdown();
+        assert!(plain.contains("## Round 1"));
+        assert!(plain.contains("## Round 2"));
+
+        let winner = report.rounds[0].winner;
+        report.rounds[0].event.options[winner].outcome.headline =
+            Some("Contact Established".to_string());
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("**Contact Established**\n\n"));
+        assert!(!plain.contains("**Contact Established**"));
+    }
+
+    #[test]
+    fn narrative_markdown_has_section_per_round() {
+        let bots = council();
+        let templates = default_templates();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(8);
+        let report = run_galactic(
+            &bots,
+            &templates,
+            3,
+            OrchestratorConfig::default(),
+            &mut rng,
+        );
+
+        let story = report.to_narrative_markdown();
+        assert_eq!(story.matches("## Round ").count(), 3);
+        assert!(story.contains(&format!("## Round 1 — {}", report.rounds[0].event.title())));
+        let rating = report.score.rating_for_rounds(3);
+        assert!(story.contains(&format!("Final rating: {}", rating)));
+    }
+
+    #[test]
+    fn html_report_has_rounds_score_and_sparkline() {
+        let bots = council();
+        let templates = default_templates();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(8);
+        let report = run_galactic(
+            &bots,
+            &templates,
+            4,
+            OrchestratorConfig::default(),
+            &mut rng,
+        );
+
+        let html = report.to_html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<svg"));
+        assert!(html.contains(&format!("<li>Final score: {}</li>", report.score.total)));
+        for round in 1..=4 {
+            assert!(html.contains(&format!("<tr><td class=\"num\">{}</td>", round)));
+        }
+        assert!(!html.contains("<script"));
+        assert_eq!(escape_html("<a & \"b\">"), "&lt;a &amp; &quot;b&quot;&gt;");
+    }
+
+    struct SurveyTemplate;
+
+    impl EventTemplate for SurveyTemplate {
+        fn name(&self) -> &'static str {
+            "Survey"
+        }
+
+        fn is_applicable(&self, _galaxy: &GalaxyState) -> bool {
+            true
+        }
+
+        fn generate(&self, galaxy: &GalaxyState, _rng: &mut dyn RngCore) -> Event {
+            use crate::galaxy::{Sector, SectorType, StateChange};
+            Event {
+                description: "Survey a new sector".to_string(),
+                relevant_expertise: vec![],
+                options: vec![ResponseOption {
+                    description: "Survey".to_string(),
+                    outcome: Outcome {
+                        description: "Sector charted.".to_string(),
+                        score_delta: 0,
+                        headline: None,
+                        state_changes: vec![StateChange::AddSector(Sector {
+                            name: format!("Sector {}", galaxy.round),
+                            sector_type: SectorType::Nebula,
+                        })],
+                    },
+                    required_discovery: None,
+                }],
+            }
+        }
+    }
+
+    #[test]
+    fn milestone_bonus_awarded_once() {
+        let bots = council();
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(SurveyTemplate)];
+        let config = OrchestratorConfig {
+            milestones: MilestoneConfig::default().with(Milestone::SectorsExplored(5), 25),
+            ..OrchestratorConfig::default()
+        };
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        let report = run_galactic(&bots, &templates, 8, config, &mut rng);
+
+        // Home sector + one per round: the 5th sector lands in round 4.
+        let awarded: Vec<u32> = report
+            .rounds
+            .iter()
+            .filter(|r| !r.milestones.is_empty())
+            .map(|r| r.round)
+            .collect();
+        assert_eq!(awarded, vec![4]);
+        assert_eq!(report.score.total, 25);
+        let bonuses: Vec<_> = report
+            .score
+            .history
+            .iter()
+            .filter(|e| e.reason.starts_with("Milestone"))
+            .collect();
+        assert_eq!(bonuses.len(), 1);
+        assert_eq!(bonuses[0].reason, "Milestone: 5 sectors explored");
+        assert_eq!(bonuses[0].round, 4);
+    }
+
+    #[derive(Clone)]
+    struct BallotBot {
+        choice: usize,
+        approvals: Vec<usize>,
+    }
+
+    impl GalacticCouncilMember for BallotBot {
+        fn name(&self) -> &'static str {
+            "ballot-bot"
+        }
+
+        fn expertise(&self) -> &[(&'static str, f32)] {
+            &[]
+        }
+
+        fn vote(&self, _event: &Event, _galaxy: &GalaxyState) -> usize {
+            self.choice
+        }
+
+        fn approve_options(&self, _event: &Event, _galaxy: &GalaxyState) -> Vec<usize> {
+            self.approvals.clone()
+        }
+    }
+
+    struct ThreeWayTemplate;
+
+    impl EventTemplate for ThreeWayTemplate {
+        fn name(&self) -> &'static str {
+            "Three Way"
+        }
+
+        fn is_applicable(&self, _galaxy: &GalaxyState) -> bool {
+            true
+        }
+
+        fn generate(&self, _galaxy: &GalaxyState, _rng: &mut dyn RngCore) -> Event {
+            Event {
+                description: "Pick one".to_string(),
+                ..make_event(3)
+            }
+        }
+    }
+
+    #[test]
+    fn resolution_mode_changes_winner() {
+        let bots: Vec<Box<dyn GalacticCouncilMember>> = vec![
+            Box::new(BallotBot {
+                choice: 0,
+                approvals: vec![0],
+            }),
+            Box::new(BallotBot {
+                choice: 0,
+                approvals: vec![0],
+            }),
+            Box::new(BallotBot {
+                choice: 1,
+                approvals: vec![1, 2],
+            }),
+            Box::new(BallotBot {
+                choice: 2,
+                approvals: vec![2, 1],
+            }),
+            Box::new(BallotBot {
+                choice: 1,
+                approvals: vec![1],
+            }),
+        ];
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(ThreeWayTemplate)];
+        let winner = |resolution| {
+            let config = OrchestratorConfig {
+                resolution,
+                ..OrchestratorConfig::default()
+            };
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            run_galactic(&bots, &templates, 1, config, &mut rng).rounds[0].winner
+        };
+
+        // Plurality: 0 and 1 tie on two votes each; lower index wins.
+        assert_eq!(winner(ResolutionMode::Plurality), 0);
+        // Approval: option 1 has three approvals.
+        assert_eq!(winner(ResolutionMode::Approval), 1);
+    }
+
+    /// Counts how often it is asked for a pick.
+    #[derive(Clone)]
+    struct CountingBot {
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl GalacticCouncilMember for CountingBot {
+        fn name(&self) -> &'static str {
+            "counting-bot"
+        }
+
+        fn expertise(&self) -> &[(&'static str, f32)] {
+            &[]
+        }
+
+        fn vote(&self, _event: &Event, _galaxy: &GalaxyState) -> usize {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            2
+        }
+    }
+
+    #[test]
+    fn ballot_modes_consult_each_bot_once() {
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(ThreeWayTemplate)];
+        for resolution in [
+            ResolutionMode::WeightedPlurality,
+            ResolutionMode::Plurality,
+            ResolutionMode::Approval,
+            ResolutionMode::RankedChoice,
+        ] {
+            let calls = Arc::new(AtomicUsize::new(0));
+            let bots: Vec<Box<dyn GalacticCouncilMember>> = vec![Box::new(CountingBot {
+                calls: calls.clone(),
+            })];
+            let config = OrchestratorConfig {
+                resolution,
+                ..OrchestratorConfig::default()
+            };
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            let report = run_galactic(&bots, &templates, 1, config, &mut rng);
+
+            assert_eq!(calls.load(Ordering::SeqCst), 1, "{:?}", resolution);
+            let record = &report.rounds[0];
+            assert_eq!(record.votes[0].chosen_option, 2);
+            assert_eq!(record.winner, 2);
+        }
+    }
+
+    #[test]
+    fn empty_approval_ballot_abstains() {
+        let bots: Vec<Box<dyn GalacticCouncilMember>> = vec![
+            Box::new(BallotBot {
+                choice: 1,
+                approvals: vec![],
+            }),
+            Box::new(BallotBot {
+                choice: 2,
+                approvals: vec![2],
+            }),
+        ];
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(ThreeWayTemplate)];
+        let config = OrchestratorConfig {
+            resolution: ResolutionMode::Approval,
+            ..OrchestratorConfig::default()
+        };
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let report = run_galactic(&bots, &templates, 1, config, &mut rng);
+
+        let record = &report.rounds[0];
+        assert_eq!(record.votes.len(), 1);
+        assert_eq!(record.votes[0].chosen_option, 2);
+        assert_eq!(record.winner, 2);
+    }
+
+    fn record_with(deltas: &[i32], winner: usize) -> RoundRecord {
+        RoundRecord {
+            round: 1,
+            event: Event {
+                description: "Test".to_string(),
+                relevant_expertise: vec![],
+                options: deltas
+                    .iter()
+                    .map(|&score_delta| ResponseOption {
+                        description: "Option".to_string(),
+                        outcome: Outcome {
+                            description: "Outcome".to_string(),
+                            score_delta,
+                            headline: None,
+                            state_changes: vec![],
+                        },
+                        required_discovery: None,
+                    })
+                    .collect(),
+            },
+            comments: vec![],
+            votes: vec![],
+            traces: vec![],
+            winner,
+            all_abstained: false,
+            score_delta: deltas[winner],
+            changes_skipped: false,
+            threat_penalty: 0,
+            milestones: vec![],
+            score_total: 0,
+            snapshot: GalaxySnapshot::default(),
+            rng_draws: vec![],
+        }
+    }
+
+    #[test]
+    fn mean_regret_averages_missed_points() {
+        let report = GalacticReport {
+            rounds: vec![
+                record_with(&[10, 5, -3], 0), // best taken: 0
+                record_with(&[10, 5, -3], 2), // 13
+                record_with(&[2, 8], 1),      // 0
+                record_with(&[4, 6], 0),      // 2
+            ],
+            galaxy: GalaxyState::new(),
+            score: ScoreTracker::new(),
+            influence_by_tag: HashMap::new(),
+        };
+        assert_eq!(report.rounds[1].regret(), 13);
+        assert!((report.mean_regret() - 3.75).abs() < 1e-6);
+
+        let empty = GalacticReport {
+            rounds: vec![],
+            galaxy: GalaxyState::new(),
+            score: ScoreTracker::new(),
+            influence_by_tag: HashMap::new(),
+        };
+        assert_eq!(empty.mean_regret(), 0.0);
+    }
+
+    #[test]
+    fn named_members_are_distinct_in_report() {
+        use crate::explorer::NamedMember;
+        let bots: Vec<Box<dyn GalacticCouncilMember>> = vec![
+            Box::new(NamedMember::new(
+                "fixed-alpha",
+                Box::new(TracedBot { choice: 0 }),
+            )),
+            Box::new(NamedMember::new(
+                "fixed-beta",
+                Box::new(TracedBot { choice: 1 }),
+            )),
+        ];
+        let templates = default_templates();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(4);
+        let report = run_galactic(
+            &bots,
+            &templates,
+            2,
+            OrchestratorConfig::default(),
+            &mut rng,
+        );
+
+        for record in &report.rounds {
+            let names: Vec<&str> = record.votes.iter().map(|v| v.bot_name.as_str()).collect();
+            assert_eq!(names, vec!["fixed-alpha", "fixed-beta"]);
+        }
+    }
+
+    #[derive(Clone)]
+    struct ExpertBot {
+        choice: usize,
+        expertise: &'static [(&'static str, f32)],
+    }
+
+    impl GalacticCouncilMember for ExpertBot {
+        fn name(&self) -> &'static str {
+            "expert-bot"
+        }
+
+        fn expertise(&self) -> &[(&'static str, f32)] {
+            self.expertise
+        }
+
+        fn vote(&self, _event: &Event, _galaxy: &GalaxyState) -> usize {
+            self.choice
+        }
+    }
+
+    #[derive(Clone)]
+    struct SummitTemplate;
+
+    impl EventTemplate for SummitTemplate {
+        fn name(&self) -> &'static str {
+            "Summit"
+        }
+
+        fn is_applicable(&self, _galaxy: &GalaxyState) -> bool {
+            true
+        }
+
+        fn generate(&self, galaxy: &GalaxyState, rng: &mut dyn RngCore) -> Event {
+            let mut event = ThreeWayTemplate.generate(galaxy, rng);
+            event.relevant_expertise = vec![("diplomacy".to_string(), 0.5)];
+            event
+        }
+    }
+
+    #[test]
+    fn normalized_base_keeps_specialist_decisive() {
+        let council = |size: usize| {
+            let mut bots: Vec<Box<dyn GalacticCouncilMember>> = vec![Box::new(ExpertBot {
+                choice: 1,
+                expertise: &[("diplomacy", 0.8)],
+            })];
+            for _ in 1..size {
+                bots.push(Box::new(ExpertBot {
+                    choice: 0,
+                    expertise: &[],
+                }));
+            }
+            bots
+        };
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(SummitTemplate)];
+        let winner = |size: usize, normalize: bool| {
+            let bots = council(size);
+            let config = OrchestratorConfig {
+                normalize_base_by_council_size: normalize,
+                ..OrchestratorConfig::default()
+            };
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            run_galactic(&bots, &templates, 1, config, &mut rng).rounds[0].winner
+        };
+
+        // Small council: the specialist wins either way.
+        assert_eq!(winner(2, false), 1);
+        assert_eq!(winner(2, true), 1);
+        // Large council: 19 base weights outvote the specialist unless normalized.
+        assert_eq!(winner(20, false), 0);
+        assert_eq!(winner(20, true), 1);
+    }
+
+    struct ShieldTemplate;
+
+    impl EventTemplate for ShieldTemplate {
+        fn name(&self) -> &'static str {
+            "Shield"
+        }
+
+        fn is_applicable(&self, _galaxy: &GalaxyState) -> bool {
+            true
+        }
+
+        fn generate(&self, galaxy: &GalaxyState, rng: &mut dyn RngCore) -> Event {
+            let mut event = ThreeWayTemplate.generate(galaxy, rng);
+            event.options[0].required_discovery = Some("Shield Technology".to_string());
+            event
+        }
+    }
+
+    #[test]
+    fn locked_options_are_hidden_until_discovered() {
+        use crate::galaxy::Discovery;
+
+        let bots = council();
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(ShieldTemplate)];
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let mut orchestrator =
+            GalacticOrchestrator::new(&bots, &templates, OrchestratorConfig::default());
+        let record = orchestrator.play_round(&mut rng);
+        assert_eq!(record.event.options.len(), 2);
+        assert_eq!(record.event.options[0].description, "Option 1");
+
+        let mut galaxy = GalaxyState::new();
+        galaxy.discoveries.push(Discovery {
+            name: "Shield Technology".to_string(),
+            category: "engineering".to_string(),
+            source_round: None,
+            source_event: None,
+        });
+        let mut orchestrator =
+            GalacticOrchestrator::new(&bots, &templates, OrchestratorConfig::default())
+                .with_galaxy(galaxy);
+        let record = orchestrator.play_round(&mut rng);
+        assert_eq!(record.event.options.len(), 3);
+    }
+
+    #[test]
+    fn variety_bot_avoids_recent_council_picks() {
+        use crate::explorer::{NamedMember, VarietyBot};
+
+        let bots: Vec<Box<dyn GalacticCouncilMember>> = vec![
+            Box::new(TracedBot { choice: 0 }),
+            Box::new(TracedBot { choice: 0 }),
+            Box::new(NamedMember::new(
+                "variety",
+                Box::new(VarietyBot::new(Box::new(TracedBot { choice: 0 }), 2)),
+            )),
+        ];
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(ThreeWayTemplate)];
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let report = run_galactic(
+            &bots,
+            &templates,
+            3,
+            OrchestratorConfig::default(),
+            &mut rng,
+        );
+
+        let variety_pick = |round: usize| {
+            report.rounds[round]
+                .votes
+                .iter()
+                .find(|v| v.bot_name == "variety")
+                .unwrap()
+                .chosen_option
+        };
+        assert_eq!(report.rounds[0].winner, 0);
+        assert_eq!(report.rounds[1].winner, 0);
+        assert_eq!(variety_pick(0), 0);
+        assert_ne!(variety_pick(2), 0);
+    }
+
+    #[test]
+    fn zero_rounds_returns_empty_report() {
+        let bots = council();
+        let templates = default_templates();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+        let report = run_galactic(
+            &bots,
+            &templates,
+            0,
+            OrchestratorConfig::default(),
+            &mut rng,
+        );
+
+        assert!(report.rounds.is_empty());
+        assert_eq!(report.score.total, 0);
+        assert!(report.score.history.is_empty());
+        assert_eq!(report.galaxy.round, 0);
+        assert_eq!(
+            GalaxySnapshot::of(&report.galaxy),
+            GalaxySnapshot::of(&GalaxyState::new())
+        );
+        assert_eq!(report.mean_regret(), 0.0);
+        assert_eq!(report.to_timeline_json(), "[]");
+    }
+
+    #[test]
+    fn seeded_galaxy_processes_threats_from_first_round() {
+        use crate::galaxy::Threat;
+
+        let mut galaxy = GalaxyState::with_round(10);
+        for (name, severity) in [("Void Swarm", 2), ("Space Pirates", 3)] {
+            galaxy.threats.push(Threat {
+                name: name.to_string(),
+                severity,
+                rounds_active: 4,
+            });
+        }
+        let bots = council();
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(ThreeWayTemplate)];
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let mut orchestrator =
+            GalacticOrchestrator::new(&bots, &templates, OrchestratorConfig::default())
+                .with_galaxy(galaxy);
+
+        let record = orchestrator.play_round(&mut rng);
+        assert_eq!(record.round, 11);
+        assert_eq!(record.threat_penalty, -15);
+        assert!(orchestrator
+            .galaxy()
+            .threats
+            .iter()
+            .all(|t| t.rounds_active == 5));
+    }
+
+    struct SevenWayTemplate;
+
+    impl EventTemplate for SevenWayTemplate {
+        fn name(&self) -> &'static str {
+            "Seven Way"
+        }
+
+        fn is_applicable(&self, _galaxy: &GalaxyState) -> bool {
+            true
+        }
+
+        fn generate(&self, galaxy: &GalaxyState, rng: &mut dyn RngCore) -> Event {
+            let mut event = ThreeWayTemplate.generate(galaxy, rng);
+            let template = event.options[0].clone();
+            event.options = (0..7)
+                .map(|i| {
+                    let mut option = template.clone();
+                    option.description = format!("Option {}", i);
+                    option.outcome.score_delta = 5;
+                    option
+                })
+                .collect();
+            event
+        }
+    }
+
+    #[test]
+    fn min_options_expands_quiet_period_fallback() {
+        use crate::event::FILLER_OPTIONS;
+
+        let bots = vec![Box::new(TracedBot { choice: 1 }) as Box<dyn GalacticCouncilMember>];
+        let templates: Vec<Box<dyn EventTemplate>> = Vec::new();
+        let config = OrchestratorConfig {
+            min_options: 2,
+            ..OrchestratorConfig::default()
+        };
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let report = run_galactic(&bots, &templates, 1, config, &mut rng);
+
+        let record = &report.rounds[0];
+        assert!(record.event.description.starts_with("A quiet period"));
+        assert_eq!(record.event.options.len(), 2);
+        assert_eq!(record.event.options[0].description, "Continue as normal");
+        assert_eq!(record.event.options[1].description, FILLER_OPTIONS[0].0);
+        assert_eq!(record.winner, 1);
+        assert_eq!(record.score_delta, 0);
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let plain = run_galactic(
+            &bots,
+            &templates,
+            1,
+            OrchestratorConfig::default(),
+            &mut rng,
+        );
+        assert_eq!(plain.rounds[0].event.options.len(), 1);
+    }
+
+    #[test]
+    fn max_options_clamps_with_defer_option() {
+        use crate::event::DEFER_OPTION;
+
+        let bots = vec![Box::new(TracedBot { choice: 3 }) as Box<dyn GalacticCouncilMember>];
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(SevenWayTemplate)];
+        let config = OrchestratorConfig {
+            max_options: Some(4),
+            ..OrchestratorConfig::default()
+        };
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let report = run_galactic(&bots, &templates, 1, config, &mut rng);
+
+        let record = &report.rounds[0];
+        assert_eq!(record.event.options.len(), 4);
+        assert_eq!(record.event.options[2].description, "Option 2");
+        assert_eq!(record.event.options[3].description, DEFER_OPTION);
+        assert_eq!(record.winner, 3);
+        assert_eq!(record.score_delta, 0);
+        assert!(record.outcome().state_changes.is_empty());
+
+        let unclamped = run_galactic(
+            &bots,
+            &templates,
+            1,
+            OrchestratorConfig::default(),
+            &mut rng,
+        );
+        assert_eq!(unclamped.rounds[0].event.options.len(), 7);
+    }
+
+    #[test]
+    fn influence_by_tag_sums_winning_expertise() {
+        let bots: Vec<Box<dyn GalacticCouncilMember>> = vec![
+            Box::new(ExpertBot {
+                choice: 1,
+                expertise: &[("diplomacy", 0.8), ("military", 0.9)],
+            }),
+            Box::new(ExpertBot {
+                choice: 1,
+                expertise: &[("diplomacy", 0.2)],
+            }),
+            Box::new(ExpertBot {
+                choice: 0,
+                expertise: &[("diplomacy", 0.4)],
+            }),
+        ];
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(SummitTemplate)];
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let report = run_galactic(
+            &bots,
+            &templates,
+            2,
+            OrchestratorConfig::default(),
+            &mut rng,
+        );
+
+        assert!(report.rounds.iter().all(|r| r.winner == 1));
+        // Per round: 0.5 * 0.8 + 0.5 * 0.2 from the two winning backers.
+        let diplomacy = report.influence_by_tag["diplomacy"];
+        assert!((diplomacy - 1.0).abs() < 1e-6, "{}", diplomacy);
+        // The event has no military tag, and the losing vote counts for nothing.
+        assert_eq!(report.influence_by_tag.len(), 1);
+    }
+
+    const THIRD_PATH: &str = "Seek a third path";
+
+    #[derive(Clone)]
+    struct ProposerBot;
+
+    impl GalacticCouncilMember for ProposerBot {
+        fn name(&self) -> &'static str {
+            "proposer-bot"
+        }
+
+        fn expertise(&self) -> &[(&'static str, f32)] {
+            &[]
+        }
+
+        fn vote(&self, event: &Event, _galaxy: &GalaxyState) -> usize {
+            event
+                .options
+                .iter()
+                .position(|o| o.description == THIRD_PATH)
+                .unwrap_or(0)
+        }
+
+        fn propose_option(&self, _event: &Event, _galaxy: &GalaxyState) -> Option<ResponseOption> {
+            Some(ResponseOption {
+                description: THIRD_PATH.to_string(),
+                outcome: Outcome {
+                    description: "An unexpected compromise pleases everyone.".to_string(),
+                    score_delta: 12,
+                    headline: None,
+                    state_changes: vec![],
+                },
+                required_discovery: None,
+            })
+        }
+    }
+
+    #[test]
+    fn proposed_option_is_added_and_can_win() {
+        let bots: Vec<Box<dyn GalacticCouncilMember>> = vec![
+            Box::new(ProposerBot),
+            Box::new(ProposerBot),
+            Box::new(TracedBot { choice: 0 }),
+        ];
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(ThreeWayTemplate)];
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+
+        let off = run_galactic(
+            &bots,
+            &templates,
+            1,
+            OrchestratorConfig::default(),
+            &mut rng,
+        );
+        assert_eq!(off.rounds[0].event.options.len(), 3);
+
+        let config = OrchestratorConfig {
+            max_proposals: 1,
+            ..OrchestratorConfig::default()
+        };
+        let report = run_galactic(&bots, &templates, 1, config, &mut rng);
+        let record = &report.rounds[0];
+        assert_eq!(record.event.options.len(), 4);
+        assert_eq!(record.event.options[3].description, THIRD_PATH);
+        assert_eq!(record.winner, 3);
+        assert_eq!(record.score_delta, 12);
+    }
+
+    #[test]
+    fn max_options_caps_proposals_too() {
+        use crate::event::DEFER_OPTION;
+
+        let bots: Vec<Box<dyn GalacticCouncilMember>> =
+            vec![Box::new(ProposerBot), Box::new(ProposerBot)];
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(ThreeWayTemplate)];
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+
+        let room = OrchestratorConfig {
+            max_proposals: 1,
+            max_options: Some(4),
+            ..OrchestratorConfig::default()
+        };
+        let report = run_galactic(&bots, &templates, 1, room, &mut rng);
+        let options = &report.rounds[0].event.options;
+        assert_eq!(options.len(), 4);
+        assert_eq!(options[3].description, THIRD_PATH);
+
+        let tight = OrchestratorConfig {
+            max_proposals: 2,
+            max_options: Some(3),
+            ..OrchestratorConfig::default()
+        };
+        let report = run_galactic(&bots, &templates, 1, tight, &mut rng);
+        let options = &report.rounds[0].event.options;
+        assert_eq!(options.len(), 3);
+        assert_eq!(options[2].description, DEFER_OPTION);
+        assert!(options.iter().all(|o| o.description != THIRD_PATH));
+    }
+
+    #[test]
+    fn discoveries_are_tagged_with_their_source() {
+        let bots = council();
+        let templates = default_templates();
+        let names: Vec<&str> = templates.iter().map(|t| t.name()).collect();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let report = run_galactic(
+            &bots,
+            &templates,
+            25,
+            OrchestratorConfig::default(),
+            &mut rng,
+        );
+
+        assert!(!report.galaxy.discoveries.is_empty());
+        for discovery in &report.galaxy.discoveries {
+            let round = discovery.source_round.expect("untagged discovery");
+            assert!((1..=25).contains(&round));
+            let source = discovery.source_event.as_deref().unwrap();
+            assert!(names.contains(&source), "unknown source {}", source);
+        }
+    }
+
+    #[test]
+    fn hashed_tie_break_spreads_tied_rounds() {
+        let bots: Vec<Box<dyn GalacticCouncilMember>> = (0..3)
+            .map(|choice| Box::new(TracedBot { choice }) as Box<dyn GalacticCouncilMember>)
+            .collect();
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(ThreeWayTemplate)];
+        let winners = |hashed_tie_break| {
+            let config = OrchestratorConfig {
+                hashed_tie_break,
+                ..OrchestratorConfig::default()
+            };
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            run_galactic(&bots, &templates, 12, config, &mut rng)
+                .rounds
+                .iter()
+                .map(|r| r.winner)
+                .collect::<Vec<_>>()
+        };
+
+        assert!(winners(false).iter().all(|&w| w == 0));
+        let hashed = winners(true);
+        assert!(hashed.iter().any(|&w| w != 0), "{:?}", hashed);
+        assert_eq!(winners(true), hashed);
+    }
+
+    #[test]
+    fn rng_log_replays_identically() {
+        let bots = council();
+        let templates = default_templates();
+        let config = OrchestratorConfig {
+            record_rng: true,
+            ..OrchestratorConfig::default()
+        };
+        let mut rng = rand::rngs::StdRng::seed_from_u64(11);
+        let report = run_galactic(&bots, &templates, 6, config.clone(), &mut rng);
+        assert!(report.rounds.iter().all(|r| !r.rng_draws.is_empty()));
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(11);
+        let mut replay = GalacticOrchestrator::new(&bots, &templates, config.clone());
+        for recorded in &report.rounds {
+            assert_eq!(replay.play_round(&mut rng).rng_draws, recorded.rng_draws);
+        }
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(11);
+        assert_eq!(
+            verify_against_rng_log(&report, &bots, &templates, config.clone(), &mut rng),
+            Ok(())
+        );
+        let mut rng = rand::rngs::StdRng::seed_from_u64(12);
+        let err = verify_against_rng_log(&report, &bots, &templates, config, &mut rng);
+        assert!(err.unwrap_err().starts_with("round 1:"));
+    }
+
+    #[test]
+    fn rng_log_is_empty_when_off() {
+        let bots = council();
+        let templates = default_templates();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(11);
+        let report = run_galactic(
+            &bots,
+            &templates,
+            3,
+            OrchestratorConfig::default(),
+            &mut rng,
+        );
+        assert!(report.rounds.iter().all(|r| r.rng_draws.is_empty()));
+    }
+
+    #[derive(Clone)]
+    struct UnsureBot {
+        choice: usize,
+        confidence: f32,
+    }
+
+    impl GalacticCouncilMember for UnsureBot {
+        fn name(&self) -> &'static str {
+            "unsure-bot"
+        }
+
+        fn expertise(&self) -> &[(&'static str, f32)] {
+            &[("diplomacy", 0.8)]
+        }
+
+        fn vote(&self, _event: &Event, _galaxy: &GalaxyState) -> usize {
+            self.choice
+        }
+
+        fn confidence(&self, _event: &Event, _galaxy: &GalaxyState) -> f32 {
+            self.confidence
+        }
+    }
+
+    #[test]
+    fn confidence_scales_vote_weight() {
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(SummitTemplate)];
+        let round = |confidence: f32| {
+            let bots: Vec<Box<dyn GalacticCouncilMember>> = vec![
+                Box::new(UnsureBot {
+                    choice: 1,
+                    confidence,
+                }),
+                Box::new(TracedBot { choice: 0 }),
+            ];
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            run_galactic(
+                &bots,
+                &templates,
+                1,
+                OrchestratorConfig::default(),
+                &mut rng,
+            )
+            .rounds
+            .remove(0)
+        };
+
+        // Full confidence keeps today's weight: base + 0.5 * 0.8.
+        let sure = round(1.0);
+        assert!((sure.votes[0].weight - 0.5).abs() < 1e-6);
+        assert_eq!(sure.winner, 1);
+
+        let unsure = round(0.0);
+        assert_eq!(unsure.votes[0].weight, 0.0);
+        assert_eq!(unsure.winner, 0);
+    }
+
+    #[test]
+    fn score_decay_applies_before_round_delta() {
+        let bots: Vec<Box<dyn GalacticCouncilMember>> = vec![Box::new(TracedBot { choice: 0 })];
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(ThreeWayTemplate)];
+        let config = OrchestratorConfig {
+            score_decay_per_round: Some(0.9),
+            ..OrchestratorConfig::default()
+        };
+        let mut orchestrator = GalacticOrchestrator::new(&bots, &templates, config);
+        orchestrator.score.add(0, 100, "Head start");
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let record = orchestrator.play_round(&mut rng);
+        assert_eq!(record.score_delta, 0);
+        assert_eq!(record.score_total, 90);
+    }
+
+    struct PaidSurveyTemplate;
+
+    impl EventTemplate for PaidSurveyTemplate {
+        fn name(&self) -> &'static str {
+            "Paid Survey"
+        }
+
+        fn is_applicable(&self, _galaxy: &GalaxyState) -> bool {
+            true
+        }
+
+        fn generate(&self, galaxy: &GalaxyState, rng: &mut dyn RngCore) -> Event {
+            let mut event = SurveyTemplate.generate(galaxy, rng);
+            event.options[0].outcome.score_delta = 3;
+            event
+        }
+    }
+
+    /// A one-option festival worth 1 point plus 2 per ally.
+    struct FestivalTemplate;
+
+    impl EventTemplate for FestivalTemplate {
+        fn name(&self) -> &'static str {
+            "Festival"
+        }
+
+        fn is_applicable(&self, _galaxy: &GalaxyState) -> bool {
+            true
+        }
+
+        fn generate(&self, _galaxy: &GalaxyState, _rng: &mut dyn RngCore) -> Event {
+            Event {
+                description: "A festival spreads across allied worlds.".to_string(),
+                relevant_expertise: vec![],
+                options: vec![ResponseOption {
+                    description: "Join the celebrations".to_string(),
+                    outcome: Outcome {
+                        description: "Morale soars.".to_string(),
+                        score_delta: 1,
+                        headline: None,
+                        state_changes: vec![crate::galaxy::StateChange::ScoreScaledBy {
+                            per_ally: 2,
+                            per_sector: 0,
+                        }],
+                    },
+                    required_discovery: None,
+                }],
+            }
+        }
+    }
+
+    #[test]
+    fn score_scaled_by_allies_adds_to_round_delta() {
+        let bots = council();
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(FestivalTemplate)];
+        let mut galaxy = GalaxyState::new();
+        for species in ["Velari", "Korth", "Zenthi"] {
+            galaxy
+                .relations
+                .insert(species.to_string(), crate::galaxy::Relation::Allied);
+        }
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let mut orchestrator =
+            GalacticOrchestrator::new(&bots, &templates, OrchestratorConfig::default())
+                .with_galaxy(galaxy);
+
+        let record = orchestrator.play_round(&mut rng);
+        assert_eq!(record.score_delta, 1 + 6);
+        assert_eq!(record.score_total, 7);
+    }
+
+    /// [`SurveyTemplate`] whose first option also earns 1 per sector.
+    struct ScaledSurveyTemplate;
+
+    impl EventTemplate for ScaledSurveyTemplate {
+        fn name(&self) -> &'static str {
+            "Scaled Survey"
+        }
+
+        fn is_applicable(&self, _galaxy: &GalaxyState) -> bool {
+            true
+        }
+
+        fn generate(&self, galaxy: &GalaxyState, rng: &mut dyn RngCore) -> Event {
+            let mut event = SurveyTemplate.generate(galaxy, rng);
+            event.options[0]
+                .outcome
+                .state_changes
+                .push(StateChange::ScoreScaledBy {
+                    per_ally: 0,
+                    per_sector: 1,
+                });
+            event
+        }
+    }
+
+    #[test]
+    fn scaled_score_ignores_mission_capacity() {
+        let bots = council();
+        let config = OrchestratorConfig {
+            max_state_changing_rounds: Some(1),
+            ..OrchestratorConfig::default()
+        };
+
+        // Scaled-only outcomes never count against the budget.
+        let festival: Vec<Box<dyn EventTemplate>> = vec![Box::new(FestivalTemplate)];
+        let mut galaxy = GalaxyState::new();
+        galaxy
+            .relations
+            .insert("Velari".to_string(), crate::galaxy::Relation::Allied);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let mut orchestrator =
+            GalacticOrchestrator::new(&bots, &festival, config.clone()).with_galaxy(galaxy);
+        for _ in 0..3 {
+            let record = orchestrator.play_round(&mut rng);
+            assert!(!record.changes_skipped);
+            assert_eq!(record.score_delta, 1 + 2);
+        }
+
+        // Once capacity is spent the sector is skipped, but the scaled
+        // earnings are still paid against the galaxy as it stands.
+        let surveys: Vec<Box<dyn EventTemplate>> = vec![Box::new(ScaledSurveyTemplate)];
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        let report = run_galactic(&bots, &surveys, 2, config, &mut rng);
+        let earned: Vec<(bool, i32)> = report
+            .rounds
+            .iter()
+            .map(|r| (r.changes_skipped, r.score_delta - r.outcome().score_delta))
+            .collect();
+        assert_eq!(earned, vec![(false, 2), (true, 2)]);
+    }
+
+    #[test]
+    fn state_change_budget_skips_changes_but_keeps_score() {
+        let bots = council();
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(PaidSurveyTemplate)];
+        let config = OrchestratorConfig {
+            max_state_changing_rounds: Some(2),
+            ..OrchestratorConfig::default()
+        };
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        let report = run_galactic(&bots, &templates, 3, config, &mut rng);
+
+        let skipped: Vec<bool> = report.rounds.iter().map(|r| r.changes_skipped).collect();
+        assert_eq!(skipped, vec![false, false, true]);
+        // Home sector plus the first two surveys; round 3's sector is skipped.
+        assert_eq!(report.galaxy.explored_sectors.len(), 3);
+        assert!(!report
+            .galaxy
+            .explored_sectors
+            .iter()
+            .any(|s| s.name == "Sector 3"));
+        assert_eq!(report.rounds[2].score_delta, 3);
+        assert_eq!(report.score.total, 9);
+        assert_eq!(
+            report
+                .to_narrative_markdown()
+                .matches("Mission capacity is spent")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn several_events_per_round_share_the_round() {
+        let bots = council();
+        let templates = default_templates();
+        let config = OrchestratorConfig {
+            events_per_round: 3,
+            record_rng: true,
+            ..OrchestratorConfig::default()
+        };
+        let mut rng = rand::rngs::StdRng::seed_from_u64(4);
+        let report = run_galactic(&bots, &templates, 2, config.clone(), &mut rng);
+
+        let rounds: Vec<u32> = report.rounds.iter().map(|r| r.round).collect();
+        assert_eq!(rounds, vec![1, 1, 1, 2, 2, 2]);
+        assert_eq!(report.rounds_played(), 2);
+        let story = report.to_narrative_markdown();
+        assert_eq!(story.matches("## Round 1 — ").count(), 3);
+        assert!(story.contains("Rounds played: 2"));
+        // Threats only bite once per round, after its last event.
+        assert!(report
+            .rounds
+            .iter()
+            .filter(|r| r.round == 1)
+            .take(2)
+            .all(|r| r.threat_penalty == 0));
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(4);
+        assert_eq!(
+            verify_against_rng_log(&report, &bots, &templates, config, &mut rng),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn abstaining_bot_casts_no_vote() {
+        use crate::explorer::SpecialistBot;
+
+        // SummitTemplate only involves diplomacy, outside TracedBot's science.
+        let bots: Vec<Box<dyn GalacticCouncilMember>> = vec![
+            Box::new(SpecialistBot::new(Box::new(TracedBot { choice: 0 }), 0.3)),
+            Box::new(UnsureBot {
+                choice: 1,
+                confidence: 1.0,
+            }),
+        ];
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(SummitTemplate)];
+        let config = OrchestratorConfig {
+            resolution: ResolutionMode::Plurality,
+            ..OrchestratorConfig::default()
+        };
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let report = run_galactic(&bots, &templates, 1, config, &mut rng);
+
+        let record = &report.rounds[0];
+        assert_eq!(record.votes.len(), 1);
+        assert_eq!(record.votes[0].bot_name, "unsure-bot");
+        assert_eq!(record.winner, 1);
+        assert!(report.influence_by_tag["diplomacy"] > 0.0);
+    }
+
+    /// Sits out every event.
+    #[derive(Clone)]
+    struct ShyBot;
+
+    impl GalacticCouncilMember for ShyBot {
+        fn name(&self) -> &'static str {
+            "shy-bot"
+        }
+
+        fn expertise(&self) -> &[(&'static str, f32)] {
+            &[("diplomacy", 1.0)]
+        }
+
+        fn vote(&self, _event: &Event, _galaxy: &GalaxyState) -> usize {
+            2
+        }
+
+        fn will_vote(&self, _event: &Event, _galaxy: &GalaxyState) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn members_that_will_not_vote_leave_the_rest_to_decide() {
+        let bots: Vec<Box<dyn GalacticCouncilMember>> = vec![
+            Box::new(ShyBot),
+            Box::new(ShyBot),
+            Box::new(TracedBot { choice: 1 }),
+        ];
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(SummitTemplate)];
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let report = run_galactic(
+            &bots,
+            &templates,
+            1,
+            OrchestratorConfig::default(),
+            &mut rng,
+        );
+
+        let record = &report.rounds[0];
+        assert_eq!(record.votes.len(), 1);
+        assert_eq!(record.votes[0].bot_name, "fixed-bot");
+        assert_eq!(record.winner, 1);
+        assert!(!record.all_abstained);
+        assert!(!report.to_markdown().contains("every member abstained"));
+    }
+
+    #[test]
+    fn declining_faction_members_get_no_vote_or_influence() {
+        let bots: Vec<Box<dyn GalacticCouncilMember>> = vec![];
+        let factions = vec![Faction::new(
+            "Bloc",
+            vec![Box::new(ShyBot), Box::new(TracedBot { choice: 1 })],
+            1.0,
+        )];
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(SummitTemplate)];
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let mut orchestrator =
+            GalacticOrchestrator::new(&bots, &templates, OrchestratorConfig::default())
+                .with_factions(&factions);
+
+        let record = orchestrator.play_round(&mut rng);
+        assert_eq!(record.votes.len(), 1);
+        assert_eq!(record.votes[0].bot_name, "fixed-bot");
+        assert_eq!(record.winner, 1);
+        // Only the diplomat declined, so no diplomacy influence is credited.
+        let report = orchestrator.finish();
+        assert!(!report.influence_by_tag.contains_key("diplomacy"));
+    }
+
+    #[test]
+    fn council_that_fully_abstains_falls_back_to_first_option() {
+        use crate::explorer::CheckedMember;
+
+        let bots: Vec<Box<dyn GalacticCouncilMember>> = vec![
+            Box::new(ShyBot),
+            Box::new(CheckedMember::new(Box::new(ShyBot))),
+        ];
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(SummitTemplate)];
+        for resolution in [
+            ResolutionMode::WeightedPlurality,
+            ResolutionMode::Plurality,
+            ResolutionMode::Approval,
+            ResolutionMode::RankedChoice,
+        ] {
+            let config = OrchestratorConfig {
+                resolution,
+                hashed_tie_break: true,
+                ..OrchestratorConfig::default()
+            };
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            let report = run_galactic(&bots, &templates, 1, config, &mut rng);
+
+            let record = &report.rounds[0];
+            assert!(record.votes.is_empty());
+            assert!(record.all_abstained);
+            assert_eq!(record.winner, 0);
+            assert!(report.to_markdown().contains("- every member abstained\n"));
+        }
+    }
+
+    /// Golden transcript for `run_golden(7, 6)`; regenerate with
+    /// `UPDATE_GOLDEN=1 cargo test -p council-core golden`.
+    const GOLDEN_PATH: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/testdata/golden_seed7_6rounds.md"
+    );
+
+    /// Full deterministic transcript of a small mixed council on the default
+    /// templates: the Markdown transcript followed by the timeline JSON.
+    fn run_golden(seed: u64, rounds: u32) -> String {
+        let bots: Vec<Box<dyn GalacticCouncilMember>> = vec![
+            Box::new(TracedBot { choice: 0 }),
+            Box::new(ExpertBot {
+                choice: 1,
+                expertise: &[("diplomacy", 0.9), ("exploration", 0.8)],
+            }),
+            Box::new(ExpertBot {
+                choice: 2,
+                expertise: &[("military", 0.9), ("engineering", 0.7)],
+            }),
+        ];
+        let templates = default_templates();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        let report = run_galactic(
+            &bots,
+            &templates,
+            rounds,
+            OrchestratorConfig::default(),
+            &mut rng,
+        );
+        format!("{}\n{}\n", report.to_markdown(), report.to_timeline_json())
+    }
+
+    #[test]
+    fn golden_transcript_is_unchanged() {
+        let actual = run_golden(7, 6);
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            std::fs::write(GOLDEN_PATH, &actual).expect("write golden file");
+            return;
+        }
+        let expected = std::fs::read_to_string(GOLDEN_PATH)
+            .expect("golden file missing; run with UPDATE_GOLDEN=1 to create it");
+        assert!(
+            actual == expected,
+            "galactic transcript changed; if intended, rerun with UPDATE_GOLDEN=1\n\
+             --- expected\n{}\n--- actual\n{}",
+            expected,
+            actual
+        );
+    }
+
+    #[derive(Clone)]
+    struct PromptBot {
+        prompts: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    impl GalacticCouncilMember for PromptBot {
+        fn name(&self) -> &'static str {
+            "prompt-bot"
+        }
+
+        fn expertise(&self) -> &[(&'static str, f32)] {
+            &[]
+        }
+
+        fn vote(&self, event: &Event, galaxy: &GalaxyState) -> usize {
+            let prompt = crate::ollama::build_galactic_prompt("", event, galaxy);
+            self.prompts.lock().unwrap().push(prompt);
+            0
+        }
+    }
+
+    #[test]
+    fn reveal_outcomes_briefs_the_prompt() {
+        let prompt_for = |reveal_outcomes: bool| {
+            let bot = PromptBot {
+                prompts: Default::default(),
+            };
+            let bots: Vec<Box<dyn GalacticCouncilMember>> = vec![Box::new(bot.clone())];
+            let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(PaidSurveyTemplate)];
+            let config = OrchestratorConfig {
+                reveal_outcomes,
+                ..OrchestratorConfig::default()
+            };
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            run_galactic(&bots, &templates, 1, config, &mut rng);
+            let prompt = bot.prompts.lock().unwrap().remove(0);
+            prompt
+        };
+
+        let briefed = prompt_for(true);
+        assert!(briefed.contains("0: Survey -> Sector charted. (score +3)"));
+        let blind = prompt_for(false);
+        assert!(!blind.contains("Sector charted."));
+        assert!(!blind.contains("score +3"));
+    }
+}
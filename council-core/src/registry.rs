@@ -0,0 +1,82 @@
+//! Name-based lookup of galactic bots.
+//!
+//! Bot crates depend on `council-core`, so the core cannot construct them
+//! itself; binaries register the bots they link against and the core resolves
+//! names (e.g. from a scenario file) through the registry.
+
+use crate::explorer::GalacticCouncilMember;
+
+/// Constructor for a registered bot.
+pub type BotFactory = fn() -> Box<dyn GalacticCouncilMember>;
+
+/// Maps bot names to constructors.
+#[derive(Debug, Clone, Default)]
+pub struct BotRegistry {
+    factories: Vec<(&'static str, BotFactory)>,
+}
+
+impl BotRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a bot under `name`, replacing any earlier registration.
+    pub fn register(mut self, name: &'static str, factory: BotFactory) -> Self {
+        self.factories.retain(|(n, _)| *n != name);
+        self.factories.push((name, factory));
+        self
+    }
+
+    /// Construct the bot registered as `name`.
+    pub fn build(&self, name: &str) -> Option<Box<dyn GalacticCouncilMember>> {
+        self.factories
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, factory)| factory())
+    }
+
+    /// Registered names in registration order.
+    pub fn names(&self) -> Vec<&'static str> {
+        self.factories.iter().map(|(n, _)| *n).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::Event;
+    use crate::galaxy::GalaxyState;
+
+    #[derive(Clone)]
+    struct TestBot;
+
+    impl GalacticCouncilMember for TestBot {
+        fn name(&self) -> &'static str {
+            "test-bot"
+        }
+
+        fn expertise(&self) -> &[(&'static str, f32)] {
+            &[]
+        }
+
+        fn vote(&self, _event: &Event, _galaxy: &GalaxyState) -> usize {
+            0
+        }
+    }
+
+    #[test]
+    fn builds_registered_bots_by_name() {
+        let registry = BotRegistry::new().register("test-bot", || Box::new(TestBot));
+        assert_eq!(registry.names(), vec!["test-bot"]);
+        assert_eq!(registry.build("test-bot").unwrap().name(), "test-bot");
+        assert!(registry.build("missing-bot").is_none());
+    }
+
+    #[test]
+    fn re_registering_replaces() {
+        let registry = BotRegistry::new()
+            .register("test-bot", || Box::new(TestBot))
+            .register("test-bot", || Box::new(TestBot));
+        assert_eq!(registry.names().len(), 1);
+    }
+}
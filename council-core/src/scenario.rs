@@ -0,0 +1,154 @@
+//! Declarative, reproducible simulation runs loaded from JSON.
+//!
+//! A scenario pins down the initial galaxy, the council, the seed and the
+//! round count, so a run can be attached to a bug report and replayed exactly.
+//!
+//! ```json
+//! {
+//!   "seed": 42,
+//!   "rounds": 10,
+//!   "bots": ["oracle-bot", "cycle-bot"],
+//!   "galaxy": { "morale": 70 }
+//! }
+//! ```
+
+use crate::explorer::GalacticCouncilMember;
+use crate::galaxy::GalaxyState;
+use crate::orchestrator::{GalacticOrchestrator, GalacticReport, OrchestratorConfig};
+use crate::registry::BotRegistry;
+use crate::templates::default_templates;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+
+/// A complete, deterministic simulation setup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Scenario {
+    /// RNG seed for event generation.
+    pub seed: u64,
+    /// Number of rounds to play.
+    pub rounds: u32,
+    /// Registered bot names making up the council.
+    pub bots: Vec<String>,
+    /// Starting galaxy; omitted fields come from [`GalaxyState::new`].
+    #[serde(default)]
+    pub galaxy: GalaxyState,
+}
+
+impl Scenario {
+    /// Parse a scenario from JSON text.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("invalid scenario: {}", e))
+    }
+
+    /// Read and parse a scenario file.
+    pub fn load(path: &str) -> Result<Self, String> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read scenario {}: {}", path, e))?;
+        Self::from_json(&json)
+    }
+
+    /// Build the council from the registry, failing on unknown names.
+    pub fn build_council(
+        &self,
+        registry: &BotRegistry,
+    ) -> Result<Vec<Box<dyn GalacticCouncilMember>>, String> {
+        self.bots
+            .iter()
+            .map(|name| {
+                registry.build(name).ok_or_else(|| {
+                    format!(
+                        "unknown bot '{}' (known: {})",
+                        name,
+                        registry.names().join(", ")
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Play the scenario with an already-built council and the default templates.
+    pub fn run(&self, bots: &[Box<dyn GalacticCouncilMember>]) -> GalacticReport {
+        let templates = default_templates();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(self.seed);
+        let mut orchestrator =
+            GalacticOrchestrator::new(bots, &templates, OrchestratorConfig::default())
+                .with_galaxy(self.galaxy.clone());
+        for _ in 0..self.rounds {
+            orchestrator.play_round(&mut rng);
+        }
+        orchestrator.finish()
+    }
+}
+
+/// Load a scenario file, build its council from `registry`, and run it.
+pub fn run_scenario(path: &str, registry: &BotRegistry) -> Result<GalacticReport, String> {
+    let scenario = Scenario::load(path)?;
+    let bots = scenario.build_council(registry)?;
+    Ok(scenario.run(&bots))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::galaxy::Relation;
+    use crate::test_util::FixedBot;
+
+    fn registry() -> BotRegistry {
+        BotRegistry::new().register("first-option", || Box::new(FixedBot { choice: 0 }))
+    }
+
+    const SCENARIO: &str = r#"{
+        "seed": 11,
+        "rounds": 6,
+        "bots": ["first-option", "first-option"],
+        "galaxy": {
+            "morale": 80,
+            "relations": { "Velari": "Friendly" },
+            "known_species": [{ "name": "Velari", "traits": ["curious"] }]
+        }
+    }"#;
+
+    #[test]
+    fn scenario_galaxy_fills_missing_fields() {
+        let scenario = Scenario::from_json(SCENARIO).unwrap();
+        assert_eq!(scenario.galaxy.morale, 80);
+        assert_eq!(
+            scenario.galaxy.relations.get("Velari"),
+            Some(&Relation::Friendly)
+        );
+        // Not listed, so taken from a fresh galaxy
+        assert_eq!(scenario.galaxy.explored_sectors.len(), 1);
+    }
+
+    #[test]
+    fn run_scenario_is_deterministic() {
+        // Unique per process and run, so concurrent test runs don't collide.
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos());
+        let path = std::env::temp_dir().join(format!(
+            "council_scenario_test_{}_{}.json",
+            std::process::id(),
+            nanos
+        ));
+        std::fs::write(&path, SCENARIO).unwrap();
+        let path = path.to_str().unwrap();
+
+        let a = run_scenario(path, &registry()).unwrap();
+        let b = run_scenario(path, &registry()).unwrap();
+        let _ = std::fs::remove_file(path);
+
+        assert_eq!(a.rounds.len(), 6);
+        assert_eq!(a.score.total, b.score.total);
+        let winners = |r: &GalacticReport| r.rounds.iter().map(|x| x.winner).collect::<Vec<_>>();
+        assert_eq!(winners(&a), winners(&b));
+    }
+
+    #[test]
+    fn unknown_bot_is_an_error() {
+        let scenario =
+            Scenario::from_json(r#"{"seed": 1, "rounds": 1, "bots": ["ghost-bot"]}"#).unwrap();
+        let err = scenario.build_council(&registry()).err().unwrap();
+        assert!(err.contains("ghost-bot"));
+    }
+}
@@ -1,5 +1,7 @@
 //! Score tracking for the simulation.
 
+use std::fmt;
+
 /// Tracks cumulative score throughout the simulation.
 #[derive(Debug, Clone, Default)]
 pub struct ScoreTracker {
@@ -20,6 +22,51 @@ pub struct ScoreEvent {
     pub reason: String,
 }
 
+/// Sign of the score changes in a streak.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreakKind {
+    Positive,
+    Negative,
+    /// Zero deltas (or no history at all).
+    Neutral,
+}
+
+impl StreakKind {
+    fn of(delta: i32) -> Self {
+        match delta.signum() {
+            1 => StreakKind::Positive,
+            -1 => StreakKind::Negative,
+            _ => StreakKind::Neutral,
+        }
+    }
+}
+
+/// Game length the fixed rating thresholds were tuned for.
+const REFERENCE_ROUNDS: u32 = 25;
+
+/// A rating label with the numbers behind it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RatingDetail {
+    /// Rating label (same as `rating_for_rounds`).
+    pub label: &'static str,
+    /// Total score.
+    pub score: i32,
+    /// Rough score ceiling: rounds × best single delta.
+    pub max_possible_estimate: i32,
+    /// Score as a percentage of the ceiling (0 when the ceiling is 0).
+    pub percentile: f32,
+}
+
+impl fmt::Display for RatingDetail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}/~{}, ~{:.0}%)",
+            self.label, self.score, self.max_possible_estimate, self.percentile
+        )
+    }
+}
+
 impl ScoreTracker {
     /// Create a new score tracker.
     pub fn new() -> Self {
@@ -36,14 +83,109 @@ impl ScoreTracker {
         });
     }
 
+    /// Scale the total by `factor`, recording the loss (or, for a negative
+    /// total, the recovery) as a "Score decay" event. Returns the change;
+    /// nothing is recorded when it rounds to zero.
+    pub fn decay(&mut self, round: u32, factor: f32) -> i32 {
+        let decayed = (self.total as f32 * factor).round() as i32;
+        let delta = decayed - self.total;
+        if delta != 0 {
+            self.add(round, delta, "Score decay");
+        }
+        delta
+    }
+
+    /// The last `window` events (all of them if the history is shorter).
+    fn recent(&self, window: usize) -> &[ScoreEvent] {
+        &self.history[self.history.len().saturating_sub(window)..]
+    }
+
+    /// Summed delta over the last `window` events; positive means the
+    /// council is on an upswing.
+    pub fn recent_trend(&self, window: usize) -> i32 {
+        self.recent(window).iter().map(|e| e.delta).sum()
+    }
+
+    /// Mean delta over the last `window` events (0.0 when there are none).
+    pub fn moving_average(&self, window: usize) -> f32 {
+        let recent = self.recent(window);
+        if recent.is_empty() {
+            return 0.0;
+        }
+        self.recent_trend(window) as f32 / recent.len() as f32
+    }
+
+    /// Kind and length of the run of same-sign score events ending with the
+    /// most recent one. A zero delta breaks positive and negative streaks.
+    pub fn current_streak(&self) -> (StreakKind, u32) {
+        let Some(last) = self.history.last() else {
+            return (StreakKind::Neutral, 0);
+        };
+        let kind = StreakKind::of(last.delta);
+        let length = self
+            .history
+            .iter()
+            .rev()
+            .take_while(|e| StreakKind::of(e.delta) == kind)
+            .count();
+        (kind, length as u32)
+    }
+
+    /// Longest run of consecutive positive score events.
+    pub fn longest_positive_streak(&self) -> u32 {
+        let mut longest = 0;
+        let mut current = 0;
+        for event in &self.history {
+            if event.delta > 0 {
+                current += 1;
+                longest = longest.max(current);
+            } else {
+                current = 0;
+            }
+        }
+        longest
+    }
+
     /// Get the rating based on total score (for a 25-round game).
     pub fn rating(&self) -> &'static str {
-        match self.total {
-            200.. => "Legendary Council",
-            150..=199 => "Distinguished",
-            100..=149 => "Competent",
-            50..=99 => "Struggling",
-            _ => "Dysfunctional",
+        self.rating_for_rounds(REFERENCE_ROUNDS)
+    }
+
+    /// Get the rating with thresholds scaled linearly to the game length.
+    pub fn rating_for_rounds(&self, rounds: u32) -> &'static str {
+        // Compare total * 25 against threshold * rounds to avoid float rounding.
+        let scaled = self.total as i64 * REFERENCE_ROUNDS as i64;
+        let rounds = rounds.max(1) as i64;
+        if scaled >= 200 * rounds {
+            "Legendary Council"
+        } else if scaled >= 150 * rounds {
+            "Distinguished"
+        } else if scaled >= 100 * rounds {
+            "Competent"
+        } else if scaled >= 50 * rounds {
+            "Struggling"
+        } else {
+            "Dysfunctional"
+        }
+    }
+
+    /// Rating label plus context on how close the score came to a rough ceiling.
+    ///
+    /// The ceiling is `rounds × best single delta` seen so far, so it is an
+    /// estimate, not a true maximum.
+    pub fn rating_detail(&self, rounds: u32) -> RatingDetail {
+        let best = self.best_moment().map(|e| e.delta).unwrap_or(0).max(0);
+        let max_possible_estimate = best * rounds as i32;
+        let percentile = if max_possible_estimate > 0 {
+            self.total as f32 / max_possible_estimate as f32 * 100.0
+        } else {
+            0.0
+        };
+        RatingDetail {
+            label: self.rating_for_rounds(rounds),
+            score: self.total,
+            max_possible_estimate,
+            percentile,
         }
     }
 
@@ -78,6 +220,69 @@ mod tests {
         assert_eq!(tracker.history.len(), 2);
     }
 
+    #[test]
+    fn decay_records_synthetic_loss() {
+        let mut tracker = ScoreTracker::new();
+        tracker.add(1, 100, "Windfall");
+        assert_eq!(tracker.decay(2, 0.9), -10);
+        assert_eq!(tracker.total, 90);
+        assert_eq!(tracker.history[1].reason, "Score decay");
+
+        let mut empty = ScoreTracker::new();
+        assert_eq!(empty.decay(1, 0.5), 0);
+        assert!(empty.history.is_empty());
+    }
+
+    #[test]
+    fn trend_and_average_use_last_window() {
+        let mut tracker = ScoreTracker::new();
+        assert_eq!(tracker.recent_trend(3), 0);
+        assert_eq!(tracker.moving_average(3), 0.0);
+
+        for (round, delta) in [(1, 20), (2, -5), (3, 4), (4, 6)] {
+            tracker.add(round, delta, "event");
+        }
+        assert_eq!(tracker.recent_trend(2), 10);
+        assert_eq!(tracker.moving_average(2), 5.0);
+        // (-5 + 4 + 6) / 3 is not rounded to an integer.
+        assert_eq!(tracker.recent_trend(3), 5);
+        assert!((tracker.moving_average(3) - 5.0 / 3.0).abs() < 1e-6);
+        // Windows past the history use every event.
+        assert_eq!(tracker.recent_trend(10), 25);
+        assert_eq!(tracker.moving_average(10), 6.25);
+        assert_eq!(tracker.moving_average(0), 0.0);
+    }
+
+    #[test]
+    fn streaks_on_empty_history() {
+        let tracker = ScoreTracker::new();
+        assert_eq!(tracker.current_streak(), (StreakKind::Neutral, 0));
+        assert_eq!(tracker.longest_positive_streak(), 0);
+    }
+
+    #[test]
+    fn all_positive_history_is_one_streak() {
+        let mut tracker = ScoreTracker::new();
+        for round in 1..=4 {
+            tracker.add(round, 5, "Win");
+        }
+        assert_eq!(tracker.current_streak(), (StreakKind::Positive, 4));
+        assert_eq!(tracker.longest_positive_streak(), 4);
+    }
+
+    #[test]
+    fn mixed_history_ending_in_losses() {
+        let mut tracker = ScoreTracker::new();
+        for (round, delta) in [(1, 5), (2, 3), (3, 8), (4, 0), (5, 2), (6, -1), (7, -4)] {
+            tracker.add(round, delta, "event");
+        }
+        assert_eq!(tracker.current_streak(), (StreakKind::Negative, 2));
+        assert_eq!(tracker.longest_positive_streak(), 3);
+
+        tracker.add(8, 0, "Quiet");
+        assert_eq!(tracker.current_streak(), (StreakKind::Neutral, 1));
+    }
+
     #[test]
     fn rating_thresholds() {
         let mut tracker = ScoreTracker::new();
@@ -108,4 +313,35 @@ mod tests {
         assert_eq!(tracker.best_moment().unwrap().delta, 10);
         assert_eq!(tracker.worst_moment().unwrap().delta, -15);
     }
+
+    #[test]
+    fn rating_for_rounds_scales_thresholds() {
+        let mut tracker = ScoreTracker::new();
+        tracker.total = 60;
+        assert_eq!(tracker.rating_for_rounds(25), "Struggling");
+        // 60 points in 10 rounds is on pace for 150 over 25.
+        assert_eq!(tracker.rating_for_rounds(10), "Distinguished");
+    }
+
+    #[test]
+    fn rating_detail_explains_rating() {
+        let mut tracker = ScoreTracker::new();
+        tracker.add(1, 20, "Great");
+        tracker.add(2, 10, "Good");
+        tracker.add(3, -5, "Meh");
+
+        let detail = tracker.rating_detail(4);
+        assert_eq!(detail.label, tracker.rating_for_rounds(4));
+        assert_eq!(detail.score, 25);
+        assert_eq!(detail.max_possible_estimate, 80);
+        assert!((detail.percentile - 25.0 / 80.0 * 100.0).abs() < 0.001);
+        assert_eq!(detail.to_string(), "Distinguished (25/~80, ~31%)");
+    }
+
+    #[test]
+    fn rating_detail_empty_history() {
+        let detail = ScoreTracker::new().rating_detail(25);
+        assert_eq!(detail.max_possible_estimate, 0);
+        assert_eq!(detail.percentile, 0.0);
+    }
 }
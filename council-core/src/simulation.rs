@@ -0,0 +1,785 @@
+//! Plain round loops: [`simulate_rounds`] for the legacy voting system and
+//! [`simulate_galaxy`], a compact summary of a galactic run.
+
+use crate::event::{EventTemplate, RngCore};
+use crate::explorer::GalacticCouncilMember;
+use crate::galaxy::GalaxyState;
+use crate::orchestrator::{run_galactic, GalacticOrchestrator, GalacticReport, OrchestratorConfig};
+use crate::scoring::ScoreTracker;
+use crate::{Context, CouncilMember, Decision, RoundTally};
+use std::io::Write;
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// One legacy round: every member's decision and the round's tally.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundResult {
+    pub round: u32,
+    /// Decisions as `(bot name, decision)` pairs, in council order.
+    pub votes: Vec<(&'static str, Decision)>,
+    pub tally: RoundTally,
+}
+
+/// Full record of a legacy simulation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SimulationReport {
+    pub rounds: Vec<RoundResult>,
+    /// Per-bot tallies over the whole run, in council order.
+    pub bot_summaries: Vec<(&'static str, RoundTally)>,
+    /// Every decision of every round.
+    pub cumulative_tally: RoundTally,
+    /// Seed the run was started with, as shown to the bots in
+    /// [`Context::seed`] (see [`simulate_rounds_from`]); 0 for the other
+    /// entry points.
+    pub seed: u64,
+}
+
+/// Run `rounds` legacy rounds, passing each round's tally to the next.
+///
+/// With `rounds == 0` the report has no rounds, a zero cumulative tally and
+/// one zeroed summary per bot.
+pub fn simulate_rounds(bots: &[Box<dyn CouncilMember>], rounds: u32) -> SimulationReport {
+    simulate_rounds_from(bots, 1, rounds, 0)
+}
+
+/// Like [`simulate_rounds`], numbering rounds from `start_round` so a run
+/// can resume where an earlier one stopped, and passing `seed` to every bot
+/// in [`Context::seed`] (and recording it in the report) for reproducible
+/// experiments.
+///
+/// The first round still sees no previous tally. Round numbers stop at
+/// `u32::MAX`: rounds that would be numbered past it are not played, so the
+/// report can hold fewer than `rounds` rounds.
+pub fn simulate_rounds_from(
+    bots: &[Box<dyn CouncilMember>],
+    start_round: u32,
+    rounds: u32,
+    seed: u64,
+) -> SimulationReport {
+    run_rounds(
+        &names(bots),
+        start_round,
+        rounds,
+        seed,
+        vote_all(bots),
+        observe_all(bots),
+        |_| Ok(()),
+    )
+    .unwrap_or_default()
+}
+
+/// Like [`simulate_rounds`], but each round's votes run on their own threads
+/// and only those back within `per_round` are counted.
+///
+/// Bots that miss the shared deadline are recorded as [`Decision::Abstain`];
+/// their threads are left to finish in the background and the late votes are
+/// discarded. This keeps slow (e.g. LLM-backed) members from stalling a
+/// real-time council.
+pub fn simulate_rounds_deadline(
+    bots: &[Arc<dyn CouncilMember + Send + Sync>],
+    rounds: u32,
+    per_round: Duration,
+) -> SimulationReport {
+    let names: Vec<&'static str> = bots.iter().map(|bot| bot.name()).collect();
+    let decide = |ctx: &Context| {
+        let deadline = Instant::now() + per_round;
+        let (sender, receiver) = mpsc::channel();
+        for (index, bot) in bots.iter().enumerate() {
+            let bot = Arc::clone(bot);
+            let sender = sender.clone();
+            let ctx = Context {
+                round: ctx.round,
+                previous_tally: ctx.previous_tally.clone(),
+                seed: ctx.seed,
+            };
+            thread::spawn(move || {
+                // The receiver is gone once the deadline has passed.
+                let _ = sender.send((index, bot.vote(&ctx)));
+            });
+        }
+        drop(sender);
+
+        let mut decisions = vec![Decision::Abstain; bots.len()];
+        let mut pending = bots.len();
+        while pending > 0 {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match receiver.recv_timeout(remaining) {
+                Ok((index, decision)) => {
+                    decisions[index] = decision;
+                    pending -= 1;
+                }
+                Err(_) => break,
+            }
+        }
+        decisions
+    };
+    let observe = |result: &RoundResult| bots.iter().for_each(|bot| bot.observe(result));
+    run_rounds(&names, 1, rounds, 0, decide, observe, |_| Ok(())).unwrap_or_default()
+}
+
+/// Like [`simulate_rounds`], writing one line per round to `writer` as soon
+/// as the round completes, e.g. `round 3: approve=2 reject=1 abstain=0 custom=0`.
+///
+/// Stops at the first write error.
+pub fn simulate_rounds_logged(
+    bots: &[Box<dyn CouncilMember>],
+    rounds: u32,
+    writer: &mut dyn Write,
+) -> Result<SimulationReport, String> {
+    run_rounds(
+        &names(bots),
+        1,
+        rounds,
+        0,
+        vote_all(bots),
+        observe_all(bots),
+        |result| {
+            let tally = &result.tally;
+            writeln!(
+                writer,
+                "round {}: approve={} reject={} abstain={} custom={}",
+                result.round, tally.approvals, tally.rejections, tally.abstentions, tally.customs
+            )
+            .and_then(|_| writer.flush())
+            .map_err(|e| format!("failed to write round {}: {}", result.round, e))
+        },
+    )
+}
+
+fn names(bots: &[Box<dyn CouncilMember>]) -> Vec<&'static str> {
+    bots.iter().map(|bot| bot.name()).collect()
+}
+
+/// Collect every member's decision in turn.
+fn vote_all(bots: &[Box<dyn CouncilMember>]) -> impl FnMut(&Context) -> Vec<Decision> + '_ {
+    move |ctx| bots.iter().map(|bot| bot.vote(ctx)).collect()
+}
+
+/// Show every member the finished round.
+fn observe_all(bots: &[Box<dyn CouncilMember>]) -> impl FnMut(&RoundResult) + '_ {
+    move |result| bots.iter().for_each(|bot| bot.observe(result))
+}
+
+/// Shared round loop over `rounds` rounds numbered from `start_round`;
+/// `decide` returns one decision per entry of `names`, and `observe` sees
+/// each finished round before `on_round`.
+fn run_rounds(
+    names: &[&'static str],
+    start_round: u32,
+    rounds: u32,
+    seed: u64,
+    mut decide: impl FnMut(&Context) -> Vec<Decision>,
+    mut observe: impl FnMut(&RoundResult),
+    mut on_round: impl FnMut(&RoundResult) -> Result<(), String>,
+) -> Result<SimulationReport, String> {
+    let mut report = SimulationReport {
+        bot_summaries: names
+            .iter()
+            .map(|&name| (name, RoundTally::default()))
+            .collect(),
+        seed,
+        ..SimulationReport::default()
+    };
+    let mut previous_tally = None;
+    for round in (start_round..=u32::MAX).take(rounds as usize) {
+        let ctx = Context {
+            round,
+            previous_tally,
+            seed,
+        };
+        let mut tally = RoundTally::default();
+        let mut votes = Vec::with_capacity(names.len());
+        let decisions = decide(&ctx);
+        for ((name, summary), decision) in report.bot_summaries.iter_mut().zip(decisions) {
+            tally.record(&decision);
+            summary.record(&decision);
+            report.cumulative_tally.record(&decision);
+            votes.push((*name, decision));
+        }
+        previous_tally = Some(tally.clone());
+        let result = RoundResult {
+            round,
+            votes,
+            tally,
+        };
+        observe(&result);
+        on_round(&result)?;
+        report.rounds.push(result);
+    }
+    Ok(report)
+}
+
+/// Like [`simulate_rounds`] but only accumulates the cumulative tally;
+/// members are not shown the rounds through [`CouncilMember::observe`].
+///
+/// The two round tallies are reused across rounds and keep the custom labels
+/// they have seen, so nothing is allocated per round once every label has
+/// turned up; use this as a profiling baseline for the core loop. The tally
+/// bots see as `previous_tally` may hold earlier labels at 0.
+pub fn simulate_rounds_tally_only(bots: &[Box<dyn CouncilMember>], rounds: u32) -> RoundTally {
+    let mut cumulative = RoundTally::default();
+    let mut current = RoundTally::default();
+    let mut ctx = Context {
+        round: 0,
+        previous_tally: None,
+        seed: 0,
+    };
+    for round in 1..=rounds {
+        ctx.round = round;
+        for bot in bots {
+            let decision = bot.vote(&ctx);
+            current.record(&decision);
+            cumulative.record(&decision);
+        }
+        let mut spare = ctx.previous_tally.take().unwrap_or_default();
+        spare.reset();
+        ctx.previous_tally = Some(std::mem::replace(&mut current, spare));
+    }
+    cumulative
+}
+
+/// One galactic round in a [`GalaxyReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GalaxyRoundRecord {
+    pub round: u32,
+    pub event_description: String,
+    /// Index of the winning option.
+    pub chosen_option: usize,
+    /// Narrative of the winning outcome.
+    pub outcome: String,
+    /// Score from the winning outcome.
+    pub score_delta: i32,
+    /// Penalty from threats still active after the outcome.
+    pub threat_penalty: i32,
+}
+
+/// Compact result of [`simulate_galaxy`].
+#[derive(Debug, Clone)]
+pub struct GalaxyReport {
+    pub rounds: Vec<GalaxyRoundRecord>,
+    pub galaxy: GalaxyState,
+    pub score: ScoreTracker,
+}
+
+/// Run a galactic council for `rounds` rounds from a fresh galaxy.
+///
+/// Each round generates an event, collects expertise-weighted votes, applies
+/// the winning outcome's state changes and score, then processes threats.
+/// This is [`run_galactic`] with the default configuration, flattened into a
+/// smaller report; use the orchestrator directly for traces, factions or
+/// alternative resolution modes.
+pub fn simulate_galaxy(
+    bots: &[&dyn GalacticCouncilMember],
+    templates: &[Box<dyn EventTemplate>],
+    rounds: u32,
+    rng: &mut dyn RngCore,
+) -> GalaxyReport {
+    let council: Vec<Box<dyn GalacticCouncilMember>> =
+        bots.iter().map(|bot| bot.clone_box()).collect();
+    let report = run_galactic(
+        &council,
+        templates,
+        rounds,
+        OrchestratorConfig::default(),
+        rng,
+    );
+    flatten(report)
+}
+
+/// When [`simulate_galaxy_until`] should stop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopCondition {
+    /// This many rounds have been played.
+    MaxRounds(u32),
+    /// The score total has dropped below the value.
+    ScoreBelow(i32),
+    /// The score total has risen above the value.
+    ScoreAbove(i32),
+    /// More than this many threats are active.
+    ThreatsExceed(usize),
+}
+
+impl StopCondition {
+    fn is_met(&self, galaxy: &GalaxyState, score: &ScoreTracker) -> bool {
+        match *self {
+            StopCondition::MaxRounds(rounds) => galaxy.round >= rounds,
+            StopCondition::ScoreBelow(limit) => score.total < limit,
+            StopCondition::ScoreAbove(limit) => score.total > limit,
+            StopCondition::ThreatsExceed(limit) => galaxy.threats.len() > limit,
+        }
+    }
+}
+
+/// Like [`simulate_galaxy`], but plays rounds until any of `conditions`
+/// holds, e.g. "until the council collapses", and returns the partial report
+/// with the first condition (in slice order) that fired.
+///
+/// Conditions are checked before every round, so one that already holds
+/// for the fresh galaxy plays no rounds. At most `max_rounds` rounds are
+/// played; if none of the conditions has fired by then the trigger is
+/// `None`.
+pub fn simulate_galaxy_until(
+    bots: &[&dyn GalacticCouncilMember],
+    templates: &[Box<dyn EventTemplate>],
+    max_rounds: u32,
+    conditions: &[StopCondition],
+    rng: &mut dyn RngCore,
+) -> (GalaxyReport, Option<StopCondition>) {
+    let council: Vec<Box<dyn GalacticCouncilMember>> =
+        bots.iter().map(|bot| bot.clone_box()).collect();
+    let mut orchestrator =
+        GalacticOrchestrator::new(&council, templates, OrchestratorConfig::default());
+    let triggered = loop {
+        let hit = conditions
+            .iter()
+            .find(|condition| condition.is_met(orchestrator.galaxy(), orchestrator.score()));
+        if hit.is_some() || orchestrator.galaxy().round >= max_rounds {
+            break hit.copied();
+        }
+        orchestrator.play_round(rng);
+    };
+    (flatten(orchestrator.finish()), triggered)
+}
+
+fn flatten(report: GalacticReport) -> GalaxyReport {
+    GalaxyReport {
+        rounds: report
+            .rounds
+            .iter()
+            .map(|record| GalaxyRoundRecord {
+                round: record.round,
+                event_description: record.event.description.clone(),
+                chosen_option: record.winner,
+                outcome: record.outcome().description.clone(),
+                score_delta: record.score_delta,
+                threat_penalty: record.threat_penalty,
+            })
+            .collect(),
+        galaxy: report.galaxy,
+        score: report.score,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Approves in odd rounds, follows the previous dominant outcome otherwise.
+    struct EchoBot;
+
+    impl CouncilMember for EchoBot {
+        fn name(&self) -> &'static str {
+            "echo-bot"
+        }
+
+        fn vote(&self, ctx: &Context) -> Decision {
+            if ctx.round % 2 == 1 {
+                return Decision::Approve;
+            }
+            match ctx.previous_tally.as_ref().map(RoundTally::dominant) {
+                Some(crate::DominantOutcome::Approve) => Decision::Custom("encore"),
+                _ => Decision::Reject,
+            }
+        }
+    }
+
+    struct AbstainBot;
+
+    impl CouncilMember for AbstainBot {
+        fn name(&self) -> &'static str {
+            "abstain-bot"
+        }
+
+        fn vote(&self, _ctx: &Context) -> Decision {
+            Decision::Abstain
+        }
+    }
+
+    fn council() -> Vec<Box<dyn CouncilMember>> {
+        vec![Box::new(EchoBot), Box::new(EchoBot), Box::new(AbstainBot)]
+    }
+
+    #[test]
+    fn simulate_rounds_records_every_round() {
+        let report = simulate_rounds(&council(), 4);
+        assert_eq!(report.rounds.len(), 4);
+        assert_eq!(report.rounds[1].tally.customs, 2);
+        assert_eq!(report.bot_summaries[0].0, "echo-bot");
+        assert_eq!(report.bot_summaries[2].1.abstentions, 4);
+        assert_eq!(report.cumulative_tally.approvals, 4);
+        assert_eq!(
+            report.cumulative_tally.custom_labels.get("encore"),
+            Some(&4)
+        );
+    }
+
+    #[test]
+    fn each_round_sees_the_previous_tally() {
+        use std::sync::Mutex;
+
+        /// Records the `previous_tally` it was shown each round.
+        struct Witness(Arc<Mutex<Vec<Option<RoundTally>>>>);
+
+        impl CouncilMember for Witness {
+            fn name(&self) -> &'static str {
+                "witness"
+            }
+
+            fn vote(&self, ctx: &Context) -> Decision {
+                self.0.lock().unwrap().push(ctx.previous_tally.clone());
+                Decision::Reject
+            }
+        }
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut bots = council();
+        bots.push(Box::new(Witness(seen.clone())));
+        let report = simulate_rounds(&bots, 2);
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 2);
+        assert_eq!(seen[0], None);
+        assert_eq!(seen[1].as_ref(), Some(&report.rounds[0].tally));
+        assert_eq!(seen[1].as_ref().unwrap().approvals, 2);
+        assert_eq!(seen[1].as_ref().unwrap().rejections, 1);
+    }
+
+    #[test]
+    fn members_observe_every_finished_round() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        /// Counts the rounds it has been shown.
+        struct Historian {
+            observed: Rc<Cell<u32>>,
+            last_round: Rc<Cell<u32>>,
+        }
+
+        impl CouncilMember for Historian {
+            fn name(&self) -> &'static str {
+                "historian"
+            }
+
+            fn vote(&self, _ctx: &Context) -> Decision {
+                Decision::Abstain
+            }
+
+            fn observe(&self, result: &RoundResult) {
+                assert_eq!(result.votes.len(), 4);
+                self.observed.set(self.observed.get() + 1);
+                self.last_round.set(result.round);
+            }
+        }
+
+        let observed = Rc::new(Cell::new(0));
+        let last_round = Rc::new(Cell::new(0));
+        let mut bots = council();
+        bots.push(Box::new(Historian {
+            observed: observed.clone(),
+            last_round: last_round.clone(),
+        }));
+
+        simulate_rounds(&bots, 5);
+        assert_eq!(observed.get(), 5);
+        assert_eq!(last_round.get(), 5);
+
+        simulate_rounds_logged(&bots, 2, &mut Vec::new()).unwrap();
+        assert_eq!(observed.get(), 7);
+        simulate_rounds_tally_only(&bots, 3);
+        assert_eq!(observed.get(), 7);
+    }
+
+    #[test]
+    fn simulate_rounds_from_resumes_numbering_and_keeps_seed() {
+        let first = simulate_rounds_from(&council(), 5, 3, 42);
+        let second = simulate_rounds_from(&council(), 5, 3, 42);
+        assert_eq!(first, second);
+        assert_eq!(first.seed, 42);
+        let numbers: Vec<u32> = first.rounds.iter().map(|r| r.round).collect();
+        assert_eq!(numbers, [5, 6, 7]);
+        // Round 5 is odd, so the echo bots approve.
+        assert_eq!(first.rounds[0].tally.approvals, 2);
+
+        let from_one = simulate_rounds_from(&council(), 1, 4, 0);
+        assert_eq!(from_one, simulate_rounds(&council(), 4));
+    }
+
+    #[test]
+    fn simulate_rounds_from_passes_the_seed_to_bots() {
+        /// Approves on even seeds and rejects on odd ones.
+        struct SeedBot;
+
+        impl CouncilMember for SeedBot {
+            fn name(&self) -> &'static str {
+                "seed-bot"
+            }
+
+            fn vote(&self, ctx: &Context) -> Decision {
+                if ctx.seed.is_multiple_of(2) {
+                    Decision::Approve
+                } else {
+                    Decision::Reject
+                }
+            }
+        }
+
+        let bots: Vec<Box<dyn CouncilMember>> = vec![Box::new(SeedBot)];
+        let even = simulate_rounds_from(&bots, 1, 2, 42);
+        let odd = simulate_rounds_from(&bots, 1, 2, 43);
+        assert_eq!(even.cumulative_tally.approvals, 2);
+        assert_eq!(odd.cumulative_tally.rejections, 2);
+        assert_ne!(even, odd);
+    }
+
+    #[test]
+    fn simulate_rounds_from_stops_numbering_at_u32_max() {
+        let report = simulate_rounds_from(&council(), u32::MAX - 1, 5, 0);
+        let numbers: Vec<u32> = report.rounds.iter().map(|r| r.round).collect();
+        assert_eq!(numbers, [u32::MAX - 1, u32::MAX]);
+    }
+
+    #[test]
+    fn zero_rounds_returns_empty_report() {
+        let report = simulate_rounds(&council(), 0);
+        assert!(report.rounds.is_empty());
+        assert_eq!(report.cumulative_tally, RoundTally::default());
+        let names: Vec<&str> = report.bot_summaries.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, vec!["echo-bot", "echo-bot", "abstain-bot"]);
+        assert!(report
+            .bot_summaries
+            .iter()
+            .all(|(_, tally)| *tally == RoundTally::default()));
+    }
+
+    #[test]
+    fn logged_simulation_writes_one_line_per_round() {
+        let mut log: Vec<u8> = Vec::new();
+        let report = simulate_rounds_logged(&council(), 3, &mut log).unwrap();
+        let log = String::from_utf8(log).unwrap();
+        let lines: Vec<&str> = log.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "round 1: approve=2 reject=0 abstain=1 custom=0");
+        assert_eq!(lines[1], "round 2: approve=0 reject=0 abstain=1 custom=2");
+        assert_eq!(
+            report.cumulative_tally,
+            simulate_rounds(&council(), 3).cumulative_tally
+        );
+    }
+
+    #[test]
+    fn tally_only_matches_full_simulation() {
+        for rounds in [0, 1, 5, 12] {
+            assert_eq!(
+                simulate_rounds_tally_only(&council(), rounds),
+                simulate_rounds(&council(), rounds).cumulative_tally
+            );
+        }
+    }
+
+    /// Takes `delay` to approve.
+    struct SlowBot {
+        name: &'static str,
+        delay: Duration,
+    }
+
+    impl CouncilMember for SlowBot {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn vote(&self, _ctx: &Context) -> Decision {
+            thread::sleep(self.delay);
+            Decision::Approve
+        }
+    }
+
+    #[test]
+    fn deadline_counts_late_bots_as_abstaining() {
+        let bot = |name, millis| -> Arc<dyn CouncilMember + Send + Sync> {
+            Arc::new(SlowBot {
+                name,
+                delay: Duration::from_millis(millis),
+            })
+        };
+        let bots = vec![bot("fast", 0), bot("sleepy", 2_000), bot("quick", 0)];
+
+        let started = Instant::now();
+        let report = simulate_rounds_deadline(&bots, 2, Duration::from_millis(200));
+
+        assert!(started.elapsed() < Duration::from_millis(1_500));
+        assert_eq!(report.rounds.len(), 2);
+        for round in &report.rounds {
+            assert_eq!(
+                round.votes,
+                vec![
+                    ("fast", Decision::Approve),
+                    ("sleepy", Decision::Abstain),
+                    ("quick", Decision::Approve),
+                ]
+            );
+        }
+        assert_eq!(report.bot_summaries[1].1.abstentions, 2);
+        assert_eq!(report.cumulative_tally.approvals, 4);
+    }
+
+    #[derive(Clone)]
+    struct BoldBot;
+
+    impl GalacticCouncilMember for BoldBot {
+        fn name(&self) -> &'static str {
+            "bold-bot"
+        }
+
+        fn expertise(&self) -> &[(&'static str, f32)] {
+            &[("exploration", 0.6)]
+        }
+
+        fn vote(&self, _event: &crate::Event, _galaxy: &GalaxyState) -> usize {
+            0
+        }
+    }
+
+    /// Every round: lose 10 points and gain a new threat.
+    struct RaidTemplate;
+
+    impl EventTemplate for RaidTemplate {
+        fn name(&self) -> &'static str {
+            "raid"
+        }
+
+        fn is_applicable(&self, _galaxy: &GalaxyState) -> bool {
+            true
+        }
+
+        fn generate(&self, galaxy: &GalaxyState, _rng: &mut dyn RngCore) -> crate::Event {
+            use crate::event::{Outcome, ResponseOption};
+            use crate::galaxy::{StateChange, Threat};
+
+            crate::Event {
+                description: "Raiders strike".to_string(),
+                relevant_expertise: vec![],
+                options: vec![ResponseOption {
+                    description: "Endure".to_string(),
+                    outcome: Outcome {
+                        description: "Losses mount".to_string(),
+                        score_delta: -10,
+                        headline: None,
+                        state_changes: vec![StateChange::AddThreat(Threat {
+                            name: format!("Raiders {}", galaxy.round),
+                            severity: 1,
+                            rounds_active: 0,
+                        })],
+                    },
+                    required_discovery: None,
+                }],
+            }
+        }
+    }
+
+    /// Run [`simulate_galaxy_until`] for two [`BoldBot`]s on seed 7, capped
+    /// at 20 rounds.
+    fn run_until(
+        templates: &[Box<dyn EventTemplate>],
+        conditions: &[StopCondition],
+    ) -> (GalaxyReport, Option<StopCondition>) {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        simulate_galaxy_until(&[&BoldBot, &BoldBot], templates, 20, conditions, &mut rng)
+    }
+
+    fn raids() -> Vec<Box<dyn EventTemplate>> {
+        vec![Box::new(RaidTemplate)]
+    }
+
+    #[test]
+    fn until_max_rounds_matches_a_fixed_length_run() {
+        use crate::templates::default_templates;
+        use rand::SeedableRng;
+
+        let calm = default_templates();
+        let (report, hit) = run_until(&calm, &[StopCondition::MaxRounds(4)]);
+        assert_eq!(hit, Some(StopCondition::MaxRounds(4)));
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        assert_eq!(
+            report.rounds,
+            simulate_galaxy(&[&BoldBot, &BoldBot], &calm, 4, &mut rng).rounds
+        );
+    }
+
+    #[test]
+    fn until_score_above_stops_once_exceeded() {
+        // Seed 7 scores 15, then 35 after round two.
+        let calm = crate::templates::default_templates();
+        let (report, hit) = run_until(&calm, &[StopCondition::ScoreAbove(30)]);
+        assert_eq!(hit, Some(StopCondition::ScoreAbove(30)));
+        assert_eq!(report.rounds.len(), 2);
+        assert_eq!(report.score.total, 35);
+    }
+
+    #[test]
+    fn until_score_below_stops_once_undercut() {
+        // Each raid costs 10 plus the growing threat penalty: -13, -29, -48.
+        let (report, hit) = run_until(&raids(), &[StopCondition::ScoreBelow(-40)]);
+        assert_eq!(hit, Some(StopCondition::ScoreBelow(-40)));
+        assert_eq!(report.rounds.len(), 3);
+    }
+
+    #[test]
+    fn until_threats_exceed_stops_once_over_the_limit() {
+        let (report, hit) = run_until(&raids(), &[StopCondition::ThreatsExceed(4)]);
+        assert_eq!(hit, Some(StopCondition::ThreatsExceed(4)));
+        assert_eq!(report.galaxy.round, 5);
+        assert_eq!(report.galaxy.threats.len(), 5);
+    }
+
+    #[test]
+    fn until_reports_the_first_condition_in_slice_order() {
+        let (report, hit) = run_until(
+            &raids(),
+            &[StopCondition::MaxRounds(3), StopCondition::ThreatsExceed(2)],
+        );
+        assert_eq!(hit, Some(StopCondition::MaxRounds(3)));
+        assert_eq!(report.rounds.len(), 3);
+    }
+
+    #[test]
+    fn until_stops_at_max_rounds_when_nothing_fires() {
+        for conditions in [
+            vec![StopCondition::ScoreAbove(i32::MAX)],
+            vec![StopCondition::ThreatsExceed(usize::MAX)],
+            vec![],
+        ] {
+            let (report, hit) = run_until(&raids(), &conditions);
+            assert_eq!(hit, None);
+            assert_eq!(report.rounds.len(), 20);
+        }
+    }
+
+    #[test]
+    fn simulate_galaxy_records_each_round() {
+        use crate::templates::default_templates;
+        use rand::SeedableRng;
+
+        let bot = BoldBot;
+        let templates = default_templates();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let report = simulate_galaxy(&[&bot, &bot], &templates, 10, &mut rng);
+
+        assert_eq!(report.rounds.len(), 10);
+        assert_eq!(report.galaxy.round, 10);
+        assert!(report.rounds.iter().all(|r| r.chosen_option == 0));
+        let summed: i32 = report
+            .rounds
+            .iter()
+            .map(|r| r.score_delta + r.threat_penalty)
+            .sum();
+        assert_eq!(summed, report.score.total);
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let again = simulate_galaxy(&[&bot, &bot], &templates, 10, &mut rng);
+        assert_eq!(again.rounds, report.rounds);
+    }
+}
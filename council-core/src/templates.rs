@@ -2,8 +2,10 @@
 
 use crate::event::{Event, EventTemplate, Outcome, ResponseOption, RngCore};
 use crate::galaxy::{
-    Discovery, GalaxyState, Relation, Sector, SectorType, Species, StateChange, Threat,
+    degrade_relation, improve_relation, Discovery, GalaxyState, Relation, Sector, SectorType,
+    Species, StateChange, Threat, FUEL, HOME_SECTOR,
 };
+use std::collections::HashMap;
 
 /// Names for procedurally generated content.
 mod names {
@@ -63,27 +65,6 @@ fn random_species_name(rng: &mut dyn RngCore) -> String {
     format!("{}{}", prefix, suffix)
 }
 
-/// Improve a relation by one step (Unknown -> Wary -> Neutral -> Friendly -> Allied).
-fn improve_relation(current: Relation) -> Relation {
-    match current {
-        Relation::Hostile => Relation::Wary,
-        Relation::Unknown | Relation::Wary => Relation::Neutral,
-        Relation::Neutral => Relation::Friendly,
-        Relation::Friendly | Relation::Allied => Relation::Allied,
-    }
-}
-
-/// Degrade a relation by one step (Allied -> Friendly -> Neutral -> Wary -> Hostile).
-fn degrade_relation(current: Relation) -> Relation {
-    match current {
-        Relation::Allied => Relation::Friendly,
-        Relation::Friendly => Relation::Neutral,
-        Relation::Neutral => Relation::Wary,
-        Relation::Wary | Relation::Unknown => Relation::Hostile,
-        Relation::Hostile => Relation::Hostile,
-    }
-}
-
 /// Improve a relation by two steps.
 fn greatly_improve_relation(current: Relation) -> Relation {
     improve_relation(improve_relation(current))
@@ -134,27 +115,33 @@ impl EventTemplate for UnknownSignalTemplate {
                             sector_name
                         ),
                         score_delta: 15,
+                        headline: None,
                         state_changes: vec![StateChange::AddSector(Sector {
                             name: sector_name.clone(),
                             sector_type,
                         })],
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Send an unmanned probe first".to_string(),
                     outcome: Outcome {
                         description: "The probe returns preliminary data. The region is noted for future exploration.".to_string(),
                         score_delta: 5,
+                        headline: None,
                         state_changes: vec![],
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Log the signal but focus on known priorities".to_string(),
                     outcome: Outcome {
                         description: "The signal is archived. Perhaps another time.".to_string(),
                         score_delta: 0,
+                        headline: None,
                         state_changes: vec![],
                     },
+                    required_discovery: None,
                 },
             ],
         }
@@ -208,10 +195,13 @@ impl EventTemplate for DerelictTemplate {
                                 discovery, threat
                             ),
                             score_delta: 6,
+                            headline: None,
                             state_changes: vec![
                                 StateChange::AddDiscovery(Discovery {
                                     name: discovery.to_string(),
                                     category: "salvage".to_string(),
+                                    source_round: None,
+                                    source_event: None,
                                 }),
                                 StateChange::AddThreat(Threat {
                                     name: threat.to_string(),
@@ -227,12 +217,16 @@ impl EventTemplate for DerelictTemplate {
                                 discovery
                             ),
                             score_delta: 14,
+                            headline: None,
                             state_changes: vec![StateChange::AddDiscovery(Discovery {
                                 name: discovery.to_string(),
                                 category: "salvage".to_string(),
+                                source_round: None,
+                                source_event: None,
                             })],
                         }
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Scan it remotely and leave it undisturbed".to_string(),
@@ -240,8 +234,10 @@ impl EventTemplate for DerelictTemplate {
                         description: "Long-range scans yield useful telemetry and material analysis. Low risk, modest gain."
                             .to_string(),
                         score_delta: 6,
+                        headline: None,
                         state_changes: vec![],
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Mark the location and move on".to_string(),
@@ -249,8 +245,10 @@ impl EventTemplate for DerelictTemplate {
                         description: "The derelict is logged for future expeditions. The council stays focused on current priorities."
                             .to_string(),
                         score_delta: 1,
+                        headline: None,
                         state_changes: vec![],
                     },
+                    required_discovery: None,
                 },
             ],
         }
@@ -290,18 +288,23 @@ impl EventTemplate for AnomalyTemplate {
                         Outcome {
                             description: "The research team makes a breakthrough discovery about spatial physics!".to_string(),
                             score_delta: 20,
+                            headline: None,
                             state_changes: vec![StateChange::AddDiscovery(Discovery {
                                 name: "Spatial Dynamics Theory".to_string(),
                                 category: "science".to_string(),
+                                source_round: None,
+                                source_event: None,
                             })],
                         }
                     } else {
                         Outcome {
                             description: "The team gathers useful data, though the anomaly remains mysterious.".to_string(),
                             score_delta: 8,
+                            headline: None,
                             state_changes: vec![],
                         }
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Observe from a safe distance with long-range sensors".to_string(),
@@ -309,8 +312,10 @@ impl EventTemplate for AnomalyTemplate {
                         description: "Remote observations provide some data. Playing it safe."
                             .to_string(),
                         score_delta: 3,
+                        headline: None,
                         state_changes: vec![],
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Mark as hazardous and establish exclusion zone".to_string(),
@@ -318,8 +323,10 @@ impl EventTemplate for AnomalyTemplate {
                         description: "The anomaly is marked on charts as a navigation hazard."
                             .to_string(),
                         score_delta: 0,
+                        headline: None,
                         state_changes: vec![],
                     },
+                    required_discovery: None,
                 },
             ],
         }
@@ -377,6 +384,7 @@ impl EventTemplate for FirstContactTemplate {
                                 species_name
                             ),
                             score_delta: -10,
+                            headline: None,
                             state_changes: vec![
                                 StateChange::AddSpecies(Species {
                                     name: species_name.clone(),
@@ -395,6 +403,7 @@ impl EventTemplate for FirstContactTemplate {
                                 species_name
                             ),
                             score_delta: 15,
+                            headline: None,
                             state_changes: vec![
                                 StateChange::AddSpecies(Species {
                                     name: species_name.clone(),
@@ -407,6 +416,7 @@ impl EventTemplate for FirstContactTemplate {
                             ],
                         }
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Maintain cautious observation before contact".to_string(),
@@ -416,19 +426,23 @@ impl EventTemplate for FirstContactTemplate {
                             species_name
                         ),
                         score_delta: 5,
+                        headline: None,
                         state_changes: vec![StateChange::AddSpecies(Species {
                             name: species_name.clone(),
                             traits,
                         })],
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Withdraw and avoid contact for now".to_string(),
                     outcome: Outcome {
                         description: "We retreat quietly. The species remains unaware of us.".to_string(),
                         score_delta: 0,
+                        headline: None,
                         state_changes: vec![],
                     },
+                    required_discovery: None,
                 },
             ],
         }
@@ -478,12 +492,14 @@ impl EventTemplate for ThreatEmergenceTemplate {
                         Outcome {
                             description: format!("Our forces engage the {}. After a fierce battle, the threat is neutralized!", threat_name),
                             score_delta: 12,
+                            headline: None,
                             state_changes: vec![],
                         }
                     } else {
                         Outcome {
                             description: format!("Our forces engage but cannot fully repel the {}. The threat persists.", threat_name),
                             score_delta: -5,
+                            headline: None,
                             state_changes: vec![StateChange::AddThreat(Threat {
                                 name: threat_name.clone(),
                                 severity: severity / 2 + 1,
@@ -491,30 +507,35 @@ impl EventTemplate for ThreatEmergenceTemplate {
                             })],
                         }
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Fortify defenses and prepare for siege".to_string(),
                     outcome: Outcome {
                         description: format!("We strengthen our defenses. The {} probe our perimeter but find no weakness.", threat_name),
                         score_delta: 3,
+                        headline: None,
                         state_changes: vec![StateChange::AddThreat(Threat {
                             name: threat_name.clone(),
                             severity,
                             rounds_active: 0,
                         })],
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Attempt diplomatic resolution".to_string(),
                     outcome: Outcome {
                         description: format!("Negotiations with the {} fail. They attack while our guard is down!", threat_name),
                         score_delta: -15,
+                        headline: None,
                         state_changes: vec![StateChange::AddThreat(Threat {
                             name: threat_name,
                             severity: severity + 1,
                             rounds_active: 0,
                         })],
                     },
+                    required_discovery: None,
                 },
             ],
         }
@@ -567,6 +588,7 @@ impl EventTemplate for ThreatEscalationTemplate {
                                 threat_name
                             ),
                             score_delta: 20,
+                            headline: None,
                             state_changes: vec![StateChange::RemoveThreat(threat_name.clone())],
                         }
                     } else {
@@ -576,12 +598,14 @@ impl EventTemplate for ThreatEscalationTemplate {
                                 threat_name
                             ),
                             score_delta: -8,
+                            headline: None,
                             state_changes: vec![StateChange::ModifyThreatSeverity {
                                 name: threat_name.clone(),
                                 delta: 1,
                             }],
                         }
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Deploy strategic containment measures".to_string(),
@@ -591,11 +615,13 @@ impl EventTemplate for ThreatEscalationTemplate {
                             threat_name
                         ),
                         score_delta: 8,
+                        headline: None,
                         state_changes: vec![StateChange::ModifyThreatSeverity {
                             name: threat_name.clone(),
                             delta: -1,
                         }],
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Negotiate a ceasefire".to_string(),
@@ -606,6 +632,7 @@ impl EventTemplate for ThreatEscalationTemplate {
                                 threat_name
                             ),
                             score_delta: 12,
+                            headline: None,
                             state_changes: vec![StateChange::ModifyThreatSeverity {
                                 name: threat_name.clone(),
                                 delta: -2,
@@ -618,12 +645,14 @@ impl EventTemplate for ThreatEscalationTemplate {
                                 threat_name
                             ),
                             score_delta: -10,
+                            headline: None,
                             state_changes: vec![StateChange::ModifyThreatSeverity {
                                 name: threat_name.clone(),
                                 delta: 2,
                             }],
                         }
                     },
+                    required_discovery: None,
                 },
             ],
         }
@@ -633,6 +662,14 @@ impl EventTemplate for ThreatEscalationTemplate {
 /// Supplies are running low and the council must respond.
 pub struct ResourceScarcityTemplate;
 
+/// Fuel gained (or spent, when negative) by a supply outcome.
+fn fuel(amount: i32) -> StateChange {
+    StateChange::AddResource {
+        kind: FUEL.to_string(),
+        amount,
+    }
+}
+
 impl EventTemplate for ResourceScarcityTemplate {
     fn name(&self) -> &'static str {
         "Resource Scarcity"
@@ -690,8 +727,10 @@ impl EventTemplate for ResourceScarcityTemplate {
                     outcome: Outcome {
                         description: "Consumption drops and reserves stabilize. Nobody loves it, but it works.".to_string(),
                         score_delta: 3,
-                        state_changes: vec![],
+                        headline: None,
+                        state_changes: vec![fuel(-(severity as i32))],
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Seek emergency trade and resupply agreements".to_string(),
@@ -699,7 +738,8 @@ impl EventTemplate for ResourceScarcityTemplate {
                         None => Outcome {
                             description: "We have no established contacts to trade with. The council must rely on internal measures.".to_string(),
                             score_delta: -2,
-                            state_changes: vec![],
+                            headline: None,
+                            state_changes: vec![fuel(-2 * severity as i32)],
                         },
                         Some(species) if trade_success => Outcome {
                             description: format!(
@@ -707,10 +747,14 @@ impl EventTemplate for ResourceScarcityTemplate {
                                 species
                             ),
                             score_delta: 8,
-                            state_changes: vec![StateChange::SetRelation {
-                                species: species.clone(),
-                                relation: improve_relation(current_relation),
-                            }],
+                            headline: None,
+                            state_changes: vec![
+                                StateChange::SetRelation {
+                                    species: species.clone(),
+                                    relation: improve_relation(current_relation),
+                                },
+                                fuel(2 * severity as i32),
+                            ],
                         },
                         Some(species) => Outcome {
                             description: format!(
@@ -718,12 +762,17 @@ impl EventTemplate for ResourceScarcityTemplate {
                                 species
                             ),
                             score_delta: -6,
-                            state_changes: vec![StateChange::SetRelation {
-                                species: species.clone(),
-                                relation: degrade_relation(current_relation),
-                            }],
+                            headline: None,
+                            state_changes: vec![
+                                StateChange::SetRelation {
+                                    species: species.clone(),
+                                    relation: degrade_relation(current_relation),
+                                },
+                                fuel(-2 * severity as i32),
+                            ],
                         },
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Attempt a rapid engineering breakthrough to replace the missing resources".to_string(),
@@ -734,22 +783,33 @@ impl EventTemplate for ResourceScarcityTemplate {
                                 discovery
                             ),
                             score_delta: 12,
-                            state_changes: vec![StateChange::AddDiscovery(Discovery {
-                                name: discovery,
-                                category: "engineering".to_string(),
-                            })],
+                            headline: None,
+                            state_changes: vec![
+                                StateChange::AddDiscovery(Discovery {
+                                    name: discovery,
+                                    category: "engineering".to_string(),
+                                    source_round: None,
+                                    source_event: None,
+                                }),
+                                fuel(3 * severity as i32),
+                            ],
                         }
                     } else {
                         Outcome {
                             description: "The retrofit program fails and causes cascading shortages. A long-term crisis is now active.".to_string(),
                             score_delta: -10,
-                            state_changes: vec![StateChange::AddThreat(Threat {
-                                name: "Resource Shortfall".to_string(),
-                                severity,
-                                rounds_active: 0,
-                            })],
+                            headline: None,
+                            state_changes: vec![
+                                StateChange::AddThreat(Threat {
+                                    name: "Resource Shortfall".to_string(),
+                                    severity,
+                                    rounds_active: 0,
+                                }),
+                                fuel(-2 * severity as i32),
+                            ],
                         }
                     },
+                    required_discovery: None,
                 },
             ],
         }
@@ -782,7 +842,7 @@ impl EventTemplate for ArtifactTemplate {
             .explored_sectors
             .get(sector_idx)
             .map(|s| s.name.as_str())
-            .unwrap_or("Home Sector");
+            .unwrap_or(HOME_SECTOR);
         let artifact_name =
             names::DISCOVERY_TYPES[rng.next_u32() as usize % names::DISCOVERY_TYPES.len()];
 
@@ -807,18 +867,23 @@ impl EventTemplate for ArtifactTemplate {
                                 artifact_name
                             ),
                             score_delta: -10,
+                            headline: None,
                             state_changes: vec![],
                         }
                     } else {
                         Outcome {
                             description: format!("The {} activates successfully! Its knowledge is integrated into our systems.", artifact_name),
                             score_delta: 18,
+                            headline: None,
                             state_changes: vec![StateChange::AddDiscovery(Discovery {
                                 name: artifact_name.to_string(),
                                 category: "artifact".to_string(),
+                                source_round: None,
+                                source_event: None,
                             })],
                         }
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Carefully study it before attempting activation".to_string(),
@@ -828,11 +893,15 @@ impl EventTemplate for ArtifactTemplate {
                             artifact_name
                         ),
                         score_delta: 10,
+                        headline: None,
                         state_changes: vec![StateChange::AddDiscovery(Discovery {
                             name: artifact_name.to_string(),
                             category: "artifact".to_string(),
+                            source_round: None,
+                            source_event: None,
                         })],
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Secure the site for later investigation".to_string(),
@@ -841,8 +910,10 @@ impl EventTemplate for ArtifactTemplate {
                             "The artifact is secured. We'll return to it when resources allow."
                                 .to_string(),
                         score_delta: 2,
+                        headline: None,
                         state_changes: vec![],
                     },
+                    required_discovery: None,
                 },
             ],
         }
@@ -904,11 +975,13 @@ impl EventTemplate for DiplomaticRequestTemplate {
                             species_name
                         ),
                         score_delta: 12,
+                        headline: None,
                         state_changes: vec![StateChange::SetRelation {
                             species: species_name.clone(),
                             relation: generous_relation,
                         }],
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Negotiate cautiously — seek mutual benefit".to_string(),
@@ -918,11 +991,13 @@ impl EventTemplate for DiplomaticRequestTemplate {
                             species_name
                         ),
                         score_delta: 5,
+                        headline: None,
                         state_changes: vec![StateChange::SetRelation {
                             species: species_name.clone(),
                             relation: negotiate_relation,
                         }],
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Decline the summit — we have other priorities".to_string(),
@@ -932,11 +1007,13 @@ impl EventTemplate for DiplomaticRequestTemplate {
                             species_name
                         ),
                         score_delta: -2,
+                        headline: None,
                         state_changes: vec![StateChange::SetRelation {
                             species: species_name.clone(),
                             relation: decline_relation,
                         }],
                     },
+                    required_discovery: None,
                 },
             ],
         }
@@ -1024,10 +1101,13 @@ impl EventTemplate for CulturalExchangeTemplate {
                             description: "A translation mishap causes offense during the exchange. Relations cool despite useful insights."
                                 .to_string(),
                             score_delta: 2,
+                            headline: None,
                             state_changes: vec![
                                 StateChange::AddDiscovery(Discovery {
                                     name: discovery.clone(),
                                     category: "culture".to_string(),
+                                    source_round: None,
+                                    source_event: None,
                                 }),
                                 StateChange::SetRelation {
                                     species: species_name.clone(),
@@ -1042,10 +1122,13 @@ impl EventTemplate for CulturalExchangeTemplate {
                                 discovery
                             ),
                             score_delta: 10,
+                            headline: None,
                             state_changes: vec![
                                 StateChange::AddDiscovery(Discovery {
                                     name: discovery.clone(),
                                     category: "culture".to_string(),
+                                    source_round: None,
+                                    source_event: None,
                                 }),
                                 StateChange::SetRelation {
                                     species: species_name.clone(),
@@ -1054,28 +1137,33 @@ impl EventTemplate for CulturalExchangeTemplate {
                             ],
                         }
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Accept cautiously — run a limited exchange".to_string(),
                     outcome: Outcome {
                         description: "A small exchange program runs smoothly. Incremental trust is built.".to_string(),
                         score_delta: 5,
+                        headline: None,
                         state_changes: vec![StateChange::SetRelation {
                             species: species_name.clone(),
                             relation: limited_exchange,
                         }],
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Decline — focus on strategic priorities".to_string(),
                     outcome: Outcome {
                         description: "We politely decline. The relationship suffers from the missed opportunity.".to_string(),
                         score_delta: -1,
+                        headline: None,
                         state_changes: vec![StateChange::SetRelation {
                             species: species_name.clone(),
                             relation: decline_relation,
                         }],
                     },
+                    required_discovery: None,
                 },
             ],
         }
@@ -1126,11 +1214,15 @@ impl EventTemplate for TechBreakthroughTemplate {
                             discovery_name
                         ),
                         score_delta: 18,
+                        headline: None,
                         state_changes: vec![StateChange::AddDiscovery(Discovery {
                             name: discovery_name.to_string(),
                             category: "research".to_string(),
+                            source_round: None,
+                            source_event: None,
                         })],
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Methodical research — steady progress over time".to_string(),
@@ -1140,11 +1232,15 @@ impl EventTemplate for TechBreakthroughTemplate {
                             discovery_name
                         ),
                         score_delta: 8,
+                        headline: None,
                         state_changes: vec![StateChange::AddDiscovery(Discovery {
                             name: discovery_name.to_string(),
                             category: "research".to_string(),
+                            source_round: None,
+                            source_event: None,
                         })],
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Archive the findings for later".to_string(),
@@ -1152,8 +1248,10 @@ impl EventTemplate for TechBreakthroughTemplate {
                         description: "The research notes are filed away. Perhaps we'll revisit them."
                             .to_string(),
                         score_delta: 2,
+                        headline: None,
                         state_changes: vec![],
                     },
+                    required_discovery: None,
                 },
             ],
         }
@@ -1177,20 +1275,20 @@ pub fn default_templates() -> Vec<Box<dyn EventTemplate>> {
     ]
 }
 
-/// Select and generate an event from applicable templates.
-pub fn generate_event(
-    templates: &[Box<dyn EventTemplate>],
-    galaxy: &GalaxyState,
-    rng: &mut dyn RngCore,
-) -> Event {
-    let applicable: Vec<_> = templates
-        .iter()
-        .filter(|t| t.is_applicable(galaxy))
-        .collect();
+/// Fallback event used when no template is applicable.
+pub struct QuietPeriodTemplate;
 
-    if applicable.is_empty() {
-        // Fallback event
-        return Event {
+impl EventTemplate for QuietPeriodTemplate {
+    fn name(&self) -> &'static str {
+        "Quiet Period"
+    }
+
+    fn is_applicable(&self, _galaxy: &GalaxyState) -> bool {
+        true
+    }
+
+    fn generate(&self, _galaxy: &GalaxyState, _rng: &mut dyn RngCore) -> Event {
+        Event {
             description: "A quiet period in the cosmos. The council convenes for routine matters."
                 .to_string(),
             relevant_expertise: vec![],
@@ -1199,25 +1297,101 @@ pub fn generate_event(
                 outcome: Outcome {
                     description: "Business as usual.".to_string(),
                     score_delta: 1,
+                    headline: None,
                     state_changes: vec![],
                 },
+                required_discovery: None,
             }],
-        };
+        }
+    }
+}
+
+/// Select and generate an event from applicable templates.
+///
+/// Falls back to [`QuietPeriodTemplate`] when none apply.
+pub fn generate_event(
+    templates: &[Box<dyn EventTemplate>],
+    galaxy: &GalaxyState,
+    rng: &mut dyn RngCore,
+) -> Event {
+    generate_event_with_fallback(templates, galaxy, rng, &QuietPeriodTemplate)
+}
+
+/// Like [`generate_event`], also returning the name of the template that
+/// produced the event.
+pub fn generate_named_event(
+    templates: &[Box<dyn EventTemplate>],
+    galaxy: &GalaxyState,
+    rng: &mut dyn RngCore,
+) -> (&'static str, Event) {
+    select_and_generate(templates, galaxy, rng, &QuietPeriodTemplate, |t| t.weight())
+}
+
+/// Select and generate an event from applicable templates, using `fallback`
+/// when none apply.
+pub fn generate_event_with_fallback(
+    templates: &[Box<dyn EventTemplate>],
+    galaxy: &GalaxyState,
+    rng: &mut dyn RngCore,
+    fallback: &dyn EventTemplate,
+) -> Event {
+    select_and_generate(templates, galaxy, rng, fallback, |t| t.weight()).1
+}
+
+/// Select and generate an event, taking each template's weight from
+/// `overrides` (keyed by template name) when present.
+///
+/// Lets a scenario rebalance templates without forking them. A zero override
+/// disables a template.
+pub fn generate_event_weighted(
+    templates: &[Box<dyn EventTemplate>],
+    galaxy: &GalaxyState,
+    rng: &mut dyn RngCore,
+    overrides: &HashMap<&str, u32>,
+) -> Event {
+    select_and_generate(templates, galaxy, rng, &QuietPeriodTemplate, |t| {
+        overrides
+            .get(t.name())
+            .copied()
+            .unwrap_or_else(|| t.weight())
+    })
+    .1
+}
+
+fn select_and_generate(
+    templates: &[Box<dyn EventTemplate>],
+    galaxy: &GalaxyState,
+    rng: &mut dyn RngCore,
+    fallback: &dyn EventTemplate,
+    weight_of: impl Fn(&dyn EventTemplate) -> u32,
+) -> (&'static str, Event) {
+    let applicable: Vec<(&dyn EventTemplate, u32)> = templates
+        .iter()
+        .filter(|t| t.is_applicable(galaxy))
+        .map(|t| (t.as_ref(), weight_of(t.as_ref())))
+        .filter(|(_, weight)| *weight > 0)
+        .collect();
+
+    if applicable.is_empty() {
+        return (fallback.name(), fallback.generate(galaxy, rng));
     }
 
     // Weight-based selection
-    let total_weight: u32 = applicable.iter().map(|t| t.weight()).sum();
+    let total_weight: u32 = applicable.iter().map(|(_, weight)| weight).sum();
     let mut roll = rng.next_u32() % total_weight;
 
-    for template in &applicable {
-        if roll < template.weight() {
-            return template.generate(galaxy, rng);
+    for (template, weight) in &applicable {
+        if roll < *weight {
+            return (template.name(), template.generate(galaxy, rng));
         }
-        roll -= template.weight();
+        roll -= weight;
     }
 
     // Fallback (shouldn't happen)
-    applicable[0].generate(galaxy, rng)
+    (
+        applicable[0].0.name(),
+        applicable[0].0.generate(galaxy, rng),
+    )
 }
 
 #[cfg(test)]
@@ -1518,6 +1692,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn resource_scarcity_rationing_spends_fuel_down_to_zero() {
+        let template = ResourceScarcityTemplate;
+        let mut galaxy = GalaxyState::new();
+        galaxy.resources.insert(FUEL.to_string(), 1);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(2026);
+
+        let event = template.generate(&galaxy, &mut rng);
+        let spent = event.options[0]
+            .outcome
+            .state_changes
+            .iter()
+            .find_map(|c| match c {
+                StateChange::AddResource { kind, amount } if kind == FUEL => Some(*amount),
+                _ => None,
+            })
+            .unwrap();
+        assert!(spent < 0);
+
+        // Rationing spends at least the one unit in stock; never below zero.
+        galaxy.apply_changes(&event.options[0].outcome.state_changes);
+        assert_eq!(galaxy.resource(FUEL), 0);
+    }
+
     // ====================================================================
     // TechBreakthroughTemplate tests
     // ====================================================================
@@ -1534,6 +1732,8 @@ mod tests {
             galaxy.discoveries.push(Discovery {
                 name: format!("Discovery {}", i),
                 category: "science".to_string(),
+                source_round: None,
+                source_event: None,
             });
         }
         assert!(!template.is_applicable(&galaxy));
@@ -1542,6 +1742,8 @@ mod tests {
         galaxy.discoveries.push(Discovery {
             name: "Discovery 2".to_string(),
             category: "science".to_string(),
+            source_round: None,
+            source_event: None,
         });
         assert!(template.is_applicable(&galaxy));
     }
@@ -1560,6 +1762,8 @@ mod tests {
             galaxy.discoveries.push(Discovery {
                 name: format!("Discovery {}", i),
                 category: "science".to_string(),
+                source_round: None,
+                source_event: None,
             });
         }
         let mut rng = rand::rngs::StdRng::seed_from_u64(77);
@@ -1744,4 +1948,86 @@ mod tests {
         assert!(names.contains(&"Threat Escalation"));
         assert_eq!(templates.len(), 11);
     }
+
+    struct CrewTrainingTemplate;
+
+    impl EventTemplate for CrewTrainingTemplate {
+        fn name(&self) -> &'static str {
+            "Crew Training"
+        }
+
+        fn is_applicable(&self, _galaxy: &GalaxyState) -> bool {
+            true
+        }
+
+        fn generate(&self, _galaxy: &GalaxyState, _rng: &mut dyn RngCore) -> Event {
+            Event {
+                description: "The council schedules crew training.".to_string(),
+                relevant_expertise: vec![],
+                options: vec![],
+            }
+        }
+    }
+
+    #[test]
+    fn custom_fallback_used_when_nothing_applies() {
+        let templates: Vec<Box<dyn EventTemplate>> = vec![Box::new(TechBreakthroughTemplate)];
+        let galaxy = GalaxyState::new(); // No discoveries, so nothing applies
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+
+        let event =
+            generate_event_with_fallback(&templates, &galaxy, &mut rng, &CrewTrainingTemplate);
+        assert_eq!(event.description, "The council schedules crew training.");
+
+        let event = generate_event(&templates, &galaxy, &mut rng);
+        assert!(event.description.starts_with("A quiet period"));
+    }
+
+    struct DrillTemplate;
+
+    impl EventTemplate for DrillTemplate {
+        fn name(&self) -> &'static str {
+            "Drill"
+        }
+
+        fn is_applicable(&self, _galaxy: &GalaxyState) -> bool {
+            true
+        }
+
+        fn generate(&self, _galaxy: &GalaxyState, _rng: &mut dyn RngCore) -> Event {
+            Event {
+                description: "Emergency drill.".to_string(),
+                relevant_expertise: vec![],
+                options: vec![],
+            }
+        }
+    }
+
+    #[test]
+    fn weight_override_shifts_selection() {
+        let templates: Vec<Box<dyn EventTemplate>> =
+            vec![Box::new(CrewTrainingTemplate), Box::new(DrillTemplate)];
+        let galaxy = GalaxyState::new();
+        let drills = |overrides: &HashMap<&str, u32>| {
+            (0..2000)
+                .filter(|&seed| {
+                    let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+                    generate_event_weighted(&templates, &galaxy, &mut rng, overrides).description
+                        == "Emergency drill."
+                })
+                .count()
+        };
+
+        let baseline = drills(&HashMap::new()); // ~1/2
+        let doubled = drills(&HashMap::from([("Drill", 20)])); // ~2/3
+        assert!(
+            doubled > baseline + 200,
+            "baseline {} doubled {}",
+            baseline,
+            doubled
+        );
+
+        let disabled = drills(&HashMap::from([("Drill", 0)]));
+        assert_eq!(disabled, 0);
+    }
 }
@@ -0,0 +1,60 @@
+//! Shared test fixtures for this crate and the bot crates.
+//!
+//! Compiled for this crate's tests; other crates enable the `test-util`
+//! feature from their `[dev-dependencies]`.
+
+use crate::event::{Event, Outcome, ResponseOption};
+use crate::explorer::GalacticCouncilMember;
+use crate::galaxy::GalaxyState;
+
+/// A "Test event" with `num_options` plain options ("Option 0" with
+/// "Outcome 0", ...) that change nothing and score 0.
+pub fn make_event(num_options: usize) -> Event {
+    Event {
+        description: "Test event".to_string(),
+        relevant_expertise: vec![],
+        options: (0..num_options)
+            .map(|i| ResponseOption {
+                description: format!("Option {}", i),
+                outcome: Outcome {
+                    description: format!("Outcome {}", i),
+                    score_delta: 0,
+                    headline: None,
+                    state_changes: vec![],
+                },
+                required_discovery: None,
+            })
+            .collect(),
+    }
+}
+
+/// Like [`make_event`], tagged with the given `(expertise, weight)` pairs.
+pub fn make_event_with_expertise(expertise: &[(&str, f32)], num_options: usize) -> Event {
+    Event {
+        relevant_expertise: expertise
+            .iter()
+            .map(|(tag, weight)| (tag.to_string(), *weight))
+            .collect(),
+        ..make_event(num_options)
+    }
+}
+
+/// Bot with no expertise that always votes for `choice`.
+#[derive(Debug, Clone)]
+pub struct FixedBot {
+    pub choice: usize,
+}
+
+impl GalacticCouncilMember for FixedBot {
+    fn name(&self) -> &'static str {
+        "fixed-bot"
+    }
+
+    fn expertise(&self) -> &[(&'static str, f32)] {
+        &[]
+    }
+
+    fn vote(&self, _event: &Event, _galaxy: &GalaxyState) -> usize {
+        self.choice
+    }
+}
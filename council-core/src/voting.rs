@@ -2,6 +2,8 @@
 
 use crate::event::Event;
 use crate::explorer::GalacticCouncilMember;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 /// A vote cast by a bot.
 #[derive(Debug, Clone)]
@@ -14,11 +16,35 @@ pub struct Vote {
     pub weight: f32,
 }
 
+/// How the orchestrator turns ballots into a winning option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResolutionMode {
+    /// One vote per bot; most votes wins.
+    Plurality,
+    /// Each bot approves any number of options; most approvals wins.
+    Approval,
+    /// Instant runoff over each bot's ranking.
+    RankedChoice,
+    /// Expertise-weighted votes (see [`resolve_votes`]).
+    #[default]
+    WeightedPlurality,
+}
+
 /// Minimum weight for bots with no matching expertise.
 pub const BASE_WEIGHT: f32 = 0.1;
 
 /// Calculate vote weight based on expertise overlap.
 pub fn calculate_vote_weight(bot: &dyn GalacticCouncilMember, event: &Event) -> f32 {
+    calculate_vote_weight_with_base(bot, event, BASE_WEIGHT)
+}
+
+/// Calculate vote weight like [`calculate_vote_weight`] with a custom base
+/// weight in place of [`BASE_WEIGHT`].
+pub fn calculate_vote_weight_with_base(
+    bot: &dyn GalacticCouncilMember,
+    event: &Event,
+    base_weight: f32,
+) -> f32 {
     let expertise = bot.expertise();
 
     let expertise_bonus: f32 = event
@@ -32,23 +58,249 @@ pub fn calculate_vote_weight(bot: &dyn GalacticCouncilMember, event: &Event) ->
         })
         .sum();
 
-    BASE_WEIGHT + expertise_bonus
+    base_weight + expertise_bonus
+}
+
+/// How [`resolve_votes_with`] picks among options tied for the most weight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TieBreak {
+    /// The tied option with the lowest index.
+    #[default]
+    LowestIndex,
+    /// The tied option with the highest index.
+    HighestIndex,
+    /// The tied option chosen earliest in the `votes` slice.
+    FirstVoter,
+    /// A tied option picked pseudo-randomly from the seed (same seed, same pick).
+    Random(u64),
+}
+
+/// Scale a vote weight by a bot's [`GalacticCouncilMember::confidence`],
+/// clamped to 0.0–1.0 (NaN counts as no confidence).
+pub fn apply_confidence(weight: f32, confidence: f32) -> f32 {
+    if confidence.is_nan() {
+        return 0.0;
+    }
+    weight * confidence.clamp(0.0, 1.0)
+}
+
+/// Summed vote weight per option. Votes for out-of-range options are
+/// ignored.
+pub fn tally_votes(votes: &[Vote], num_options: usize) -> Vec<f32> {
+    let mut totals = vec![0.0_f32; num_options];
+    for vote in votes {
+        if vote.chosen_option < num_options {
+            totals[vote.chosen_option] += vote.weight;
+        }
+    }
+    totals
+}
+
+/// Resolve like [`resolve_votes`], also returning the per-option totals from
+/// [`tally_votes`].
+pub fn resolve_votes_detailed(votes: &[Vote], num_options: usize) -> (usize, Vec<f32>) {
+    (
+        resolve_votes(votes, num_options),
+        tally_votes(votes, num_options),
+    )
 }
 
 /// Resolve votes to determine winning option index.
 /// Ties are broken by lower index (first option wins).
 pub fn resolve_votes(votes: &[Vote], num_options: usize) -> usize {
+    resolve_votes_with(votes, num_options, TieBreak::LowestIndex)
+}
+
+/// Resolve votes by summed weight, breaking ties with `tie_break`.
+pub fn resolve_votes_with(votes: &[Vote], num_options: usize, tie_break: TieBreak) -> usize {
     if num_options == 0 {
         return 0;
     }
 
-    let mut totals = vec![0.0_f32; num_options];
+    let totals = tally_votes(votes, num_options);
 
-    for vote in votes {
-        if vote.chosen_option < num_options {
-            totals[vote.chosen_option] += vote.weight;
+    let tied = leaders(&totals);
+    match tie_break {
+        TieBreak::LowestIndex => tied[0],
+        TieBreak::HighestIndex => tied[tied.len() - 1],
+        TieBreak::FirstVoter => votes
+            .iter()
+            .map(|v| v.chosen_option)
+            .find(|o| tied.contains(o))
+            .unwrap_or(tied[0]),
+        TieBreak::Random(seed) => {
+            let mut rng = StdRng::seed_from_u64(seed);
+            tied[rng.gen_range(0..tied.len())]
         }
     }
+}
+
+/// Indices sharing the highest total, in ascending order; a NaN total
+/// (e.g. from a NaN confidence) counts as 0.0.
+///
+/// Never empty while `totals` is not.
+fn leaders(totals: &[f32]) -> Vec<usize> {
+    let total = |i: usize| if totals[i].is_nan() { 0.0 } else { totals[i] };
+    let best = (0..totals.len())
+        .map(total)
+        .fold(f32::NEG_INFINITY, f32::max);
+    (0..totals.len()).filter(|&i| total(i) == best).collect()
+}
+
+/// Resolve votes counting each vote once, ignoring weights.
+/// Ties are broken by lower index.
+pub fn resolve_plurality(votes: &[Vote], num_options: usize) -> usize {
+    let unweighted: Vec<Vote> = votes
+        .iter()
+        .map(|v| Vote {
+            weight: 1.0,
+            ..v.clone()
+        })
+        .collect();
+    resolve_votes(&unweighted, num_options)
+}
+
+/// Pick the option with the most approvals (one per bot per option).
+/// Ties are broken by lower index.
+pub fn resolve_approval(ballots: &[Vec<usize>], num_options: usize) -> usize {
+    let mut counts = vec![0u32; num_options];
+    for ballot in ballots {
+        let mut seen = vec![false; num_options];
+        for &option in ballot {
+            if option < num_options && !seen[option] {
+                seen[option] = true;
+                counts[option] += 1;
+            }
+        }
+    }
+    lowest_index_max(&counts)
+}
+
+/// Resolve votes like [`resolve_votes`], but break ties with a hash of
+/// `round` and the option descriptions instead of by index.
+///
+/// Ties resolve differently from round to round (avoiding a systematic
+/// lowest-index bias over long runs) yet identically for the same round and
+/// event, so seeded runs stay reproducible.
+pub fn resolve_votes_hashed(votes: &[Vote], event: &Event, round: u32) -> usize {
+    let num_options = event.options.len();
+    if num_options == 0 {
+        return 0;
+    }
+
+    let totals = tally_votes(votes, num_options);
+
+    let tied = leaders(&totals);
+    match tied.len() {
+        0 => 0,
+        1 => tied[0],
+        n => tied[(tie_hash(event, round) % n as u64) as usize],
+    }
+}
+
+/// FNV-1a over the round and option descriptions.
+fn tie_hash(event: &Event, round: u32) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let bytes = round
+        .to_le_bytes()
+        .into_iter()
+        .chain(event.options.iter().flat_map(|o| o.description.bytes()));
+    for byte in bytes {
+        hash = (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+/// A weighted ranked ballot for [`resolve_votes_ranked`].
+#[derive(Debug, Clone)]
+pub struct RankedVote {
+    /// Name of the bot that voted.
+    pub bot_name: String,
+    /// Options in order of preference, most preferred first.
+    pub preferences: Vec<usize>,
+    /// Calculated weight of this vote.
+    pub weight: f32,
+}
+
+/// Instant-runoff resolution over ranked ballots.
+///
+/// Each round counts every ballot's highest-ranked remaining option; an
+/// option with a strict majority wins, otherwise the option with the fewest
+/// first preferences is eliminated (the higher index on ties). Options absent
+/// from a ballot are unranked by it.
+pub fn resolve_ranked_choice(ballots: &[Vec<usize>], num_options: usize) -> usize {
+    instant_runoff(ballots.iter().map(|b| (b.as_slice(), 1.0)), num_options)
+}
+
+/// Weighted instant runoff: like [`resolve_ranked_choice`], but each ballot
+/// counts with its vote weight and a majority is measured against the weight
+/// of ballots still ranking a remaining option.
+pub fn resolve_votes_ranked(votes: &[RankedVote], num_options: usize) -> usize {
+    instant_runoff(
+        votes.iter().map(|v| (v.preferences.as_slice(), v.weight)),
+        num_options,
+    )
+}
+
+fn instant_runoff<'a>(
+    ballots: impl Iterator<Item = (&'a [usize], f32)> + Clone,
+    num_options: usize,
+) -> usize {
+    if num_options == 0 {
+        return 0;
+    }
+    let mut remaining = vec![true; num_options];
+    loop {
+        let mut totals = vec![0.0f32; num_options];
+        let mut active = 0.0f32;
+        for (ballot, weight) in ballots.clone() {
+            if let Some(&top) = ballot.iter().find(|&&o| o < num_options && remaining[o]) {
+                totals[top] += weight;
+                active += weight;
+            }
+        }
+
+        let alive: Vec<usize> = (0..num_options).filter(|&o| remaining[o]).collect();
+        let leader = alive
+            .iter()
+            .copied()
+            .max_by(|&a, &b| totals[a].total_cmp(&totals[b]).then(b.cmp(&a)))
+            .unwrap_or(0);
+        if alive.len() == 1 || totals[leader] * 2.0 > active {
+            return leader;
+        }
+
+        let loser = alive
+            .iter()
+            .copied()
+            .min_by(|&a, &b| totals[a].total_cmp(&totals[b]).then(b.cmp(&a)))
+            .unwrap_or(leader);
+        remaining[loser] = false;
+    }
+}
+
+/// Index of the largest count, lower index winning ties.
+fn lowest_index_max(counts: &[u32]) -> usize {
+    counts
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(&a.0)))
+        .map(|(idx, _)| idx)
+        .unwrap_or(0)
+}
+
+/// Resolve votes like [`resolve_votes`], but break ties by the
+/// lexicographically smallest [`Event::stable_option_key`] instead of by index.
+///
+/// Useful for templates that shuffle option order, where "lowest index" has no
+/// meaning.
+pub fn resolve_votes_stable(votes: &[Vote], event: &Event) -> usize {
+    let num_options = event.options.len();
+    if num_options == 0 {
+        return 0;
+    }
+
+    let totals = tally_votes(votes, num_options);
 
     totals
         .iter()
@@ -56,7 +308,12 @@ pub fn resolve_votes(votes: &[Vote], num_options: usize) -> usize {
         .max_by(|a, b| {
             a.1.partial_cmp(b.1)
                 .unwrap_or(std::cmp::Ordering::Equal)
-                .then(b.0.cmp(&a.0)) // Lower index wins ties
+                .then_with(|| {
+                    // Smaller key wins ties
+                    event
+                        .stable_option_key(b.0)
+                        .cmp(event.stable_option_key(a.0))
+                })
         })
         .map(|(idx, _)| idx)
         .unwrap_or(0)
@@ -68,6 +325,7 @@ mod tests {
     use crate::event::{Outcome, ResponseOption};
     use crate::galaxy::GalaxyState;
 
+    #[derive(Clone)]
     struct TestBot {
         name: &'static str,
         expertise: Vec<(&'static str, f32)>,
@@ -100,16 +358,20 @@ mod tests {
                     outcome: Outcome {
                         description: "A happened".to_string(),
                         score_delta: 0,
+                        headline: None,
                         state_changes: vec![],
                     },
+                    required_discovery: None,
                 },
                 ResponseOption {
                     description: "Option B".to_string(),
                     outcome: Outcome {
                         description: "B happened".to_string(),
                         score_delta: 0,
+                        headline: None,
                         state_changes: vec![],
                     },
+                    required_discovery: None,
                 },
             ],
         }
@@ -183,4 +445,236 @@ mod tests {
         ];
         assert_eq!(resolve_votes(&votes, 2), 0);
     }
+
+    #[test]
+    fn resolve_votes_stable_ignores_option_order() {
+        let votes = vec![
+            Vote {
+                bot_name: "a".to_string(),
+                chosen_option: 0,
+                weight: 0.5,
+            },
+            Vote {
+                bot_name: "b".to_string(),
+                chosen_option: 1,
+                weight: 0.5,
+            },
+        ];
+
+        let event = make_event(vec![]);
+        let winner = resolve_votes_stable(&votes, &event);
+        assert_eq!(event.stable_option_key(winner), "Option A");
+
+        let mut reversed = event.clone();
+        reversed.options.reverse();
+        let winner = resolve_votes_stable(&votes, &reversed);
+        assert_eq!(reversed.stable_option_key(winner), "Option A");
+        assert_eq!(winner, 1);
+    }
+
+    fn vote(option: usize, weight: f32) -> Vote {
+        Vote {
+            bot_name: "bot".to_string(),
+            chosen_option: option,
+            weight,
+        }
+    }
+
+    #[test]
+    fn plurality_ignores_weights() {
+        let votes = vec![vote(0, 0.9), vote(1, 0.2), vote(1, 0.2)];
+        assert_eq!(resolve_votes(&votes, 2), 0);
+        assert_eq!(resolve_plurality(&votes, 2), 1);
+    }
+
+    #[test]
+    fn approval_counts_each_option_once_per_ballot() {
+        let ballots = vec![vec![0], vec![0], vec![1, 2], vec![2, 1, 2], vec![1]];
+        assert_eq!(resolve_approval(&ballots, 3), 1);
+        assert_eq!(resolve_approval(&[], 3), 0);
+    }
+
+    #[test]
+    fn ranked_choice_transfers_eliminated_preferences() {
+        // First preferences: 0 x2, 1 x2, 2 x1. Option 2 is eliminated and
+        // its ballot transfers to 1, which then has a majority.
+        let ballots = vec![
+            vec![0, 1, 2],
+            vec![0, 2, 1],
+            vec![1, 0, 2],
+            vec![1, 2, 0],
+            vec![2, 1, 0],
+        ];
+        assert_eq!(resolve_ranked_choice(&ballots, 3), 1);
+    }
+
+    #[test]
+    fn ranked_choice_majority_wins_immediately() {
+        let ballots = vec![vec![2], vec![2, 0], vec![0, 1]];
+        assert_eq!(resolve_ranked_choice(&ballots, 3), 2);
+        assert_eq!(resolve_ranked_choice(&[], 0), 0);
+    }
+
+    fn ranked(preferences: &[usize], weight: f32) -> RankedVote {
+        RankedVote {
+            bot_name: "test".to_string(),
+            preferences: preferences.to_vec(),
+            weight,
+        }
+    }
+
+    #[test]
+    fn weighted_runoff_overturns_plurality_winner() {
+        // First-preference weight: 0 = 0.4, 1 = 0.35, 2 = 0.25. Option 0 wins
+        // a weighted plurality, but option 2's supporters prefer 1 next.
+        let votes = vec![
+            ranked(&[0, 1, 2], 0.4),
+            ranked(&[1, 0, 2], 0.35),
+            ranked(&[2, 1, 0], 0.25),
+        ];
+        let firsts: Vec<Vote> = votes
+            .iter()
+            .map(|v| vote(v.preferences[0], v.weight))
+            .collect();
+        assert_eq!(resolve_votes(&firsts, 3), 0);
+        assert_eq!(resolve_votes_ranked(&votes, 3), 1);
+    }
+
+    #[test]
+    fn weighted_runoff_majority_and_ties() {
+        // A heavy single ballot holds a majority of the weight outright.
+        let votes = vec![ranked(&[2], 0.9), ranked(&[0], 0.3), ranked(&[1], 0.3)];
+        assert_eq!(resolve_votes_ranked(&votes, 3), 2);
+
+        // Elimination ties drop the higher index, leaving the lower one.
+        let votes = vec![ranked(&[0], 0.5), ranked(&[1], 0.5)];
+        assert_eq!(resolve_votes_ranked(&votes, 2), 0);
+        assert_eq!(resolve_votes_ranked(&[], 0), 0);
+    }
+
+    fn options_event(num_options: usize) -> Event {
+        Event {
+            description: "Tie".to_string(),
+            relevant_expertise: vec![],
+            options: (0..num_options)
+                .map(|i| crate::event::ResponseOption {
+                    description: format!("Option {}", i),
+                    outcome: crate::event::Outcome {
+                        description: String::new(),
+                        score_delta: 0,
+                        headline: None,
+                        state_changes: vec![],
+                    },
+                    required_discovery: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn hashed_tie_break_varies_by_round_but_is_reproducible() {
+        let event = options_event(3);
+        let votes = vec![vote(0, 0.5), vote(1, 0.5), vote(2, 0.5)];
+
+        let picks: Vec<usize> = (1..=20)
+            .map(|round| resolve_votes_hashed(&votes, &event, round))
+            .collect();
+        assert!(picks.iter().any(|&p| p != picks[0]), "{:?}", picks);
+        for round in 1..=20 {
+            assert_eq!(
+                resolve_votes_hashed(&votes, &event, round),
+                picks[round as usize - 1]
+            );
+        }
+    }
+
+    #[test]
+    fn hashed_tie_break_only_among_leaders() {
+        let event = options_event(3);
+        let votes = vec![vote(0, 0.2), vote(1, 0.5), vote(2, 0.5)];
+        for round in 1..=10 {
+            assert_ne!(resolve_votes_hashed(&votes, &event, round), 0);
+        }
+        let clear = vec![vote(2, 0.9), vote(1, 0.5)];
+        assert_eq!(resolve_votes_hashed(&clear, &event, 4), 2);
+    }
+
+    #[test]
+    fn hashed_tie_break_survives_nan_weights() {
+        let event = options_event(3);
+        let votes = vec![vote(0, f32::NAN), vote(1, f32::NAN), vote(2, f32::NAN)];
+        for round in 1..=10 {
+            assert!(resolve_votes_hashed(&votes, &event, round) < 3);
+        }
+        let one_real = vec![vote(0, f32::NAN), vote(2, 0.4)];
+        assert_eq!(resolve_votes_hashed(&one_real, &event, 1), 2);
+    }
+
+    #[test]
+    fn tie_break_variants_on_two_way_tie() {
+        let votes = vec![vote(2, 0.3), vote(0, 0.3), vote(1, 0.1)];
+        assert_eq!(resolve_votes_with(&votes, 3, TieBreak::LowestIndex), 0);
+        assert_eq!(resolve_votes(&votes, 3), 0);
+        assert_eq!(resolve_votes_with(&votes, 3, TieBreak::HighestIndex), 2);
+        assert_eq!(resolve_votes_with(&votes, 3, TieBreak::FirstVoter), 2);
+
+        let random = resolve_votes_with(&votes, 3, TieBreak::Random(7));
+        assert!(random == 0 || random == 2);
+        assert_eq!(resolve_votes_with(&votes, 3, TieBreak::Random(7)), random);
+        let picks: Vec<usize> = (0..20)
+            .map(|seed| resolve_votes_with(&votes, 3, TieBreak::Random(seed)))
+            .collect();
+        assert!(picks.contains(&0) && picks.contains(&2), "{:?}", picks);
+    }
+
+    #[test]
+    fn tie_break_ignored_without_tie() {
+        let votes = vec![vote(1, 0.5), vote(0, 0.3)];
+        for tie_break in [
+            TieBreak::LowestIndex,
+            TieBreak::HighestIndex,
+            TieBreak::FirstVoter,
+            TieBreak::Random(3),
+        ] {
+            assert_eq!(resolve_votes_with(&votes, 2, tie_break), 1);
+        }
+    }
+
+    #[test]
+    fn nan_weights_count_as_zero() {
+        let all_nan = vec![vote(1, f32::NAN), vote(2, f32::NAN)];
+        assert_eq!(resolve_votes(&all_nan, 3), 0);
+        assert_eq!(resolve_votes_with(&all_nan, 3, TieBreak::HighestIndex), 2);
+        assert_eq!(resolve_votes_with(&all_nan, 3, TieBreak::FirstVoter), 1);
+
+        let mixed = vec![vote(0, f32::NAN), vote(2, 0.1)];
+        assert_eq!(resolve_votes(&mixed, 3), 2);
+        let sunk = vec![vote(1, f32::NEG_INFINITY), vote(2, f32::NEG_INFINITY)];
+        assert_eq!(resolve_votes(&sunk, 3), 0);
+    }
+
+    #[test]
+    fn tally_matches_hand_computed_weights() {
+        let votes = vec![vote(0, 0.5), vote(2, 0.25), vote(0, 0.1), vote(7, 9.0)];
+        let totals = tally_votes(&votes, 3);
+        assert_eq!(totals.len(), 3);
+        assert!((totals[0] - 0.6).abs() < 1e-6);
+        assert_eq!(totals[1], 0.0);
+        assert!((totals[2] - 0.25).abs() < 1e-6);
+
+        let (winner, detailed) = resolve_votes_detailed(&votes, 3);
+        assert_eq!(winner, 0);
+        assert_eq!(detailed, totals);
+        assert_eq!(resolve_votes_detailed(&votes, 0), (0, vec![]));
+    }
+
+    #[test]
+    fn confidence_scales_weight() {
+        assert_eq!(apply_confidence(0.6, 1.0), 0.6);
+        assert_eq!(apply_confidence(0.6, 0.0), 0.0);
+        assert_eq!(apply_confidence(0.6, 0.5), 0.3);
+        assert_eq!(apply_confidence(0.6, 3.0), 0.6);
+        assert_eq!(apply_confidence(0.6, -1.0), 0.0);
+        assert_eq!(apply_confidence(0.6, f32::NAN), 0.0);
+    }
 }